@@ -17,7 +17,9 @@ pub mod pallet {
     use frame_support::pallet_prelude::*;
     use frame_support::traits::tokens::Balance;
     use frame_system::pallet_prelude::*;
-    use sp_runtime::BoundedVec;
+    use pallet_subtensor::{HyperparameterTemplate, SubnetHyperparameter};
+    use sp_runtime::{BoundedVec, SaturatedConversion};
+    use subtensor_macros::freeze_struct;
 
     /// The main data structure of the module.
     #[pallet::pallet]
@@ -50,8 +52,192 @@ pub mod pallet {
         type Balance: Balance;
     }
 
+    /// A configuration value which can be changed through this pallet.
+    #[derive(Encode, Decode, TypeInfo, Clone, Copy, PartialEq, Eq, Debug)]
+    pub enum Hyperparameter {
+        /// A hyperparameter of a single subnet.
+        Subnet(SubnetHyperparameter),
+        /// The recycled RAO of a subnet.
+        RaoRecycled,
+        /// The default delegate take.
+        DefaultTake,
+        /// The transaction rate limit.
+        TxRateLimit,
+        /// The subnet owner cut.
+        SubnetOwnerCut,
+        /// The network registration rate limit.
+        NetworkRateLimit,
+        /// The total issuance.
+        TotalIssuance,
+        /// The network immunity period.
+        NetworkImmunityPeriod,
+        /// The network minimum lock cost.
+        NetworkMinLockCost,
+        /// The maximum number of subnets.
+        SubnetLimit,
+        /// The network lock reduction interval.
+        LockReductionInterval,
+        /// The minimum stake required to set weights.
+        WeightsMinStake,
+        /// The minimum stake required for nominators.
+        NominatorMinRequiredStake,
+        /// The delegate take transaction rate limit.
+        TxDelegateTakeRateLimit,
+        /// The minimum delegate take.
+        MinDelegateTake,
+        /// The target stakes per interval.
+        TargetStakesPerInterval,
+        /// The hotkey emission tempo.
+        HotkeyEmissionTempo,
+        /// The coldkey swap schedule duration.
+        ColdkeySwapScheduleDuration,
+        /// The dissolve network schedule duration.
+        DissolveNetworkScheduleDuration,
+        /// The subnet owner transfer schedule duration.
+        SubnetOwnerTransferScheduleDuration,
+        /// The senate adjustment interval.
+        SenateAdjustmentInterval,
+        /// The hyperparameter enactment delay.
+        HyperparameterEnactmentDelay,
+        /// The delegate take notice period.
+        DelegateTakeNoticePeriod,
+        /// The unbonding period.
+        UnbondingPeriod,
+        /// Whether senate motions are tallied by stake.
+        SenateStakeWeightedVoting,
+    }
+
+    /// A set of subnet hyperparameter changes applied together, `None` fields are left unchanged.
+    ///
+    /// The fields match those of `SubnetHyperparams`.
+    #[freeze_struct("fde492fd9a1dde75")]
+    #[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug, Default)]
+    pub struct HyperparamPatch {
+        /// The rho of the subnet.
+        pub rho: Option<u16>,
+        /// The kappa of the subnet.
+        pub kappa: Option<u16>,
+        /// The immunity period of the subnet.
+        pub immunity_period: Option<u16>,
+        /// The minimum allowed weights of the subnet.
+        pub min_allowed_weights: Option<u16>,
+        /// The maximum weight limit of the subnet.
+        pub max_weights_limit: Option<u16>,
+        /// The tempo of the subnet.
+        pub tempo: Option<u16>,
+        /// The minimum difficulty of the subnet.
+        pub min_difficulty: Option<u64>,
+        /// The maximum difficulty of the subnet.
+        pub max_difficulty: Option<u64>,
+        /// The weights version key of the subnet.
+        pub weights_version: Option<u64>,
+        /// The weights set rate limit of the subnet.
+        pub weights_rate_limit: Option<u64>,
+        /// The adjustment interval of the subnet.
+        pub adjustment_interval: Option<u16>,
+        /// The activity cutoff of the subnet.
+        pub activity_cutoff: Option<u16>,
+        /// Whether registration is allowed on the subnet.
+        pub registration_allowed: Option<bool>,
+        /// The target registrations per interval of the subnet.
+        pub target_regs_per_interval: Option<u16>,
+        /// The minimum burn of the subnet.
+        pub min_burn: Option<u64>,
+        /// The maximum burn of the subnet.
+        pub max_burn: Option<u64>,
+        /// The bonds moving average of the subnet.
+        pub bonds_moving_avg: Option<u64>,
+        /// The maximum registrations per block of the subnet.
+        pub max_regs_per_block: Option<u16>,
+        /// The serving rate limit of the subnet.
+        pub serving_rate_limit: Option<u64>,
+        /// The maximum number of validators of the subnet.
+        pub max_validators: Option<u16>,
+        /// The adjustment alpha of the subnet.
+        pub adjustment_alpha: Option<u64>,
+        /// The difficulty of the subnet.
+        pub difficulty: Option<u64>,
+        /// The commit/reveal weights interval of the subnet.
+        pub commit_reveal_weights_interval: Option<u64>,
+        /// Whether commit/reveal weights is enabled on the subnet.
+        pub commit_reveal_weights_enabled: Option<bool>,
+        /// The upper liquid alpha of the subnet.
+        pub alpha_high: Option<u16>,
+        /// The lower liquid alpha of the subnet.
+        pub alpha_low: Option<u16>,
+        /// Whether liquid alpha is enabled on the subnet.
+        pub liquid_alpha_enabled: Option<bool>,
+        /// The maximum number of children of a hotkey on the subnet.
+        pub max_children: Option<u16>,
+        /// The minimum proportion of each child on the subnet.
+        pub min_child_proportion: Option<u64>,
+        /// The blocks between setting the children of a hotkey on the subnet.
+        pub set_children_cooldown: Option<u64>,
+        /// The lower bound of the childkey take on the subnet.
+        pub min_childkey_take: Option<u16>,
+        /// The upper bound of the childkey take on the subnet.
+        pub max_childkey_take: Option<u16>,
+    }
+
+    /// The kind of origin which made a change.
+    #[derive(Encode, Decode, TypeInfo, Clone, Copy, PartialEq, Eq, Debug)]
+    pub enum OriginKind {
+        /// The root account.
+        Root,
+        /// The owner of the subnet.
+        SubnetOwner,
+    }
+
     #[pallet::event]
-    pub enum Event<T: Config> {}
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// A hyperparameter has been changed, when it is set or once its enactment delay has passed.
+        HyperparameterChanged {
+            /// The subnet of the hyperparameter, `None` for network-wide values.
+            netuid: Option<u16>,
+            /// The changed hyperparameter.
+            param: Hyperparameter,
+            /// The value before the change.
+            old_value: u64,
+            /// The value after the change.
+            new_value: u64,
+            /// The kind of origin which made the change.
+            origin_kind: OriginKind,
+        },
+        /// The Aura authorities have been swapped.
+        AuthoritiesSwapped {
+            /// The new authorities.
+            new_authorities: BoundedVec<T::AuthorityId, T::MaxAuthorities>,
+        },
+        /// A subnet hyperparameter change has been scheduled to apply after the enactment delay.
+        HyperparameterChangeScheduled {
+            /// The subnet of the hyperparameter.
+            netuid: u16,
+            /// The hyperparameter to change.
+            param: Hyperparameter,
+            /// The value when the change was scheduled.
+            old_value: u64,
+            /// The value after the change.
+            new_value: u64,
+            /// The block at which the change applies.
+            effective_block: u64,
+            /// The kind of origin which made the change.
+            origin_kind: OriginKind,
+        },
+    }
+
+    #[pallet::storage]
+    /// --- DMAP ( netuid, hyperparameter ) --> origin_kind | The kind of origin which scheduled a
+    /// pending subnet hyperparameter change.
+    pub type PendingHyperparameterChangeOrigins<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        u16,
+        Twox64Concat,
+        SubnetHyperparameter,
+        OriginKind,
+        OptionQuery,
+    >;
 
     // Errors inform users that something went wrong.
     #[pallet::error]
@@ -62,6 +248,32 @@ pub mod pallet {
         MaxValidatorsLargerThanMaxUIds,
         /// The maximum number of subnet validators must be more than the current number of UIDs already in the subnet.
         MaxAllowedUIdsLessThanCurrentUIds,
+        /// The minimum childkey take of a subnet must not be more than its maximum childkey take.
+        InvalidChildkeyTakeBounds,
+        /// The value is outside the limits every subnet keeps to for its children.
+        OutsideChildkeyLimits,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        /// Applies the subnet hyperparameter changes whose enactment delay has passed.
+        fn on_initialize(_block_number: BlockNumberFor<T>) -> Weight {
+            let (mut weight, enacted) =
+                pallet_subtensor::Pallet::<T>::enact_pending_hyperparameter_changes();
+            for (netuid, param, old_value, new_value) in enacted {
+                let origin_kind = PendingHyperparameterChangeOrigins::<T>::take(netuid, param)
+                    .unwrap_or(OriginKind::Root);
+                weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+                Self::deposit_hyperparameter_changed(
+                    Some(netuid),
+                    Hyperparameter::Subnet(param),
+                    old_value,
+                    new_value,
+                    origin_kind,
+                );
+            }
+            weight
+        }
     }
 
     /// Dispatchable functions allows users to interact with the pallet and invoke state changes.
@@ -81,6 +293,7 @@ pub mod pallet {
             T::Aura::change_authorities(new_authorities.clone());
 
             log::debug!("Aura authorities changed: {:?}", new_authorities);
+            Self::deposit_event(Event::AuthoritiesSwapped { new_authorities });
 
             // Return a successful DispatchResultWithPostInfo
             Ok(())
@@ -93,7 +306,15 @@ pub mod pallet {
         #[pallet::weight(T::WeightInfo::sudo_set_default_take())]
         pub fn sudo_set_default_take(origin: OriginFor<T>, default_take: u16) -> DispatchResult {
             ensure_root(origin)?;
+            let old_value = u64::from(pallet_subtensor::Pallet::<T>::get_max_delegate_take());
             pallet_subtensor::Pallet::<T>::set_max_delegate_take(default_take);
+            Self::deposit_hyperparameter_changed(
+                None,
+                Hyperparameter::DefaultTake,
+                old_value,
+                u64::from(default_take),
+                OriginKind::Root,
+            );
             log::debug!("DefaultTakeSet( default_take: {:?} ) ", default_take);
             Ok(())
         }
@@ -105,7 +326,15 @@ pub mod pallet {
         #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
         pub fn sudo_set_tx_rate_limit(origin: OriginFor<T>, tx_rate_limit: u64) -> DispatchResult {
             ensure_root(origin)?;
+            let old_value = pallet_subtensor::Pallet::<T>::get_tx_rate_limit();
             pallet_subtensor::Pallet::<T>::set_tx_rate_limit(tx_rate_limit);
+            Self::deposit_hyperparameter_changed(
+                None,
+                Hyperparameter::TxRateLimit,
+                old_value,
+                tx_rate_limit,
+                OriginKind::Root,
+            );
             log::debug!("TxRateLimitSet( tx_rate_limit: {:?} ) ", tx_rate_limit);
             Ok(())
         }
@@ -120,9 +349,19 @@ pub mod pallet {
             netuid: u16,
             serving_rate_limit: u64,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            let origin_kind = Self::ensure_subnet_owner_or_root_within_bounds(
+                origin,
+                netuid,
+                SubnetHyperparameter::ServingRateLimit,
+                serving_rate_limit,
+            )?;
 
-            pallet_subtensor::Pallet::<T>::set_serving_rate_limit(netuid, serving_rate_limit);
+            Self::set_subnet_hyperparameter(
+                origin_kind,
+                netuid,
+                SubnetHyperparameter::ServingRateLimit,
+                serving_rate_limit,
+            );
             log::debug!(
                 "ServingRateLimitSet( serving_rate_limit: {:?} ) ",
                 serving_rate_limit
@@ -140,13 +379,23 @@ pub mod pallet {
             netuid: u16,
             min_difficulty: u64,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            let origin_kind = Self::ensure_subnet_owner_or_root_within_bounds(
+                origin,
+                netuid,
+                SubnetHyperparameter::MinDifficulty,
+                min_difficulty,
+            )?;
 
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
                 Error::<T>::SubnetDoesNotExist
             );
-            pallet_subtensor::Pallet::<T>::set_min_difficulty(netuid, min_difficulty);
+            Self::set_subnet_hyperparameter(
+                origin_kind,
+                netuid,
+                SubnetHyperparameter::MinDifficulty,
+                min_difficulty,
+            );
             log::debug!(
                 "MinDifficultySet( netuid: {:?} min_difficulty: {:?} ) ",
                 netuid,
@@ -165,13 +414,23 @@ pub mod pallet {
             netuid: u16,
             max_difficulty: u64,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            let origin_kind = Self::ensure_subnet_owner_or_root_within_bounds(
+                origin,
+                netuid,
+                SubnetHyperparameter::MaxDifficulty,
+                max_difficulty,
+            )?;
 
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
                 Error::<T>::SubnetDoesNotExist
             );
-            pallet_subtensor::Pallet::<T>::set_max_difficulty(netuid, max_difficulty);
+            Self::set_subnet_hyperparameter(
+                origin_kind,
+                netuid,
+                SubnetHyperparameter::MaxDifficulty,
+                max_difficulty,
+            );
             log::debug!(
                 "MaxDifficultySet( netuid: {:?} max_difficulty: {:?} ) ",
                 netuid,
@@ -190,13 +449,23 @@ pub mod pallet {
             netuid: u16,
             weights_version_key: u64,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            let origin_kind = Self::ensure_subnet_owner_or_root_within_bounds(
+                origin,
+                netuid,
+                SubnetHyperparameter::WeightsVersionKey,
+                weights_version_key,
+            )?;
 
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
                 Error::<T>::SubnetDoesNotExist
             );
-            pallet_subtensor::Pallet::<T>::set_weights_version_key(netuid, weights_version_key);
+            Self::set_subnet_hyperparameter(
+                origin_kind,
+                netuid,
+                SubnetHyperparameter::WeightsVersionKey,
+                weights_version_key,
+            );
             log::debug!(
                 "WeightsVersionKeySet( netuid: {:?} weights_version_key: {:?} ) ",
                 netuid,
@@ -215,14 +484,21 @@ pub mod pallet {
             netuid: u16,
             weights_set_rate_limit: u64,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            let origin_kind = Self::ensure_subnet_owner_or_root_within_bounds(
+                origin,
+                netuid,
+                SubnetHyperparameter::WeightsSetRateLimit,
+                weights_set_rate_limit,
+            )?;
 
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
                 Error::<T>::SubnetDoesNotExist
             );
-            pallet_subtensor::Pallet::<T>::set_weights_set_rate_limit(
+            Self::set_subnet_hyperparameter(
+                origin_kind,
                 netuid,
+                SubnetHyperparameter::WeightsSetRateLimit,
                 weights_set_rate_limit,
             );
             log::debug!(
@@ -249,7 +525,12 @@ pub mod pallet {
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
                 Error::<T>::SubnetDoesNotExist
             );
-            pallet_subtensor::Pallet::<T>::set_adjustment_interval(netuid, adjustment_interval);
+            Self::set_subnet_hyperparameter(
+                OriginKind::Root,
+                netuid,
+                SubnetHyperparameter::AdjustmentInterval,
+                u64::from(adjustment_interval),
+            );
             log::debug!(
                 "AdjustmentIntervalSet( netuid: {:?} adjustment_interval: {:?} ) ",
                 netuid,
@@ -274,13 +555,23 @@ pub mod pallet {
             netuid: u16,
             adjustment_alpha: u64,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            let origin_kind = Self::ensure_subnet_owner_or_root_within_bounds(
+                origin,
+                netuid,
+                SubnetHyperparameter::AdjustmentAlpha,
+                adjustment_alpha,
+            )?;
 
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
                 Error::<T>::SubnetDoesNotExist
             );
-            pallet_subtensor::Pallet::<T>::set_adjustment_alpha(netuid, adjustment_alpha);
+            Self::set_subnet_hyperparameter(
+                origin_kind,
+                netuid,
+                SubnetHyperparameter::AdjustmentAlpha,
+                adjustment_alpha,
+            );
             log::debug!(
                 "AdjustmentAlphaSet( adjustment_alpha: {:?} ) ",
                 adjustment_alpha
@@ -298,13 +589,23 @@ pub mod pallet {
             netuid: u16,
             max_weight_limit: u16,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            let origin_kind = Self::ensure_subnet_owner_or_root_within_bounds(
+                origin,
+                netuid,
+                SubnetHyperparameter::MaxWeightLimit,
+                u64::from(max_weight_limit),
+            )?;
 
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
                 Error::<T>::SubnetDoesNotExist
             );
-            pallet_subtensor::Pallet::<T>::set_max_weight_limit(netuid, max_weight_limit);
+            Self::set_subnet_hyperparameter(
+                origin_kind,
+                netuid,
+                SubnetHyperparameter::MaxWeightLimit,
+                u64::from(max_weight_limit),
+            );
             log::debug!(
                 "MaxWeightLimitSet( netuid: {:?} max_weight_limit: {:?} ) ",
                 netuid,
@@ -323,13 +624,23 @@ pub mod pallet {
             netuid: u16,
             immunity_period: u16,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            let origin_kind = Self::ensure_subnet_owner_or_root_within_bounds(
+                origin,
+                netuid,
+                SubnetHyperparameter::ImmunityPeriod,
+                u64::from(immunity_period),
+            )?;
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
                 Error::<T>::SubnetDoesNotExist
             );
 
-            pallet_subtensor::Pallet::<T>::set_immunity_period(netuid, immunity_period);
+            Self::set_subnet_hyperparameter(
+                origin_kind,
+                netuid,
+                SubnetHyperparameter::ImmunityPeriod,
+                u64::from(immunity_period),
+            );
             log::debug!(
                 "ImmunityPeriodSet( netuid: {:?} immunity_period: {:?} ) ",
                 netuid,
@@ -348,13 +659,23 @@ pub mod pallet {
             netuid: u16,
             min_allowed_weights: u16,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            let origin_kind = Self::ensure_subnet_owner_or_root_within_bounds(
+                origin,
+                netuid,
+                SubnetHyperparameter::MinAllowedWeights,
+                u64::from(min_allowed_weights),
+            )?;
 
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
                 Error::<T>::SubnetDoesNotExist
             );
-            pallet_subtensor::Pallet::<T>::set_min_allowed_weights(netuid, min_allowed_weights);
+            Self::set_subnet_hyperparameter(
+                origin_kind,
+                netuid,
+                SubnetHyperparameter::MinAllowedWeights,
+                u64::from(min_allowed_weights),
+            );
             log::debug!(
                 "MinAllowedWeightSet( netuid: {:?} min_allowed_weights: {:?} ) ",
                 netuid,
@@ -382,7 +703,12 @@ pub mod pallet {
                 pallet_subtensor::Pallet::<T>::get_subnetwork_n(netuid) < max_allowed_uids,
                 Error::<T>::MaxAllowedUIdsLessThanCurrentUIds
             );
-            pallet_subtensor::Pallet::<T>::set_max_allowed_uids(netuid, max_allowed_uids);
+            Self::set_subnet_hyperparameter(
+                OriginKind::Root,
+                netuid,
+                SubnetHyperparameter::MaxAllowedUids,
+                u64::from(max_allowed_uids),
+            );
             log::debug!(
                 "MaxAllowedUidsSet( netuid: {:?} max_allowed_uids: {:?} ) ",
                 netuid,
@@ -397,13 +723,23 @@ pub mod pallet {
         #[pallet::call_index(16)]
         #[pallet::weight(T::WeightInfo::sudo_set_kappa())]
         pub fn sudo_set_kappa(origin: OriginFor<T>, netuid: u16, kappa: u16) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            let origin_kind = Self::ensure_subnet_owner_or_root_within_bounds(
+                origin,
+                netuid,
+                SubnetHyperparameter::Kappa,
+                u64::from(kappa),
+            )?;
 
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
                 Error::<T>::SubnetDoesNotExist
             );
-            pallet_subtensor::Pallet::<T>::set_kappa(netuid, kappa);
+            Self::set_subnet_hyperparameter(
+                origin_kind,
+                netuid,
+                SubnetHyperparameter::Kappa,
+                u64::from(kappa),
+            );
             log::debug!("KappaSet( netuid: {:?} kappa: {:?} ) ", netuid, kappa);
             Ok(())
         }
@@ -414,13 +750,23 @@ pub mod pallet {
         #[pallet::call_index(17)]
         #[pallet::weight(T::WeightInfo::sudo_set_rho())]
         pub fn sudo_set_rho(origin: OriginFor<T>, netuid: u16, rho: u16) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            let origin_kind = Self::ensure_subnet_owner_or_root_within_bounds(
+                origin,
+                netuid,
+                SubnetHyperparameter::Rho,
+                u64::from(rho),
+            )?;
 
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
                 Error::<T>::SubnetDoesNotExist
             );
-            pallet_subtensor::Pallet::<T>::set_rho(netuid, rho);
+            Self::set_subnet_hyperparameter(
+                origin_kind,
+                netuid,
+                SubnetHyperparameter::Rho,
+                u64::from(rho),
+            );
             log::debug!("RhoSet( netuid: {:?} rho: {:?} ) ", netuid, rho);
             Ok(())
         }
@@ -435,13 +781,23 @@ pub mod pallet {
             netuid: u16,
             activity_cutoff: u16,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            let origin_kind = Self::ensure_subnet_owner_or_root_within_bounds(
+                origin,
+                netuid,
+                SubnetHyperparameter::ActivityCutoff,
+                u64::from(activity_cutoff),
+            )?;
 
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
                 Error::<T>::SubnetDoesNotExist
             );
-            pallet_subtensor::Pallet::<T>::set_activity_cutoff(netuid, activity_cutoff);
+            Self::set_subnet_hyperparameter(
+                origin_kind,
+                netuid,
+                SubnetHyperparameter::ActivityCutoff,
+                u64::from(activity_cutoff),
+            );
             log::debug!(
                 "ActivityCutoffSet( netuid: {:?} activity_cutoff: {:?} ) ",
                 netuid,
@@ -466,11 +822,18 @@ pub mod pallet {
             netuid: u16,
             registration_allowed: bool,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            let origin_kind = Self::ensure_subnet_owner_or_root_within_bounds(
+                origin,
+                netuid,
+                SubnetHyperparameter::NetworkRegistrationAllowed,
+                u64::from(registration_allowed),
+            )?;
 
-            pallet_subtensor::Pallet::<T>::set_network_registration_allowed(
+            Self::set_subnet_hyperparameter(
+                origin_kind,
                 netuid,
-                registration_allowed,
+                SubnetHyperparameter::NetworkRegistrationAllowed,
+                u64::from(registration_allowed),
             );
             log::debug!(
                 "NetworkRegistrationAllowed( registration_allowed: {:?} ) ",
@@ -494,11 +857,18 @@ pub mod pallet {
             netuid: u16,
             registration_allowed: bool,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            let origin_kind = Self::ensure_subnet_owner_or_root_within_bounds(
+                origin,
+                netuid,
+                SubnetHyperparameter::NetworkPowRegistrationAllowed,
+                u64::from(registration_allowed),
+            )?;
 
-            pallet_subtensor::Pallet::<T>::set_network_pow_registration_allowed(
+            Self::set_subnet_hyperparameter(
+                origin_kind,
                 netuid,
-                registration_allowed,
+                SubnetHyperparameter::NetworkPowRegistrationAllowed,
+                u64::from(registration_allowed),
             );
             log::debug!(
                 "NetworkPowRegistrationAllowed( registration_allowed: {:?} ) ",
@@ -523,9 +893,11 @@ pub mod pallet {
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
                 Error::<T>::SubnetDoesNotExist
             );
-            pallet_subtensor::Pallet::<T>::set_target_registrations_per_interval(
+            Self::set_subnet_hyperparameter(
+                OriginKind::Root,
                 netuid,
-                target_registrations_per_interval,
+                SubnetHyperparameter::TargetRegistrationsPerInterval,
+                u64::from(target_registrations_per_interval),
             );
             log::debug!(
             "RegistrationPerIntervalSet( netuid: {:?} target_registrations_per_interval: {:?} ) ",
@@ -545,13 +917,23 @@ pub mod pallet {
             netuid: u16,
             min_burn: u64,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            let origin_kind = Self::ensure_subnet_owner_or_root_within_bounds(
+                origin,
+                netuid,
+                SubnetHyperparameter::MinBurn,
+                min_burn,
+            )?;
 
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
                 Error::<T>::SubnetDoesNotExist
             );
-            pallet_subtensor::Pallet::<T>::set_min_burn(netuid, min_burn);
+            Self::set_subnet_hyperparameter(
+                origin_kind,
+                netuid,
+                SubnetHyperparameter::MinBurn,
+                min_burn,
+            );
             log::debug!(
                 "MinBurnSet( netuid: {:?} min_burn: {:?} ) ",
                 netuid,
@@ -570,13 +952,23 @@ pub mod pallet {
             netuid: u16,
             max_burn: u64,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            let origin_kind = Self::ensure_subnet_owner_or_root_within_bounds(
+                origin,
+                netuid,
+                SubnetHyperparameter::MaxBurn,
+                max_burn,
+            )?;
 
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
                 Error::<T>::SubnetDoesNotExist
             );
-            pallet_subtensor::Pallet::<T>::set_max_burn(netuid, max_burn);
+            Self::set_subnet_hyperparameter(
+                origin_kind,
+                netuid,
+                SubnetHyperparameter::MaxBurn,
+                max_burn,
+            );
             log::debug!(
                 "MaxBurnSet( netuid: {:?} max_burn: {:?} ) ",
                 netuid,
@@ -595,12 +987,22 @@ pub mod pallet {
             netuid: u16,
             difficulty: u64,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            let origin_kind = Self::ensure_subnet_owner_or_root_within_bounds(
+                origin,
+                netuid,
+                SubnetHyperparameter::Difficulty,
+                difficulty,
+            )?;
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
                 Error::<T>::SubnetDoesNotExist
             );
-            pallet_subtensor::Pallet::<T>::set_difficulty(netuid, difficulty);
+            Self::set_subnet_hyperparameter(
+                origin_kind,
+                netuid,
+                SubnetHyperparameter::Difficulty,
+                difficulty,
+            );
             log::debug!(
                 "DifficultySet( netuid: {:?} difficulty: {:?} ) ",
                 netuid,
@@ -630,9 +1032,11 @@ pub mod pallet {
                 Error::<T>::MaxValidatorsLargerThanMaxUIds
             );
 
-            pallet_subtensor::Pallet::<T>::set_max_allowed_validators(
+            Self::set_subnet_hyperparameter(
+                OriginKind::Root,
                 netuid,
-                max_allowed_validators,
+                SubnetHyperparameter::MaxAllowedValidators,
+                u64::from(max_allowed_validators),
             );
             log::debug!(
                 "MaxAllowedValidatorsSet( netuid: {:?} max_allowed_validators: {:?} ) ",
@@ -652,13 +1056,23 @@ pub mod pallet {
             netuid: u16,
             bonds_moving_average: u64,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            let origin_kind = Self::ensure_subnet_owner_or_root_within_bounds(
+                origin,
+                netuid,
+                SubnetHyperparameter::BondsMovingAverage,
+                bonds_moving_average,
+            )?;
 
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
                 Error::<T>::SubnetDoesNotExist
             );
-            pallet_subtensor::Pallet::<T>::set_bonds_moving_average(netuid, bonds_moving_average);
+            Self::set_subnet_hyperparameter(
+                origin_kind,
+                netuid,
+                SubnetHyperparameter::BondsMovingAverage,
+                bonds_moving_average,
+            );
             log::debug!(
                 "BondsMovingAverageSet( netuid: {:?} bonds_moving_average: {:?} ) ",
                 netuid,
@@ -683,9 +1097,11 @@ pub mod pallet {
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
                 Error::<T>::SubnetDoesNotExist
             );
-            pallet_subtensor::Pallet::<T>::set_max_registrations_per_block(
+            Self::set_subnet_hyperparameter(
+                OriginKind::Root,
                 netuid,
-                max_registrations_per_block,
+                SubnetHyperparameter::MaxRegistrationsPerBlock,
+                u64::from(max_registrations_per_block),
             );
             log::debug!(
                 "MaxRegistrationsPerBlock( netuid: {:?} max_registrations_per_block: {:?} ) ",
@@ -710,7 +1126,15 @@ pub mod pallet {
             subnet_owner_cut: u16,
         ) -> DispatchResult {
             ensure_root(origin)?;
+            let old_value = u64::from(pallet_subtensor::Pallet::<T>::get_subnet_owner_cut());
             pallet_subtensor::Pallet::<T>::set_subnet_owner_cut(subnet_owner_cut);
+            Self::deposit_hyperparameter_changed(
+                None,
+                Hyperparameter::SubnetOwnerCut,
+                old_value,
+                u64::from(subnet_owner_cut),
+                OriginKind::Root,
+            );
             log::debug!(
                 "SubnetOwnerCut( subnet_owner_cut: {:?} ) ",
                 subnet_owner_cut
@@ -733,7 +1157,15 @@ pub mod pallet {
             rate_limit: u64,
         ) -> DispatchResult {
             ensure_root(origin)?;
+            let old_value = pallet_subtensor::NetworkRateLimit::<T>::get();
             pallet_subtensor::Pallet::<T>::set_network_rate_limit(rate_limit);
+            Self::deposit_hyperparameter_changed(
+                None,
+                Hyperparameter::NetworkRateLimit,
+                old_value,
+                rate_limit,
+                OriginKind::Root,
+            );
             log::debug!("NetworkRateLimit( rate_limit: {:?} ) ", rate_limit);
             Ok(())
         }
@@ -749,7 +1181,12 @@ pub mod pallet {
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
                 Error::<T>::SubnetDoesNotExist
             );
-            pallet_subtensor::Pallet::<T>::set_tempo(netuid, tempo);
+            Self::set_subnet_hyperparameter(
+                OriginKind::Root,
+                netuid,
+                SubnetHyperparameter::Tempo,
+                u64::from(tempo),
+            );
             log::debug!("TempoSet( netuid: {:?} tempo: {:?} ) ", netuid, tempo);
             Ok(())
         }
@@ -765,7 +1202,15 @@ pub mod pallet {
         ) -> DispatchResult {
             ensure_root(origin)?;
 
+            let old_value = pallet_subtensor::Pallet::<T>::get_total_issuance();
             pallet_subtensor::Pallet::<T>::set_total_issuance(total_issuance);
+            Self::deposit_hyperparameter_changed(
+                None,
+                Hyperparameter::TotalIssuance,
+                old_value,
+                total_issuance,
+                OriginKind::Root,
+            );
 
             Ok(())
         }
@@ -786,7 +1231,15 @@ pub mod pallet {
         ) -> DispatchResult {
             ensure_root(origin)?;
 
+            let old_value = pallet_subtensor::Pallet::<T>::get_network_immunity_period();
             pallet_subtensor::Pallet::<T>::set_network_immunity_period(immunity_period);
+            Self::deposit_hyperparameter_changed(
+                None,
+                Hyperparameter::NetworkImmunityPeriod,
+                old_value,
+                immunity_period,
+                OriginKind::Root,
+            );
 
             log::debug!("NetworkImmunityPeriod( period: {:?} ) ", immunity_period);
 
@@ -809,7 +1262,15 @@ pub mod pallet {
         ) -> DispatchResult {
             ensure_root(origin)?;
 
+            let old_value = pallet_subtensor::Pallet::<T>::get_network_min_lock();
             pallet_subtensor::Pallet::<T>::set_network_min_lock(lock_cost);
+            Self::deposit_hyperparameter_changed(
+                None,
+                Hyperparameter::NetworkMinLockCost,
+                old_value,
+                lock_cost,
+                OriginKind::Root,
+            );
 
             log::debug!("NetworkMinLockCost( lock_cost: {:?} ) ", lock_cost);
 
@@ -828,7 +1289,15 @@ pub mod pallet {
 		))]
         pub fn sudo_set_subnet_limit(origin: OriginFor<T>, max_subnets: u16) -> DispatchResult {
             ensure_root(origin)?;
+            let old_value = u64::from(pallet_subtensor::Pallet::<T>::get_max_subnets());
             pallet_subtensor::Pallet::<T>::set_max_subnets(max_subnets);
+            Self::deposit_hyperparameter_changed(
+                None,
+                Hyperparameter::SubnetLimit,
+                old_value,
+                u64::from(max_subnets),
+                OriginKind::Root,
+            );
 
             log::debug!("SubnetLimit( max_subnets: {:?} ) ", max_subnets);
 
@@ -851,7 +1320,15 @@ pub mod pallet {
         ) -> DispatchResult {
             ensure_root(origin)?;
 
+            let old_value = pallet_subtensor::Pallet::<T>::get_lock_reduction_interval();
             pallet_subtensor::Pallet::<T>::set_lock_reduction_interval(interval);
+            Self::deposit_hyperparameter_changed(
+                None,
+                Hyperparameter::LockReductionInterval,
+                old_value,
+                interval,
+                OriginKind::Root,
+            );
 
             log::debug!("NetworkLockReductionInterval( interval: {:?} ) ", interval);
 
@@ -873,7 +1350,15 @@ pub mod pallet {
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
                 Error::<T>::SubnetDoesNotExist
             );
+            let old_value = pallet_subtensor::Pallet::<T>::get_rao_recycled(netuid);
             pallet_subtensor::Pallet::<T>::set_rao_recycled(netuid, rao_recycled);
+            Self::deposit_hyperparameter_changed(
+                Some(netuid),
+                Hyperparameter::RaoRecycled,
+                old_value,
+                rao_recycled,
+                OriginKind::Root,
+            );
             Ok(())
         }
 
@@ -884,7 +1369,15 @@ pub mod pallet {
         #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
         pub fn sudo_set_weights_min_stake(origin: OriginFor<T>, min_stake: u64) -> DispatchResult {
             ensure_root(origin)?;
+            let old_value = pallet_subtensor::Pallet::<T>::get_weights_min_stake();
             pallet_subtensor::Pallet::<T>::set_weights_min_stake(min_stake);
+            Self::deposit_hyperparameter_changed(
+                None,
+                Hyperparameter::WeightsMinStake,
+                old_value,
+                min_stake,
+                OriginKind::Root,
+            );
             Ok(())
         }
 
@@ -902,6 +1395,13 @@ pub mod pallet {
             let prev_min_stake = pallet_subtensor::Pallet::<T>::get_nominator_min_required_stake();
             log::trace!("Setting minimum stake to: {}", min_stake);
             pallet_subtensor::Pallet::<T>::set_nominator_min_required_stake(min_stake);
+            Self::deposit_hyperparameter_changed(
+                None,
+                Hyperparameter::NominatorMinRequiredStake,
+                prev_min_stake,
+                min_stake,
+                OriginKind::Root,
+            );
             if min_stake > prev_min_stake {
                 log::trace!("Clearing small nominations");
                 pallet_subtensor::Pallet::<T>::clear_small_nominations();
@@ -920,7 +1420,15 @@ pub mod pallet {
             tx_rate_limit: u64,
         ) -> DispatchResult {
             ensure_root(origin)?;
+            let old_value = pallet_subtensor::Pallet::<T>::get_tx_delegate_take_rate_limit();
             pallet_subtensor::Pallet::<T>::set_tx_delegate_take_rate_limit(tx_rate_limit);
+            Self::deposit_hyperparameter_changed(
+                None,
+                Hyperparameter::TxDelegateTakeRateLimit,
+                old_value,
+                tx_rate_limit,
+                OriginKind::Root,
+            );
             log::debug!(
                 "TxRateLimitDelegateTakeSet( tx_delegate_take_rate_limit: {:?} ) ",
                 tx_rate_limit
@@ -935,7 +1443,15 @@ pub mod pallet {
         #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
         pub fn sudo_set_min_delegate_take(origin: OriginFor<T>, take: u16) -> DispatchResult {
             ensure_root(origin)?;
+            let old_value = u64::from(pallet_subtensor::Pallet::<T>::get_min_delegate_take());
             pallet_subtensor::Pallet::<T>::set_min_delegate_take(take);
+            Self::deposit_hyperparameter_changed(
+                None,
+                Hyperparameter::MinDelegateTake,
+                old_value,
+                u64::from(take),
+                OriginKind::Root,
+            );
             log::debug!("TxMinDelegateTakeSet( tx_min_delegate_take: {:?} ) ", take);
             Ok(())
         }
@@ -950,9 +1466,17 @@ pub mod pallet {
             target_stakes_per_interval: u64,
         ) -> DispatchResult {
             ensure_root(origin)?;
+            let old_value = pallet_subtensor::Pallet::<T>::get_target_stakes_per_interval();
             pallet_subtensor::Pallet::<T>::set_target_stakes_per_interval(
                 target_stakes_per_interval,
             );
+            Self::deposit_hyperparameter_changed(
+                None,
+                Hyperparameter::TargetStakesPerInterval,
+                old_value,
+                target_stakes_per_interval,
+                OriginKind::Root,
+            );
             log::debug!(
                 "TxTargetStakesPerIntervalSet( set_target_stakes_per_interval: {:?} ) ",
                 target_stakes_per_interval
@@ -970,14 +1494,24 @@ pub mod pallet {
             netuid: u16,
             interval: u64,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            let origin_kind = Self::ensure_subnet_owner_or_root_within_bounds(
+                origin,
+                netuid,
+                SubnetHyperparameter::CommitRevealWeightsInterval,
+                interval,
+            )?;
 
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
                 Error::<T>::SubnetDoesNotExist
             );
 
-            pallet_subtensor::Pallet::<T>::set_commit_reveal_weights_interval(netuid, interval);
+            Self::set_subnet_hyperparameter(
+                origin_kind,
+                netuid,
+                SubnetHyperparameter::CommitRevealWeightsInterval,
+                interval,
+            );
             log::debug!(
                 "SetWeightCommitInterval( netuid: {:?}, interval: {:?} ) ",
                 netuid,
@@ -996,14 +1530,24 @@ pub mod pallet {
             netuid: u16,
             enabled: bool,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            let origin_kind = Self::ensure_subnet_owner_or_root_within_bounds(
+                origin,
+                netuid,
+                SubnetHyperparameter::CommitRevealWeightsEnabled,
+                u64::from(enabled),
+            )?;
 
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
                 Error::<T>::SubnetDoesNotExist
             );
 
-            pallet_subtensor::Pallet::<T>::set_commit_reveal_weights_enabled(netuid, enabled);
+            Self::set_subnet_hyperparameter(
+                origin_kind,
+                netuid,
+                SubnetHyperparameter::CommitRevealWeightsEnabled,
+                u64::from(enabled),
+            );
             log::debug!("ToggleSetWeightsCommitReveal( netuid: {:?} ) ", netuid);
             Ok(())
         }
@@ -1024,8 +1568,18 @@ pub mod pallet {
             netuid: u16,
             enabled: bool,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
-            pallet_subtensor::Pallet::<T>::set_liquid_alpha_enabled(netuid, enabled);
+            let origin_kind = Self::ensure_subnet_owner_or_root_within_bounds(
+                origin,
+                netuid,
+                SubnetHyperparameter::LiquidAlphaEnabled,
+                u64::from(enabled),
+            )?;
+            Self::set_subnet_hyperparameter(
+                origin_kind,
+                netuid,
+                SubnetHyperparameter::LiquidAlphaEnabled,
+                u64::from(enabled),
+            );
             log::debug!(
                 "LiquidAlphaEnableToggled( netuid: {:?}, Enabled: {:?} ) ",
                 netuid,
@@ -1044,9 +1598,41 @@ pub mod pallet {
             alpha_high: u16,
         ) -> DispatchResult {
             pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin.clone(), netuid)?;
-            pallet_subtensor::Pallet::<T>::do_set_alpha_values(
-                origin, netuid, alpha_low, alpha_high,
-            )
+            pallet_subtensor::Pallet::<T>::ensure_valid_alpha_values(
+                netuid, alpha_low, alpha_high,
+            )?;
+            let origin_kind = Self::ensure_subnet_owner_or_root_within_bounds(
+                origin.clone(),
+                netuid,
+                SubnetHyperparameter::AlphaLow,
+                u64::from(alpha_low),
+            )?;
+            Self::ensure_subnet_owner_or_root_within_bounds(
+                origin,
+                netuid,
+                SubnetHyperparameter::AlphaHigh,
+                u64::from(alpha_high),
+            )?;
+
+            Self::set_subnet_hyperparameter(
+                origin_kind,
+                netuid,
+                SubnetHyperparameter::AlphaLow,
+                u64::from(alpha_low),
+            );
+            Self::set_subnet_hyperparameter(
+                origin_kind,
+                netuid,
+                SubnetHyperparameter::AlphaHigh,
+                u64::from(alpha_high),
+            );
+            log::debug!(
+                "AlphaValuesSet( netuid: {:?}, AlphaLow: {:?}, AlphaHigh: {:?} ) ",
+                netuid,
+                alpha_low,
+                alpha_high
+            );
+            Ok(())
         }
 
         /// Sets the hotkey emission tempo.
@@ -1071,7 +1657,15 @@ pub mod pallet {
             emission_tempo: u64,
         ) -> DispatchResult {
             ensure_root(origin)?;
+            let old_value = pallet_subtensor::Pallet::<T>::get_hotkey_emission_tempo();
             pallet_subtensor::Pallet::<T>::set_hotkey_emission_tempo(emission_tempo);
+            Self::deposit_hyperparameter_changed(
+                None,
+                Hyperparameter::HotkeyEmissionTempo,
+                old_value,
+                emission_tempo,
+                OriginKind::Root,
+            );
             log::debug!(
                 "HotkeyEmissionTempoSet( emission_tempo: {:?} )",
                 emission_tempo
@@ -1118,7 +1712,12 @@ pub mod pallet {
             ensure_root(origin)?;
 
             // Set the new maximum stake for the specified network
-            pallet_subtensor::Pallet::<T>::set_network_max_stake(netuid, max_stake);
+            Self::set_subnet_hyperparameter(
+                OriginKind::Root,
+                netuid,
+                SubnetHyperparameter::NetworkMaxStake,
+                max_stake,
+            );
 
             // Log the change
             log::trace!(
@@ -1154,7 +1753,16 @@ pub mod pallet {
             ensure_root(origin)?;
 
             // Set the new duration of schedule coldkey swap
+            let old_value =
+                pallet_subtensor::ColdkeySwapScheduleDuration::<T>::get().saturated_into();
             pallet_subtensor::Pallet::<T>::set_coldkey_swap_schedule_duration(duration);
+            Self::deposit_hyperparameter_changed(
+                None,
+                Hyperparameter::ColdkeySwapScheduleDuration,
+                old_value,
+                duration.saturated_into(),
+                OriginKind::Root,
+            );
 
             // Log the change
             log::trace!("ColdkeySwapScheduleDurationSet( duration: {:?} )", duration);
@@ -1186,7 +1794,16 @@ pub mod pallet {
             ensure_root(origin)?;
 
             // Set the duration of schedule dissolve network
+            let old_value =
+                pallet_subtensor::DissolveNetworkScheduleDuration::<T>::get().saturated_into();
             pallet_subtensor::Pallet::<T>::set_dissolve_network_schedule_duration(duration);
+            Self::deposit_hyperparameter_changed(
+                None,
+                Hyperparameter::DissolveNetworkScheduleDuration,
+                old_value,
+                duration.saturated_into(),
+                OriginKind::Root,
+            );
 
             // Log the change
             log::trace!(
@@ -1196,6 +1813,949 @@ pub mod pallet {
 
             Ok(())
         }
+
+        /// Sets the duration of the subnet owner transfer schedule.
+        ///
+        /// This extrinsic allows the root account to set the delay between a subnet owner proposing
+        /// an ownership transfer and the new owner being able to accept it.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account.
+        /// * `duration` - The new duration for the subnet owner transfer schedule, in number of blocks.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is not the root account.
+        ///
+        /// # Weight
+        /// Weight is handled by the `#[pallet::weight]` attribute.
+        #[pallet::call_index(56)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_subnet_owner_transfer_schedule_duration(
+            origin: OriginFor<T>,
+            duration: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            // Ensure the call is made by the root account
+            ensure_root(origin)?;
+
+            // Set the duration of subnet owner transfers
+            let old_value =
+                pallet_subtensor::SubnetOwnerTransferScheduleDuration::<T>::get().saturated_into();
+            pallet_subtensor::Pallet::<T>::set_subnet_owner_transfer_schedule_duration(duration);
+            Self::deposit_hyperparameter_changed(
+                None,
+                Hyperparameter::SubnetOwnerTransferScheduleDuration,
+                old_value,
+                duration.saturated_into(),
+                OriginKind::Root,
+            );
+
+            // Log the change
+            log::trace!(
+                "SubnetOwnerTransferScheduleDurationSet( duration: {:?} )",
+                duration
+            );
+
+            Ok(())
+        }
+
+        /// Sets the senate adjustment interval.
+        ///
+        /// This extrinsic allows the root account to set the number of blocks between automatic
+        /// rebuilds of the senate from the top root network stakers. Setting it to 0 disables them.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account.
+        /// * `interval` - The new senate adjustment interval, in number of blocks.
+        ///
+        /// # Emits
+        /// * `Event::SenateAdjustmentIntervalSet` - When the interval is successfully set.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is not the root account.
+        #[pallet::call_index(57)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_senate_adjustment_interval(
+            origin: OriginFor<T>,
+            interval: u64,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            let old_value = pallet_subtensor::Pallet::<T>::get_senate_adjustment_interval();
+            pallet_subtensor::Pallet::<T>::set_senate_adjustment_interval(interval);
+            Self::deposit_hyperparameter_changed(
+                None,
+                Hyperparameter::SenateAdjustmentInterval,
+                old_value,
+                interval,
+                OriginKind::Root,
+            );
+            log::debug!("SenateAdjustmentIntervalSet( interval: {:?} )", interval);
+            Ok(())
+        }
+
+        /// Sets the hyperparameter enactment delay.
+        ///
+        /// This extrinsic allows the root account to set the number of blocks between a subnet
+        /// hyperparameter change and its enactment, giving subnet participants notice of the change.
+        /// Setting it to 0 applies changes immediately.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account.
+        /// * `delay` - The new enactment delay, in number of blocks.
+        ///
+        /// # Emits
+        /// * `Event::HyperparameterEnactmentDelaySet` - When the delay is successfully set.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is not the root account.
+        #[pallet::call_index(58)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_hyperparameter_enactment_delay(
+            origin: OriginFor<T>,
+            delay: u64,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            let old_value = pallet_subtensor::HyperparameterEnactmentDelay::<T>::get();
+            pallet_subtensor::Pallet::<T>::set_hyperparameter_enactment_delay(delay);
+            Self::deposit_hyperparameter_changed(
+                None,
+                Hyperparameter::HyperparameterEnactmentDelay,
+                old_value,
+                delay,
+                OriginKind::Root,
+            );
+            log::debug!("HyperparameterEnactmentDelaySet( delay: {:?} )", delay);
+            Ok(())
+        }
+
+        /// Cancels a pending subnet hyperparameter change before it is enacted.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be allowed to set the hyperparameter: the
+        ///   root account for root-only hyperparameters, otherwise the root account or subnet owner.
+        /// * `netuid` - The unique identifier of the subnet.
+        /// * `param` - The hyperparameter whose pending change is cancelled.
+        ///
+        /// # Emits
+        /// * `Event::HyperparameterChangeCancelled` - When the pending change is removed.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller may not set the hyperparameter.
+        /// * `NoPendingHyperparameterChange` - If no change of `param` is pending for the subnet.
+        #[pallet::call_index(59)]
+        #[pallet::weight((
+            Weight::from_parts(10_000_000, 0)
+                .saturating_add(T::DbWeight::get().reads_writes(3, 2)),
+            DispatchClass::Operational,
+            Pays::No
+        ))]
+        pub fn cancel_pending_hyperparameter_change(
+            origin: OriginFor<T>,
+            netuid: u16,
+            param: SubnetHyperparameter,
+        ) -> DispatchResult {
+            pallet_subtensor::Pallet::<T>::ensure_subnet_hyperparameter_origin(
+                origin, netuid, param,
+            )?;
+            pallet_subtensor::Pallet::<T>::cancel_pending_hyperparameter_change(netuid, param)?;
+            PendingHyperparameterChangeOrigins::<T>::remove(netuid, param);
+            log::debug!(
+                "HyperparameterChangeCancelled( netuid: {:?}, param: {:?} )",
+                netuid,
+                param
+            );
+            Ok(())
+        }
+
+        /// Sets the range a subnet owner may set a hyperparameter to.
+        ///
+        /// Root is not bound by the range. Setting the full `u64` range removes the bounds.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account.
+        /// * `param` - The hyperparameter to bound.
+        /// * `min` - The lowest value a subnet owner may set.
+        /// * `max` - The highest value a subnet owner may set.
+        ///
+        /// # Emits
+        /// * `Event::HyperparameterBoundsSet` - When the bounds are successfully set.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is not the root account.
+        /// * `InvalidHyperparameterBounds` - If `min` is above `max`.
+        #[pallet::call_index(60)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_hyperparameter_bounds(
+            origin: OriginFor<T>,
+            param: SubnetHyperparameter,
+            min: u64,
+            max: u64,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            pallet_subtensor::Pallet::<T>::set_hyperparameter_bounds(param, min, max)?;
+            log::debug!(
+                "HyperparameterBoundsSet( param: {:?}, min: {:?}, max: {:?} )",
+                param,
+                min,
+                max
+            );
+            Ok(())
+        }
+
+        /// Sets several hyperparameters of a subnet at once.
+        ///
+        /// Every field of the patch is checked with the rules of its own `sudo_set_*` call, including
+        /// which origin may set it. If any field fails, none of the patch is applied.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account or subnet owner.
+        /// * `netuid` - The unique identifier of the subnet.
+        /// * `patch` - The hyperparameters to set, `None` fields are left unchanged.
+        ///
+        /// # Emits
+        /// * `Event::HyperparameterChanged` - For every hyperparameter in the patch.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller may not set one of the hyperparameters in the patch.
+        /// * `SubnetDoesNotExist` - If the subnet does not exist.
+        /// * Any error of the `sudo_set_*` call of a hyperparameter in the patch.
+        #[pallet::call_index(61)]
+        #[pallet::weight((
+            Pallet::<T>::sudo_set_hyperparameters_weight(patch),
+            DispatchClass::Operational,
+            Pays::No
+        ))]
+        pub fn sudo_set_hyperparameters(
+            origin: OriginFor<T>,
+            netuid: u16,
+            patch: HyperparamPatch,
+        ) -> DispatchResult {
+            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin.clone(), netuid)?;
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+
+            if let Some(rho) = patch.rho {
+                Self::sudo_set_rho(origin.clone(), netuid, rho)?;
+            }
+            if let Some(kappa) = patch.kappa {
+                Self::sudo_set_kappa(origin.clone(), netuid, kappa)?;
+            }
+            if let Some(immunity_period) = patch.immunity_period {
+                Self::sudo_set_immunity_period(origin.clone(), netuid, immunity_period)?;
+            }
+            if let Some(min_allowed_weights) = patch.min_allowed_weights {
+                Self::sudo_set_min_allowed_weights(origin.clone(), netuid, min_allowed_weights)?;
+            }
+            if let Some(max_weight_limit) = patch.max_weights_limit {
+                Self::sudo_set_max_weight_limit(origin.clone(), netuid, max_weight_limit)?;
+            }
+            if let Some(tempo) = patch.tempo {
+                Self::sudo_set_tempo(origin.clone(), netuid, tempo)?;
+            }
+            if let Some(min_difficulty) = patch.min_difficulty {
+                Self::sudo_set_min_difficulty(origin.clone(), netuid, min_difficulty)?;
+            }
+            if let Some(max_difficulty) = patch.max_difficulty {
+                Self::sudo_set_max_difficulty(origin.clone(), netuid, max_difficulty)?;
+            }
+            if let Some(weights_version_key) = patch.weights_version {
+                Self::sudo_set_weights_version_key(origin.clone(), netuid, weights_version_key)?;
+            }
+            if let Some(weights_set_rate_limit) = patch.weights_rate_limit {
+                Self::sudo_set_weights_set_rate_limit(
+                    origin.clone(),
+                    netuid,
+                    weights_set_rate_limit,
+                )?;
+            }
+            if let Some(adjustment_interval) = patch.adjustment_interval {
+                Self::sudo_set_adjustment_interval(origin.clone(), netuid, adjustment_interval)?;
+            }
+            if let Some(activity_cutoff) = patch.activity_cutoff {
+                Self::sudo_set_activity_cutoff(origin.clone(), netuid, activity_cutoff)?;
+            }
+            if let Some(registration_allowed) = patch.registration_allowed {
+                Self::sudo_set_network_registration_allowed(
+                    origin.clone(),
+                    netuid,
+                    registration_allowed,
+                )?;
+            }
+            if let Some(target_registrations_per_interval) = patch.target_regs_per_interval {
+                Self::sudo_set_target_registrations_per_interval(
+                    origin.clone(),
+                    netuid,
+                    target_registrations_per_interval,
+                )?;
+            }
+            if let Some(min_burn) = patch.min_burn {
+                Self::sudo_set_min_burn(origin.clone(), netuid, min_burn)?;
+            }
+            if let Some(max_burn) = patch.max_burn {
+                Self::sudo_set_max_burn(origin.clone(), netuid, max_burn)?;
+            }
+            if let Some(bonds_moving_average) = patch.bonds_moving_avg {
+                Self::sudo_set_bonds_moving_average(origin.clone(), netuid, bonds_moving_average)?;
+            }
+            if let Some(max_registrations_per_block) = patch.max_regs_per_block {
+                Self::sudo_set_max_registrations_per_block(
+                    origin.clone(),
+                    netuid,
+                    max_registrations_per_block,
+                )?;
+            }
+            if let Some(serving_rate_limit) = patch.serving_rate_limit {
+                Self::sudo_set_serving_rate_limit(origin.clone(), netuid, serving_rate_limit)?;
+            }
+            if let Some(max_allowed_validators) = patch.max_validators {
+                Self::sudo_set_max_allowed_validators(
+                    origin.clone(),
+                    netuid,
+                    max_allowed_validators,
+                )?;
+            }
+            if let Some(adjustment_alpha) = patch.adjustment_alpha {
+                Self::sudo_set_adjustment_alpha(origin.clone(), netuid, adjustment_alpha)?;
+            }
+            if let Some(difficulty) = patch.difficulty {
+                Self::sudo_set_difficulty(origin.clone(), netuid, difficulty)?;
+            }
+            if let Some(interval) = patch.commit_reveal_weights_interval {
+                Self::sudo_set_commit_reveal_weights_interval(origin.clone(), netuid, interval)?;
+            }
+            if let Some(enabled) = patch.commit_reveal_weights_enabled {
+                Self::sudo_set_commit_reveal_weights_enabled(origin.clone(), netuid, enabled)?;
+            }
+            // Liquid alpha must be enabled before its alpha values can be set.
+            if let Some(enabled) = patch.liquid_alpha_enabled {
+                Self::sudo_set_liquid_alpha_enabled(origin.clone(), netuid, enabled)?;
+            }
+            if patch.alpha_low.is_some() || patch.alpha_high.is_some() {
+                let (alpha_low, alpha_high) =
+                    pallet_subtensor::Pallet::<T>::get_alpha_values(netuid);
+                Self::sudo_set_alpha_values(
+                    origin.clone(),
+                    netuid,
+                    patch.alpha_low.unwrap_or(alpha_low),
+                    patch.alpha_high.unwrap_or(alpha_high),
+                )?;
+            }
+            if let Some(max_children) = patch.max_children {
+                Self::sudo_set_max_children(origin.clone(), netuid, max_children)?;
+            }
+            if let Some(proportion) = patch.min_child_proportion {
+                Self::sudo_set_min_child_proportion(origin.clone(), netuid, proportion)?;
+            }
+            if let Some(cooldown) = patch.set_children_cooldown {
+                Self::sudo_set_set_children_cooldown(origin.clone(), netuid, cooldown)?;
+            }
+            // The take bounds are set in the order which keeps the lower bound below the upper one.
+            match (patch.min_childkey_take, patch.max_childkey_take) {
+                (Some(min_take), Some(max_take))
+                    if min_take
+                        > pallet_subtensor::Pallet::<T>::get_subnet_max_childkey_take(netuid) =>
+                {
+                    Self::sudo_set_subnet_max_childkey_take(origin.clone(), netuid, max_take)?;
+                    Self::sudo_set_subnet_min_childkey_take(origin, netuid, min_take)?;
+                }
+                (min_take, max_take) => {
+                    if let Some(min_take) = min_take {
+                        Self::sudo_set_subnet_min_childkey_take(origin.clone(), netuid, min_take)?;
+                    }
+                    if let Some(max_take) = max_take {
+                        Self::sudo_set_subnet_max_childkey_take(origin, netuid, max_take)?;
+                    }
+                }
+            }
+
+            log::debug!(
+                "HyperparametersSet( netuid: {:?}, patch: {:?} )",
+                netuid,
+                patch
+            );
+            Ok(())
+        }
+
+        /// Sets a hyperparameter template which new subnets can be registered with.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account.
+        /// * `template_id` - The id of the template, an existing template is replaced.
+        /// * `template` - The name and hyperparameters of the template.
+        ///
+        /// # Emits
+        /// * `Event::HyperparameterTemplateSet` - When the template is successfully set.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is not the root account.
+        /// * `InvalidHyperparameterTemplate` - If the template is not valid.
+        #[pallet::call_index(62)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_hyperparameter_template(
+            origin: OriginFor<T>,
+            template_id: u16,
+            template: HyperparameterTemplate,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            pallet_subtensor::Pallet::<T>::set_hyperparameter_template(template_id, template)?;
+            log::debug!(
+                "HyperparameterTemplateSet( template_id: {:?} )",
+                template_id
+            );
+            Ok(())
+        }
+
+        /// Removes a hyperparameter template.
+        ///
+        /// Subnets registered with the template keep their hyperparameters.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account.
+        /// * `template_id` - The id of the template to remove.
+        ///
+        /// # Emits
+        /// * `Event::HyperparameterTemplateRemoved` - When the template is removed.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is not the root account.
+        /// * `HyperparameterTemplateNotFound` - If no template exists with the id.
+        #[pallet::call_index(63)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_remove_hyperparameter_template(
+            origin: OriginFor<T>,
+            template_id: u16,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            pallet_subtensor::Pallet::<T>::remove_hyperparameter_template(template_id)?;
+            log::debug!(
+                "HyperparameterTemplateRemoved( template_id: {:?} )",
+                template_id
+            );
+            Ok(())
+        }
+
+        /// Sets the delegate take notice period.
+        ///
+        /// This extrinsic allows the root account to set the number of blocks between a delegate
+        /// announcing a take increase and the increase applying, giving nominators time to leave.
+        /// Setting it to 0 applies increases immediately.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account.
+        /// * `period` - The new notice period, in number of blocks.
+        ///
+        /// # Emits
+        /// * `Event::DelegateTakeNoticePeriodSet` - When the notice period is successfully set.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is not the root account.
+        #[pallet::call_index(64)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_delegate_take_notice_period(
+            origin: OriginFor<T>,
+            period: u64,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            let old_value = pallet_subtensor::Pallet::<T>::get_delegate_take_notice_period();
+            pallet_subtensor::Pallet::<T>::set_delegate_take_notice_period(period);
+            Self::deposit_hyperparameter_changed(
+                None,
+                Hyperparameter::DelegateTakeNoticePeriod,
+                old_value,
+                period,
+                OriginKind::Root,
+            );
+            log::debug!("DelegateTakeNoticePeriodSet( period: {:?} )", period);
+            Ok(())
+        }
+        /// Sets the maximum number of children a hotkey may have on a subnet.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account or subnet owner.
+        /// * `netuid` - The unique identifier of the subnet.
+        /// * `max_children` - The new maximum number of children.
+        ///
+        /// # Emits
+        /// * `Event::HyperparameterChanged` - When the maximum is set or scheduled.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is neither root nor the subnet owner.
+        /// * `SubnetDoesNotExist` - If the subnet does not exist.
+        /// * `OutsideChildkeyLimits` - If `max_children` is above `MAX_CHILDREN_LIMIT`.
+        #[pallet::call_index(65)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_max_children(
+            origin: OriginFor<T>,
+            netuid: u16,
+            max_children: u16,
+        ) -> DispatchResult {
+            let origin_kind = Self::ensure_subnet_owner_or_root_within_bounds(
+                origin,
+                netuid,
+                SubnetHyperparameter::MaxChildren,
+                u64::from(max_children),
+            )?;
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+            ensure!(
+                pallet_subtensor::Pallet::<T>::is_within_childkey_limits(
+                    SubnetHyperparameter::MaxChildren,
+                    u64::from(max_children)
+                ),
+                Error::<T>::OutsideChildkeyLimits
+            );
+
+            Self::set_subnet_hyperparameter(
+                origin_kind,
+                netuid,
+                SubnetHyperparameter::MaxChildren,
+                u64::from(max_children),
+            );
+            log::debug!(
+                "MaxChildrenSet( netuid: {:?} max_children: {:?} ) ",
+                netuid,
+                max_children
+            );
+            Ok(())
+        }
+
+        /// Sets the minimum proportion each child of a hotkey must get on a subnet.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account or subnet owner.
+        /// * `netuid` - The unique identifier of the subnet.
+        /// * `proportion` - The new minimum proportion, out of `u64::MAX`.
+        ///
+        /// # Emits
+        /// * `Event::HyperparameterChanged` - When the minimum is set or scheduled.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is neither root nor the subnet owner.
+        /// * `SubnetDoesNotExist` - If the subnet does not exist.
+        #[pallet::call_index(66)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_min_child_proportion(
+            origin: OriginFor<T>,
+            netuid: u16,
+            proportion: u64,
+        ) -> DispatchResult {
+            let origin_kind = Self::ensure_subnet_owner_or_root_within_bounds(
+                origin,
+                netuid,
+                SubnetHyperparameter::MinChildProportion,
+                proportion,
+            )?;
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+
+            Self::set_subnet_hyperparameter(
+                origin_kind,
+                netuid,
+                SubnetHyperparameter::MinChildProportion,
+                proportion,
+            );
+            log::debug!(
+                "MinChildProportionSet( netuid: {:?} proportion: {:?} ) ",
+                netuid,
+                proportion
+            );
+            Ok(())
+        }
+
+        /// Sets the blocks a hotkey must wait between setting its children on a subnet.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account or subnet owner.
+        /// * `netuid` - The unique identifier of the subnet.
+        /// * `cooldown` - The new cooldown, in number of blocks.
+        ///
+        /// # Emits
+        /// * `Event::HyperparameterChanged` - When the cooldown is set or scheduled.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is neither root nor the subnet owner.
+        /// * `SubnetDoesNotExist` - If the subnet does not exist.
+        /// * `OutsideChildkeyLimits` - If `cooldown` is below `MIN_SET_CHILDREN_COOLDOWN`.
+        #[pallet::call_index(67)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_set_children_cooldown(
+            origin: OriginFor<T>,
+            netuid: u16,
+            cooldown: u64,
+        ) -> DispatchResult {
+            let origin_kind = Self::ensure_subnet_owner_or_root_within_bounds(
+                origin,
+                netuid,
+                SubnetHyperparameter::SetChildrenCooldown,
+                cooldown,
+            )?;
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+            ensure!(
+                pallet_subtensor::Pallet::<T>::is_within_childkey_limits(
+                    SubnetHyperparameter::SetChildrenCooldown,
+                    cooldown
+                ),
+                Error::<T>::OutsideChildkeyLimits
+            );
+
+            Self::set_subnet_hyperparameter(
+                origin_kind,
+                netuid,
+                SubnetHyperparameter::SetChildrenCooldown,
+                cooldown,
+            );
+            log::debug!(
+                "SetChildrenCooldownSet( netuid: {:?} cooldown: {:?} ) ",
+                netuid,
+                cooldown
+            );
+            Ok(())
+        }
+
+        /// Sets the lower bound of the childkey take on a subnet.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account or subnet owner.
+        /// * `netuid` - The unique identifier of the subnet.
+        /// * `take` - The new lower bound, out of `u16::MAX`.
+        ///
+        /// # Emits
+        /// * `Event::HyperparameterChanged` - When the bound is set or scheduled.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is neither root nor the subnet owner.
+        /// * `SubnetDoesNotExist` - If the subnet does not exist.
+        /// * `InvalidChildkeyTakeBounds` - If `take` is above the upper bound of the subnet.
+        /// * `OutsideChildkeyLimits` - If `take` is outside the global childkey take bounds.
+        #[pallet::call_index(68)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_subnet_min_childkey_take(
+            origin: OriginFor<T>,
+            netuid: u16,
+            take: u16,
+        ) -> DispatchResult {
+            let origin_kind = Self::ensure_subnet_owner_or_root_within_bounds(
+                origin,
+                netuid,
+                SubnetHyperparameter::MinChildkeyTake,
+                u64::from(take),
+            )?;
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+            ensure!(
+                pallet_subtensor::Pallet::<T>::is_within_childkey_limits(
+                    SubnetHyperparameter::MinChildkeyTake,
+                    u64::from(take)
+                ),
+                Error::<T>::OutsideChildkeyLimits
+            );
+            ensure!(
+                take <= pallet_subtensor::Pallet::<T>::get_subnet_max_childkey_take(netuid),
+                Error::<T>::InvalidChildkeyTakeBounds
+            );
+
+            Self::set_subnet_hyperparameter(
+                origin_kind,
+                netuid,
+                SubnetHyperparameter::MinChildkeyTake,
+                u64::from(take),
+            );
+            log::debug!(
+                "SubnetMinChildkeyTakeSet( netuid: {:?} take: {:?} ) ",
+                netuid,
+                take
+            );
+            Ok(())
+        }
+
+        /// Sets the upper bound of the childkey take on a subnet.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account or subnet owner.
+        /// * `netuid` - The unique identifier of the subnet.
+        /// * `take` - The new upper bound, out of `u16::MAX`.
+        ///
+        /// # Emits
+        /// * `Event::HyperparameterChanged` - When the bound is set or scheduled.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is neither root nor the subnet owner.
+        /// * `SubnetDoesNotExist` - If the subnet does not exist.
+        /// * `InvalidChildkeyTakeBounds` - If `take` is below the lower bound of the subnet.
+        /// * `OutsideChildkeyLimits` - If `take` is outside the global childkey take bounds.
+        #[pallet::call_index(69)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_subnet_max_childkey_take(
+            origin: OriginFor<T>,
+            netuid: u16,
+            take: u16,
+        ) -> DispatchResult {
+            let origin_kind = Self::ensure_subnet_owner_or_root_within_bounds(
+                origin,
+                netuid,
+                SubnetHyperparameter::MaxChildkeyTake,
+                u64::from(take),
+            )?;
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+            ensure!(
+                pallet_subtensor::Pallet::<T>::is_within_childkey_limits(
+                    SubnetHyperparameter::MaxChildkeyTake,
+                    u64::from(take)
+                ),
+                Error::<T>::OutsideChildkeyLimits
+            );
+            ensure!(
+                take >= pallet_subtensor::Pallet::<T>::get_subnet_min_childkey_take(netuid),
+                Error::<T>::InvalidChildkeyTakeBounds
+            );
+
+            Self::set_subnet_hyperparameter(
+                origin_kind,
+                netuid,
+                SubnetHyperparameter::MaxChildkeyTake,
+                u64::from(take),
+            );
+            log::debug!(
+                "SubnetMaxChildkeyTakeSet( netuid: {:?} take: {:?} ) ",
+                netuid,
+                take
+            );
+            Ok(())
+        }
+
+        /// Sets the unbonding period.
+        ///
+        /// This extrinsic allows the root account to set the number of blocks removed stake waits
+        /// in the unbonding queue of its coldkey before it can be withdrawn. Setting it to 0
+        /// returns removed stake to the free balance immediately.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account.
+        /// * `period` - The new unbonding period, in number of blocks.
+        ///
+        /// # Emits
+        /// * `Event::UnbondingPeriodSet` - When the unbonding period is successfully set.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is not the root account.
+        #[pallet::call_index(70)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_unbonding_period(origin: OriginFor<T>, period: u64) -> DispatchResult {
+            ensure_root(origin)?;
+            let old_value = pallet_subtensor::Pallet::<T>::get_unbonding_period();
+            pallet_subtensor::Pallet::<T>::set_unbonding_period(period);
+            Self::deposit_hyperparameter_changed(
+                None,
+                Hyperparameter::UnbondingPeriod,
+                old_value,
+                period,
+                OriginKind::Root,
+            );
+            log::debug!("UnbondingPeriodSet( period: {:?} )", period);
+            Ok(())
+        }
+
+        /// Enables or disables stake weighted senate voting.
+        ///
+        /// This extrinsic allows the root account to choose whether senate motions are tallied by
+        /// the total stake of the voting members instead of one vote per member. It applies to
+        /// motions proposed after the change.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account.
+        /// * `enabled` - Whether senate motions are tallied by stake.
+        ///
+        /// # Emits
+        /// * `Event::SenateStakeWeightedVotingSet` - When the flag is successfully set.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is not the root account.
+        #[pallet::call_index(71)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_senate_stake_weighted_voting(
+            origin: OriginFor<T>,
+            enabled: bool,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            let old_value = pallet_subtensor::Pallet::<T>::get_senate_stake_weighted_voting();
+            pallet_subtensor::Pallet::<T>::set_senate_stake_weighted_voting(enabled);
+            Self::deposit_hyperparameter_changed(
+                None,
+                Hyperparameter::SenateStakeWeightedVoting,
+                u64::from(old_value),
+                u64::from(enabled),
+                OriginKind::Root,
+            );
+            log::debug!("SenateStakeWeightedVotingSet( enabled: {:?} )", enabled);
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Returns the weight of the `sudo_set_*` calls of the hyperparameters in a patch.
+        fn sudo_set_hyperparameters_weight(patch: &HyperparamPatch) -> Weight {
+            let inline_weight = Weight::from_parts(14_000_000, 0)
+                .saturating_add(T::DbWeight::get().reads_writes(1, 1));
+            [
+                patch.rho.map(|_| T::WeightInfo::sudo_set_rho()),
+                patch.kappa.map(|_| T::WeightInfo::sudo_set_kappa()),
+                patch
+                    .immunity_period
+                    .map(|_| T::WeightInfo::sudo_set_immunity_period()),
+                patch
+                    .min_allowed_weights
+                    .map(|_| T::WeightInfo::sudo_set_min_allowed_weights()),
+                patch
+                    .max_weights_limit
+                    .map(|_| T::WeightInfo::sudo_set_max_weight_limit()),
+                patch.tempo.map(|_| T::WeightInfo::sudo_set_tempo()),
+                patch
+                    .min_difficulty
+                    .map(|_| T::WeightInfo::sudo_set_min_difficulty()),
+                patch
+                    .max_difficulty
+                    .map(|_| T::WeightInfo::sudo_set_max_difficulty()),
+                patch
+                    .weights_version
+                    .map(|_| T::WeightInfo::sudo_set_weights_version_key()),
+                patch
+                    .weights_rate_limit
+                    .map(|_| T::WeightInfo::sudo_set_weights_set_rate_limit()),
+                patch
+                    .adjustment_interval
+                    .map(|_| T::WeightInfo::sudo_set_adjustment_interval()),
+                patch
+                    .activity_cutoff
+                    .map(|_| T::WeightInfo::sudo_set_activity_cutoff()),
+                patch.registration_allowed.map(|_| inline_weight),
+                patch
+                    .target_regs_per_interval
+                    .map(|_| T::WeightInfo::sudo_set_target_registrations_per_interval()),
+                patch.min_burn.map(|_| T::WeightInfo::sudo_set_min_burn()),
+                patch.max_burn.map(|_| T::WeightInfo::sudo_set_max_burn()),
+                patch
+                    .bonds_moving_avg
+                    .map(|_| T::WeightInfo::sudo_set_bonds_moving_average()),
+                patch
+                    .max_regs_per_block
+                    .map(|_| T::WeightInfo::sudo_set_max_registrations_per_block()),
+                patch
+                    .serving_rate_limit
+                    .map(|_| T::WeightInfo::sudo_set_serving_rate_limit()),
+                patch
+                    .max_validators
+                    .map(|_| T::WeightInfo::sudo_set_max_allowed_validators()),
+                patch.adjustment_alpha.map(|_| inline_weight),
+                patch
+                    .difficulty
+                    .map(|_| T::WeightInfo::sudo_set_difficulty()),
+                patch
+                    .commit_reveal_weights_interval
+                    .map(|_| T::WeightInfo::sudo_set_commit_reveal_weights_interval()),
+                patch
+                    .commit_reveal_weights_enabled
+                    .map(|_| T::WeightInfo::sudo_set_commit_reveal_weights_enabled()),
+                patch.liquid_alpha_enabled.map(|_| inline_weight),
+                patch.alpha_low.or(patch.alpha_high).map(|_| inline_weight),
+                patch.max_children.map(|_| inline_weight),
+                patch.min_child_proportion.map(|_| inline_weight),
+                patch.set_children_cooldown.map(|_| inline_weight),
+                patch.min_childkey_take.map(|_| inline_weight),
+                patch.max_childkey_take.map(|_| inline_weight),
+            ]
+            .into_iter()
+            .flatten()
+            .fold(T::DbWeight::get().reads(2), |total, weight| {
+                total.saturating_add(weight)
+            })
+        }
+
+        /// Returns whether a subnet owner or root origin is root.
+        fn origin_kind(origin: &OriginFor<T>) -> OriginKind {
+            if ensure_root(origin.clone()).is_ok() {
+                OriginKind::Root
+            } else {
+                OriginKind::SubnetOwner
+            }
+        }
+
+        /// Ensures the origin is the subnet owner or root, and that a subnet owner stays within the
+        /// bounds of the hyperparameter. Returns the kind of the origin.
+        fn ensure_subnet_owner_or_root_within_bounds(
+            origin: OriginFor<T>,
+            netuid: u16,
+            param: SubnetHyperparameter,
+            value: u64,
+        ) -> Result<OriginKind, DispatchError> {
+            let origin_kind = Self::origin_kind(&origin);
+            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root_within_bounds(
+                origin, netuid, param, value,
+            )?;
+            Ok(origin_kind)
+        }
+
+        /// Sets or schedules a subnet hyperparameter and records the change. A scheduled change is
+        /// recorded again by `on_initialize` once it applies.
+        fn set_subnet_hyperparameter(
+            origin_kind: OriginKind,
+            netuid: u16,
+            param: SubnetHyperparameter,
+            value: u64,
+        ) {
+            let old_value = pallet_subtensor::Pallet::<T>::get_subnet_hyperparameter(netuid, param);
+            match pallet_subtensor::Pallet::<T>::set_or_schedule_subnet_hyperparameter(
+                netuid, param, value,
+            ) {
+                Some(effective_block) => {
+                    PendingHyperparameterChangeOrigins::<T>::insert(netuid, param, origin_kind);
+                    Self::deposit_event(Event::HyperparameterChangeScheduled {
+                        netuid,
+                        param: Hyperparameter::Subnet(param),
+                        old_value,
+                        new_value: value,
+                        effective_block,
+                        origin_kind,
+                    });
+                }
+                None => Self::deposit_hyperparameter_changed(
+                    Some(netuid),
+                    Hyperparameter::Subnet(param),
+                    old_value,
+                    value,
+                    origin_kind,
+                ),
+            }
+        }
+
+        /// Emits a HyperparameterChanged event.
+        fn deposit_hyperparameter_changed(
+            netuid: Option<u16>,
+            param: Hyperparameter,
+            old_value: u64,
+            new_value: u64,
+            origin_kind: OriginKind,
+        ) {
+            Self::deposit_event(Event::HyperparameterChanged {
+                netuid,
+                param,
+                old_value,
+                new_value,
+                origin_kind,
+            });
+        }
     }
 }
 
@@ -125,6 +125,9 @@ parameter_types! {
     pub const InitialNetworkMaxStake: u64 = u64::MAX; // Maximum possible value for u64, this make the make stake infinity
     pub const InitialColdkeySwapScheduleDuration: u64 = 5 * 24 * 60 * 60 / 12; // 5 days
     pub const InitialDissolveNetworkScheduleDuration: u64 = 5 * 24 * 60 * 60 / 12; // 5 days
+    pub const InitialSubnetOwnerTransferScheduleDuration: u64 = 5 * 24 * 60 * 60 / 12; // 5 days
+    pub const InitialSenateAdjustmentInterval: u64 = 0; // Disabled
+    pub const InitialDelegateTakeNoticePeriod: u64 = 0; // Increases apply immediately
 }
 
 impl pallet_subtensor::Config for Test {
@@ -190,6 +193,9 @@ impl pallet_subtensor::Config for Test {
     type Preimages = ();
     type InitialColdkeySwapScheduleDuration = InitialColdkeySwapScheduleDuration;
     type InitialDissolveNetworkScheduleDuration = InitialDissolveNetworkScheduleDuration;
+    type InitialSubnetOwnerTransferScheduleDuration = InitialSubnetOwnerTransferScheduleDuration;
+    type InitialSenateAdjustmentInterval = InitialSenateAdjustmentInterval;
+    type InitialDelegateTakeNoticePeriod = InitialDelegateTakeNoticePeriod;
 }
 
 #[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
@@ -291,6 +297,7 @@ pub(crate) fn run_to_block(n: u64) {
         System::set_block_number(System::block_number() + 1);
         System::on_initialize(System::block_number());
         SubtensorModule::on_initialize(System::block_number());
+        AdminUtils::on_initialize(System::block_number());
     }
 }
 
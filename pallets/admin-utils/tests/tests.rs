@@ -2,11 +2,13 @@ use frame_support::sp_runtime::DispatchError;
 use frame_support::{
     assert_err, assert_noop, assert_ok,
     dispatch::{DispatchClass, GetDispatchInfo, Pays},
+    BoundedVec,
 };
 use frame_system::Config;
-use pallet_admin_utils::Error;
+use pallet_admin_utils::{Error, HyperparamPatch, Hyperparameter, OriginKind};
 use pallet_subtensor::Error as SubtensorError;
-use pallet_subtensor::{migrations, Event};
+use pallet_subtensor::{migrations, Event, SubnetHyperparameter};
+use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_core::U256;
 
 mod mock;
@@ -1113,6 +1115,74 @@ fn test_sudo_set_min_delegate_take() {
     });
 }
 
+#[test]
+fn test_sudo_set_delegate_take_notice_period() {
+    new_test_ext().execute_with(|| {
+        let to_be_set: u64 = 7200;
+        let init_value: u64 = SubtensorModule::get_delegate_take_notice_period();
+        assert_eq!(
+            AdminUtils::sudo_set_delegate_take_notice_period(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            SubtensorModule::get_delegate_take_notice_period(),
+            init_value
+        );
+        assert_ok!(AdminUtils::sudo_set_delegate_take_notice_period(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            to_be_set
+        ));
+        assert_eq!(
+            SubtensorModule::get_delegate_take_notice_period(),
+            to_be_set
+        );
+    });
+}
+
+#[test]
+fn test_sudo_set_unbonding_period() {
+    new_test_ext().execute_with(|| {
+        let to_be_set: u64 = 7200;
+        let init_value: u64 = SubtensorModule::get_unbonding_period();
+        assert_eq!(
+            AdminUtils::sudo_set_unbonding_period(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(SubtensorModule::get_unbonding_period(), init_value);
+        assert_ok!(AdminUtils::sudo_set_unbonding_period(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            to_be_set
+        ));
+        assert_eq!(SubtensorModule::get_unbonding_period(), to_be_set);
+    });
+}
+
+#[test]
+fn test_sudo_set_senate_stake_weighted_voting() {
+    new_test_ext().execute_with(|| {
+        assert!(!SubtensorModule::get_senate_stake_weighted_voting());
+        assert_eq!(
+            AdminUtils::sudo_set_senate_stake_weighted_voting(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                true
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert!(!SubtensorModule::get_senate_stake_weighted_voting());
+        assert_ok!(AdminUtils::sudo_set_senate_stake_weighted_voting(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            true
+        ));
+        assert!(SubtensorModule::get_senate_stake_weighted_voting());
+    });
+}
+
 #[test]
 fn test_sudo_set_weight_commit_interval() {
     new_test_ext().execute_with(|| {
@@ -1243,7 +1313,7 @@ fn test_sudo_get_set_alpha() {
             DispatchError::BadOrigin
         );
 
-        assert_ok!(SubtensorModule::register_network(signer.clone()));
+        assert_ok!(SubtensorModule::register_network(signer.clone(), None));
 
         assert_ok!(AdminUtils::sudo_set_alpha_values(
             signer.clone(),
@@ -1359,6 +1429,37 @@ fn test_sudo_get_set_alpha() {
             alpha_low,
             alpha_high
         ));
+
+        // 4. The subnet owner keeps to the bounds set by root
+        assert_ok!(SubtensorModule::set_hyperparameter_bounds(
+            SubnetHyperparameter::AlphaHigh,
+            u64::from(u16::MAX - 100),
+            u64::from(u16::MAX - 50)
+        ));
+        assert_err!(
+            AdminUtils::sudo_set_alpha_values(signer.clone(), netuid, alpha_low, alpha_high),
+            SubtensorError::<Test>::HyperparameterOutOfBounds
+        );
+        assert_ok!(AdminUtils::sudo_set_alpha_values(
+            signer.clone(),
+            netuid,
+            alpha_low,
+            u16::MAX - 60
+        ));
+        assert_eq!(
+            SubtensorModule::get_subnet_hyperparameter(netuid, SubnetHyperparameter::AlphaHigh),
+            u64::from(u16::MAX - 60)
+        );
+        assert_ok!(AdminUtils::sudo_set_alpha_values(
+            RuntimeOrigin::root(),
+            netuid,
+            alpha_low,
+            alpha_high
+        ));
+        assert_eq!(
+            SubtensorModule::get_alpha_values(netuid),
+            (alpha_low, alpha_high)
+        );
     });
 }
 
@@ -1395,7 +1496,7 @@ fn test_sudo_set_coldkey_swap_schedule_duration() {
         ));
 
         // You might want to check for events here if your pallet emits them
-        System::assert_last_event(Event::ColdkeySwapScheduleDurationSet(new_duration).into());
+        System::assert_has_event(Event::ColdkeySwapScheduleDurationSet(new_duration).into());
     });
 }
 
@@ -1432,6 +1533,623 @@ fn test_sudo_set_dissolve_network_schedule_duration() {
         ));
 
         // You might want to check for events here if your pallet emits them
-        System::assert_last_event(Event::DissolveNetworkScheduleDurationSet(new_duration).into());
+        System::assert_has_event(Event::DissolveNetworkScheduleDurationSet(new_duration).into());
+    });
+}
+
+#[test]
+fn test_sudo_set_subnet_owner_transfer_schedule_duration() {
+    new_test_ext().execute_with(|| {
+        // Arrange
+        let root = RuntimeOrigin::root();
+        let non_root = RuntimeOrigin::signed(U256::from(1));
+        let new_duration = 300u32.into();
+
+        // Act & Assert: Non-root account should fail
+        assert_noop!(
+            AdminUtils::sudo_set_subnet_owner_transfer_schedule_duration(non_root, new_duration),
+            DispatchError::BadOrigin
+        );
+
+        // Act: Root account should succeed
+        assert_ok!(
+            AdminUtils::sudo_set_subnet_owner_transfer_schedule_duration(root, new_duration)
+        );
+
+        // Assert: Check the duration was set and the event emitted
+        assert_eq!(
+            pallet_subtensor::SubnetOwnerTransferScheduleDuration::<Test>::get(),
+            new_duration
+        );
+        System::assert_has_event(
+            Event::SubnetOwnerTransferScheduleDurationSet(new_duration).into(),
+        );
+    });
+}
+
+#[test]
+fn test_sudo_set_senate_adjustment_interval() {
+    new_test_ext().execute_with(|| {
+        let interval: u64 = 7200;
+
+        assert_noop!(
+            AdminUtils::sudo_set_senate_adjustment_interval(
+                RuntimeOrigin::signed(U256::from(1)),
+                interval
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(AdminUtils::sudo_set_senate_adjustment_interval(
+            RuntimeOrigin::root(),
+            interval
+        ));
+        assert_eq!(SubtensorModule::get_senate_adjustment_interval(), interval);
+        System::assert_has_event(Event::SenateAdjustmentIntervalSet(interval).into());
+    });
+}
+
+#[test]
+fn test_hyperparameter_change_enactment_delay() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let delay: u64 = 10;
+        add_network(netuid, 10);
+        let init_value: u16 = SubtensorModule::get_kappa(netuid);
+
+        assert_noop!(
+            AdminUtils::sudo_set_hyperparameter_enactment_delay(
+                RuntimeOrigin::signed(U256::from(1)),
+                delay
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(AdminUtils::sudo_set_hyperparameter_enactment_delay(
+            RuntimeOrigin::root(),
+            delay
+        ));
+        System::assert_has_event(Event::HyperparameterEnactmentDelaySet(delay).into());
+
+        // The change is recorded as pending instead of applied.
+        assert_ok!(AdminUtils::sudo_set_kappa(
+            RuntimeOrigin::root(),
+            netuid,
+            20
+        ));
+        assert_eq!(SubtensorModule::get_kappa(netuid), init_value);
+        assert_eq!(
+            SubtensorModule::get_pending_hyperparameter_changes(netuid),
+            vec![(SubnetHyperparameter::Kappa, 20, 11)]
+        );
+        System::assert_has_event(
+            Event::HyperparameterChangeScheduled {
+                netuid,
+                param: SubnetHyperparameter::Kappa,
+                value: 20,
+                effective_block: 11,
+            }
+            .into(),
+        );
+        System::assert_has_event(
+            pallet_admin_utils::Event::HyperparameterChangeScheduled {
+                netuid,
+                param: Hyperparameter::Subnet(SubnetHyperparameter::Kappa),
+                old_value: u64::from(init_value),
+                new_value: 20,
+                effective_block: 11,
+                origin_kind: OriginKind::Root,
+            }
+            .into(),
+        );
+
+        // It applies once the delay has passed.
+        run_to_block(10);
+        assert_eq!(SubtensorModule::get_kappa(netuid), init_value);
+        run_to_block(11);
+        assert_eq!(SubtensorModule::get_kappa(netuid), 20);
+        assert!(SubtensorModule::get_pending_hyperparameter_changes(netuid).is_empty());
+        System::assert_has_event(
+            Event::HyperparameterChangeEnacted {
+                netuid,
+                param: SubnetHyperparameter::Kappa,
+                value: 20,
+            }
+            .into(),
+        );
+        // The change is recorded once it applies.
+        System::assert_has_event(
+            pallet_admin_utils::Event::HyperparameterChanged {
+                netuid: Some(netuid),
+                param: Hyperparameter::Subnet(SubnetHyperparameter::Kappa),
+                old_value: u64::from(init_value),
+                new_value: 20,
+                origin_kind: OriginKind::Root,
+            }
+            .into(),
+        );
+        assert!(!pallet_admin_utils::PendingHyperparameterChangeOrigins::<
+            Test,
+        >::contains_key(netuid, SubnetHyperparameter::Kappa));
+    });
+}
+
+#[test]
+fn test_pending_hyperparameter_changes_cannot_invert_ranges() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 10);
+        SubtensorModule::set_min_burn(netuid, 100);
+        SubtensorModule::set_max_burn(netuid, 1_000);
+        SubtensorModule::set_min_difficulty(netuid, 100);
+        SubtensorModule::set_max_difficulty(netuid, 1_000);
+        pallet_subtensor::HyperparameterEnactmentDelay::<Test>::put(10);
+
+        // Each change is valid on its own, but together they would invert the range.
+        assert_ok!(AdminUtils::sudo_set_min_burn(
+            RuntimeOrigin::root(),
+            netuid,
+            600
+        ));
+        assert_ok!(AdminUtils::sudo_set_max_burn(
+            RuntimeOrigin::root(),
+            netuid,
+            500
+        ));
+        assert_ok!(AdminUtils::sudo_set_max_difficulty(
+            RuntimeOrigin::root(),
+            netuid,
+            500
+        ));
+        assert_ok!(AdminUtils::sudo_set_min_difficulty(
+            RuntimeOrigin::root(),
+            netuid,
+            600
+        ));
+
+        // The change enacted last is dropped, the ranges stay ordered.
+        run_to_block(11);
+        assert_eq!(SubtensorModule::get_min_burn_as_u64(netuid), 600);
+        assert_eq!(SubtensorModule::get_max_burn_as_u64(netuid), 1_000);
+        assert_eq!(SubtensorModule::get_min_difficulty(netuid), 100);
+        assert_eq!(SubtensorModule::get_max_difficulty(netuid), 500);
+        assert!(SubtensorModule::get_pending_hyperparameter_changes(netuid).is_empty());
+    });
+}
+
+#[test]
+fn test_cancel_pending_hyperparameter_change() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let owner = U256::from(1);
+        add_network(netuid, 10);
+        pallet_subtensor::SubnetOwner::<Test>::insert(netuid, owner);
+        pallet_subtensor::HyperparameterEnactmentDelay::<Test>::put(10);
+        let init_value: u16 = SubtensorModule::get_rho(netuid);
+
+        assert_ok!(AdminUtils::sudo_set_rho(RuntimeOrigin::root(), netuid, 20));
+        assert_noop!(
+            AdminUtils::cancel_pending_hyperparameter_change(
+                RuntimeOrigin::signed(U256::from(2)),
+                netuid,
+                SubnetHyperparameter::Rho
+            ),
+            DispatchError::BadOrigin
+        );
+
+        // The subnet owner can cancel a change made by root.
+        assert_ok!(AdminUtils::cancel_pending_hyperparameter_change(
+            RuntimeOrigin::signed(owner),
+            netuid,
+            SubnetHyperparameter::Rho
+        ));
+        System::assert_last_event(
+            Event::HyperparameterChangeCancelled {
+                netuid,
+                param: SubnetHyperparameter::Rho,
+            }
+            .into(),
+        );
+        assert_noop!(
+            AdminUtils::cancel_pending_hyperparameter_change(
+                RuntimeOrigin::root(),
+                netuid,
+                SubnetHyperparameter::Rho
+            ),
+            SubtensorError::<Test>::NoPendingHyperparameterChange
+        );
+
+        // Only root can cancel a change of a root-only hyperparameter.
+        let init_tempo: u16 = SubtensorModule::get_tempo(netuid);
+        assert_ok!(AdminUtils::sudo_set_tempo(
+            RuntimeOrigin::root(),
+            netuid,
+            30
+        ));
+        assert_noop!(
+            AdminUtils::cancel_pending_hyperparameter_change(
+                RuntimeOrigin::signed(owner),
+                netuid,
+                SubnetHyperparameter::Tempo
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(AdminUtils::cancel_pending_hyperparameter_change(
+            RuntimeOrigin::root(),
+            netuid,
+            SubnetHyperparameter::Tempo
+        ));
+
+        run_to_block(11);
+        assert_eq!(SubtensorModule::get_rho(netuid), init_value);
+        assert_eq!(SubtensorModule::get_tempo(netuid), init_tempo);
+        assert!(
+            pallet_subtensor::PendingHyperparameterChangesByBlock::<Test>::iter()
+                .next()
+                .is_none()
+        );
+    });
+}
+
+#[test]
+fn test_rescheduled_hyperparameter_change_applies_at_its_new_block() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 10);
+        pallet_subtensor::HyperparameterEnactmentDelay::<Test>::put(10);
+        let init_value: u16 = SubtensorModule::get_kappa(netuid);
+
+        assert_ok!(AdminUtils::sudo_set_kappa(
+            RuntimeOrigin::root(),
+            netuid,
+            20
+        ));
+        run_to_block(5);
+        assert_ok!(AdminUtils::sudo_set_kappa(
+            RuntimeOrigin::root(),
+            netuid,
+            30
+        ));
+        assert_eq!(
+            pallet_subtensor::PendingHyperparameterChangesByBlock::<Test>::get(11),
+            vec![]
+        );
+        assert_eq!(
+            pallet_subtensor::PendingHyperparameterChangesByBlock::<Test>::get(15),
+            vec![(netuid, SubnetHyperparameter::Kappa)]
+        );
+
+        run_to_block(14);
+        assert_eq!(SubtensorModule::get_kappa(netuid), init_value);
+        run_to_block(15);
+        assert_eq!(SubtensorModule::get_kappa(netuid), 30);
+        assert!(SubtensorModule::get_pending_hyperparameter_changes(netuid).is_empty());
+        assert!(!pallet_subtensor::PendingHyperparameterChangesByBlock::<Test>::contains_key(15));
+    });
+}
+
+#[test]
+fn test_sudo_set_hyperparameter_bounds() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let owner = U256::from(1);
+        add_network(netuid, 10);
+        pallet_subtensor::SubnetOwner::<Test>::insert(netuid, owner);
+
+        assert_noop!(
+            AdminUtils::sudo_set_hyperparameter_bounds(
+                RuntimeOrigin::signed(owner),
+                SubnetHyperparameter::Rho,
+                5,
+                20
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            AdminUtils::sudo_set_hyperparameter_bounds(
+                RuntimeOrigin::root(),
+                SubnetHyperparameter::Rho,
+                20,
+                5
+            ),
+            SubtensorError::<Test>::InvalidHyperparameterBounds
+        );
+        assert_ok!(AdminUtils::sudo_set_hyperparameter_bounds(
+            RuntimeOrigin::root(),
+            SubnetHyperparameter::Rho,
+            5,
+            20
+        ));
+        System::assert_last_event(
+            Event::HyperparameterBoundsSet {
+                param: SubnetHyperparameter::Rho,
+                min: 5,
+                max: 20,
+            }
+            .into(),
+        );
+        assert_eq!(
+            SubtensorModule::get_all_hyperparameter_bounds(),
+            vec![(SubnetHyperparameter::Rho, 5, 20)]
+        );
+
+        // The subnet owner must stay within the bounds.
+        assert_noop!(
+            AdminUtils::sudo_set_rho(RuntimeOrigin::signed(owner), netuid, 21),
+            SubtensorError::<Test>::HyperparameterOutOfBounds
+        );
+        assert_noop!(
+            AdminUtils::sudo_set_rho(RuntimeOrigin::signed(owner), netuid, 4),
+            SubtensorError::<Test>::HyperparameterOutOfBounds
+        );
+        assert_ok!(AdminUtils::sudo_set_rho(
+            RuntimeOrigin::signed(owner),
+            netuid,
+            20
+        ));
+        assert_eq!(SubtensorModule::get_rho(netuid), 20);
+
+        // Root is not bound.
+        assert_ok!(AdminUtils::sudo_set_rho(RuntimeOrigin::root(), netuid, 30));
+        assert_eq!(SubtensorModule::get_rho(netuid), 30);
+
+        // Setting the full range removes the bounds.
+        assert_ok!(AdminUtils::sudo_set_hyperparameter_bounds(
+            RuntimeOrigin::root(),
+            SubnetHyperparameter::Rho,
+            0,
+            u64::MAX
+        ));
+        assert!(SubtensorModule::get_all_hyperparameter_bounds().is_empty());
+        assert_ok!(AdminUtils::sudo_set_rho(
+            RuntimeOrigin::signed(owner),
+            netuid,
+            40
+        ));
+    });
+}
+
+#[test]
+fn test_hyperparameter_changes_are_audited() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let owner = U256::from(1);
+        add_network(netuid, 10);
+        pallet_subtensor::SubnetOwner::<Test>::insert(netuid, owner);
+        let init_rho: u16 = SubtensorModule::get_rho(netuid);
+        let init_tx_rate_limit: u64 = SubtensorModule::get_tx_rate_limit();
+
+        assert_ok!(AdminUtils::sudo_set_rho(
+            RuntimeOrigin::signed(owner),
+            netuid,
+            init_rho + 1
+        ));
+        System::assert_last_event(
+            pallet_admin_utils::Event::HyperparameterChanged {
+                netuid: Some(netuid),
+                param: Hyperparameter::Subnet(SubnetHyperparameter::Rho),
+                old_value: u64::from(init_rho),
+                new_value: u64::from(init_rho + 1),
+                origin_kind: OriginKind::SubnetOwner,
+            }
+            .into(),
+        );
+
+        assert_ok!(AdminUtils::sudo_set_tx_rate_limit(
+            RuntimeOrigin::root(),
+            init_tx_rate_limit + 1
+        ));
+        System::assert_last_event(
+            pallet_admin_utils::Event::HyperparameterChanged {
+                netuid: None,
+                param: Hyperparameter::TxRateLimit,
+                old_value: init_tx_rate_limit,
+                new_value: init_tx_rate_limit + 1,
+                origin_kind: OriginKind::Root,
+            }
+            .into(),
+        );
+
+        let new_authorities = BoundedVec::try_from(vec![AuraId::from(
+            sp_core::sr25519::Public::from_raw([1u8; 32]),
+        )])
+        .expect("Authorities should fit the bound");
+        assert_ok!(AdminUtils::swap_authorities(
+            RuntimeOrigin::root(),
+            new_authorities.clone()
+        ));
+        System::assert_last_event(
+            pallet_admin_utils::Event::AuthoritiesSwapped { new_authorities }.into(),
+        );
+    });
+}
+
+#[test]
+fn test_sudo_set_hyperparameters() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let owner = U256::from(1);
+        add_network(netuid, 10);
+        pallet_subtensor::SubnetOwner::<Test>::insert(netuid, owner);
+        let init_tempo: u16 = SubtensorModule::get_tempo(netuid);
+
+        assert_noop!(
+            AdminUtils::sudo_set_hyperparameters(
+                RuntimeOrigin::signed(U256::from(2)),
+                netuid,
+                HyperparamPatch {
+                    rho: Some(20),
+                    ..Default::default()
+                }
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            AdminUtils::sudo_set_hyperparameters(
+                RuntimeOrigin::root(),
+                netuid + 1,
+                HyperparamPatch::default()
+            ),
+            Error::<Test>::SubnetDoesNotExist
+        );
+
+        // The tempo may only be set by root, so none of the patch applies.
+        assert_noop!(
+            AdminUtils::sudo_set_hyperparameters(
+                RuntimeOrigin::signed(owner),
+                netuid,
+                HyperparamPatch {
+                    rho: Some(20),
+                    kappa: Some(30),
+                    tempo: Some(init_tempo + 1),
+                    ..Default::default()
+                }
+            ),
+            DispatchError::BadOrigin
+        );
+
+        assert_ok!(AdminUtils::sudo_set_hyperparameters(
+            RuntimeOrigin::signed(owner),
+            netuid,
+            HyperparamPatch {
+                rho: Some(20),
+                kappa: Some(30),
+                commit_reveal_weights_enabled: Some(true),
+                ..Default::default()
+            }
+        ));
+        assert_eq!(SubtensorModule::get_rho(netuid), 20);
+        assert_eq!(SubtensorModule::get_kappa(netuid), 30);
+        assert!(SubtensorModule::get_commit_reveal_weights_enabled(netuid));
+        assert_eq!(SubtensorModule::get_tempo(netuid), init_tempo);
+
+        assert_ok!(AdminUtils::sudo_set_hyperparameters(
+            RuntimeOrigin::root(),
+            netuid,
+            HyperparamPatch {
+                tempo: Some(init_tempo + 1),
+                ..Default::default()
+            }
+        ));
+        assert_eq!(SubtensorModule::get_tempo(netuid), init_tempo + 1);
+    });
+}
+
+#[test]
+fn test_sudo_set_childkey_limits() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let owner = U256::from(1);
+        add_network(netuid, 10);
+        pallet_subtensor::SubnetOwner::<Test>::insert(netuid, owner);
+
+        assert_noop!(
+            AdminUtils::sudo_set_max_children(RuntimeOrigin::signed(U256::from(2)), netuid, 8),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            AdminUtils::sudo_set_max_children(RuntimeOrigin::root(), netuid + 1, 8),
+            Error::<Test>::SubnetDoesNotExist
+        );
+
+        assert_ok!(AdminUtils::sudo_set_max_children(
+            RuntimeOrigin::signed(owner),
+            netuid,
+            8
+        ));
+        assert_ok!(AdminUtils::sudo_set_min_child_proportion(
+            RuntimeOrigin::signed(owner),
+            netuid,
+            1_000
+        ));
+        assert_ok!(AdminUtils::sudo_set_set_children_cooldown(
+            RuntimeOrigin::root(),
+            netuid,
+            20
+        ));
+        assert_eq!(SubtensorModule::get_max_children(netuid), 8);
+        assert_eq!(SubtensorModule::get_min_child_proportion(netuid), 1_000);
+        assert_eq!(SubtensorModule::get_set_children_cooldown(netuid), 20);
+
+        // Not even root can go past the limits every subnet keeps to
+        assert_noop!(
+            AdminUtils::sudo_set_max_children(
+                RuntimeOrigin::root(),
+                netuid,
+                pallet_subtensor::MAX_CHILDREN_LIMIT + 1
+            ),
+            Error::<Test>::OutsideChildkeyLimits
+        );
+        assert_noop!(
+            AdminUtils::sudo_set_set_children_cooldown(
+                RuntimeOrigin::root(),
+                netuid,
+                pallet_subtensor::MIN_SET_CHILDREN_COOLDOWN - 1
+            ),
+            Error::<Test>::OutsideChildkeyLimits
+        );
+        assert_noop!(
+            AdminUtils::sudo_set_subnet_max_childkey_take(
+                RuntimeOrigin::root(),
+                netuid,
+                SubtensorModule::get_max_childkey_take() + 1
+            ),
+            Error::<Test>::OutsideChildkeyLimits
+        );
+
+        // The take bounds of the subnet may not cross
+        assert_ok!(AdminUtils::sudo_set_subnet_max_childkey_take(
+            RuntimeOrigin::signed(owner),
+            netuid,
+            3_000
+        ));
+        assert_noop!(
+            AdminUtils::sudo_set_subnet_min_childkey_take(
+                RuntimeOrigin::signed(owner),
+                netuid,
+                3_001
+            ),
+            Error::<Test>::InvalidChildkeyTakeBounds
+        );
+        assert_ok!(AdminUtils::sudo_set_subnet_min_childkey_take(
+            RuntimeOrigin::signed(owner),
+            netuid,
+            1_000
+        ));
+        assert_noop!(
+            AdminUtils::sudo_set_subnet_max_childkey_take(
+                RuntimeOrigin::signed(owner),
+                netuid,
+                999
+            ),
+            Error::<Test>::InvalidChildkeyTakeBounds
+        );
+        assert_eq!(SubtensorModule::get_subnet_min_childkey_take(netuid), 1_000);
+        assert_eq!(SubtensorModule::get_subnet_max_childkey_take(netuid), 3_000);
+
+        // Both bounds can be moved past each other in one patch
+        assert_ok!(AdminUtils::sudo_set_hyperparameters(
+            RuntimeOrigin::signed(owner),
+            netuid,
+            HyperparamPatch {
+                max_children: Some(3),
+                min_childkey_take: Some(4_000),
+                max_childkey_take: Some(5_000),
+                ..Default::default()
+            }
+        ));
+        assert_eq!(SubtensorModule::get_max_children(netuid), 3);
+        assert_eq!(SubtensorModule::get_subnet_min_childkey_take(netuid), 4_000);
+        assert_eq!(SubtensorModule::get_subnet_max_childkey_take(netuid), 5_000);
+
+        // Subnet owners stay within the bounds set by root
+        assert_ok!(AdminUtils::sudo_set_hyperparameter_bounds(
+            RuntimeOrigin::root(),
+            SubnetHyperparameter::MaxChildren,
+            1,
+            10
+        ));
+        assert_noop!(
+            AdminUtils::sudo_set_max_children(RuntimeOrigin::signed(owner), netuid, 11),
+            SubtensorError::<Test>::HyperparameterOutOfBounds
+        );
     });
 }
@@ -176,7 +176,7 @@ benchmarks_instance_pallet! {
         // New proposal is recorded
         assert_eq!(Collective::<T, I>::proposals().len(), p as usize);
         let proposal_hash = T::Hashing::hash_of(&proposal);
-        assert_last_event::<T, I>(Event::Proposed { account: caller, proposal_index: p - 1, proposal_hash, threshold }.into());
+        assert_last_event::<T, I>(Event::Proposed { account: caller, proposal_index: p - 1, proposal_hash, threshold, metadata: None }.into());
     }
 
     vote {
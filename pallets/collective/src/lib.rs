@@ -38,6 +38,15 @@
 //! abstentions and the proposal is executed if there are enough approvals counting the new votes.
 //!
 //! If there are not, or if no prime is set, then the motion is dropped without being executed.
+//!
+//! Motions can optionally be proposed with a metadata record, giving the voters a title, the hash
+//! of a description and a discussion URL instead of only the call hash.
+//!
+//! Motions can optionally be tallied by voting weight instead of one vote per member. If
+//! `VotingWeights` is weighted when a motion is proposed, the weights of the eligible voters are
+//! captured then and the motion is decided by the `WeightedThreshold` strategy on the weight of its
+//! ayes and nays, on top of the threshold of votes. Abstaining weight follows the `DefaultVote`
+//! strategy once the motion has ended.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![recursion_limit = "128"]
@@ -54,7 +63,7 @@ use frame_support::{
 use scale_info::TypeInfo;
 use sp_io::storage;
 use sp_runtime::traits::Dispatchable;
-use sp_runtime::{traits::Hash, RuntimeDebug, Saturating};
+use sp_runtime::{traits::Hash, Permill, RuntimeDebug, Saturating};
 use sp_std::{marker::PhantomData, prelude::*, result};
 
 #[cfg(test)]
@@ -62,6 +71,7 @@ mod tests;
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+pub mod migrations;
 pub mod weights;
 
 pub use pallet::*;
@@ -125,6 +135,54 @@ impl DefaultVote for MoreThanMajorityThenPrimeDefaultVote {
     }
 }
 
+/// Voting weight of the eligible voters, used to tally motions by weight.
+pub trait GetVotingWeights<AccountId> {
+    /// Whether motions proposed now are tallied by voting weight.
+    fn is_weighted() -> bool;
+
+    /// Get the current voting weight of every eligible voter.
+    fn voting_weights() -> Vec<(AccountId, u64)>;
+}
+
+impl<AccountId> GetVotingWeights<AccountId> for () {
+    fn is_weighted() -> bool {
+        false
+    }
+
+    fn voting_weights() -> Vec<(AccountId, u64)> {
+        Vec::new()
+    }
+}
+
+/// Approval strategy for motions tallied by voting weight.
+pub trait WeightedThreshold {
+    /// Whether the motion is approved, given:
+    ///
+    /// - Voting weight in favour.
+    /// - Total voting weight of the eligible voters.
+    fn is_approved(aye_weight: u64, total_weight: u64) -> bool;
+}
+
+/// Approve once more than half of the total voting weight is in favour.
+pub struct MoreThanHalfWeight;
+
+impl WeightedThreshold for MoreThanHalfWeight {
+    fn is_approved(aye_weight: u64, total_weight: u64) -> bool {
+        u128::from(aye_weight).saturating_mul(2) > u128::from(total_weight)
+    }
+}
+
+/// Approve once at least two thirds of the total voting weight is in favour.
+pub struct AtLeastTwoThirdsWeight;
+
+impl WeightedThreshold for AtLeastTwoThirdsWeight {
+    fn is_approved(aye_weight: u64, total_weight: u64) -> bool {
+        aye_weight > 0
+            && u128::from(aye_weight).saturating_mul(3)
+                >= u128::from(total_weight).saturating_mul(2)
+    }
+}
+
 /// Origin for the collective module.
 #[derive(PartialEq, Eq, Clone, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
 #[scale_info(skip_type_params(I))]
@@ -151,7 +209,7 @@ impl<AccountId, I> GetBacking for RawOrigin<AccountId, I> {
 }
 
 /// Info for keeping track of a motion being voted on.
-#[freeze_struct("a8e7b0b34ad52b17")]
+#[freeze_struct("6ac9541f67f6f5da")]
 #[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
 pub struct Votes<AccountId, BlockNumber> {
     /// The proposal's unique index.
@@ -164,6 +222,96 @@ pub struct Votes<AccountId, BlockNumber> {
     nays: Vec<AccountId>,
     /// The hard end time of this vote.
     end: BlockNumber,
+    /// The weighted tally, if the motion is tallied by voting weight.
+    weighted: Option<WeightedTally<AccountId>>,
+}
+
+impl<AccountId: PartialEq, BlockNumber> Votes<AccountId, BlockNumber> {
+    /// The weighted tally of the motion, if it is tallied by voting weight.
+    pub fn weighted_tally(&self) -> Option<&WeightedTally<AccountId>> {
+        self.weighted.as_ref()
+    }
+
+    /// Recount the weighted tally after the ayes or nays changed.
+    fn recount_weighted(&mut self) {
+        if let Some(tally) = self.weighted.as_mut() {
+            tally.ayes = tally.weight_of_all(&self.ayes);
+            tally.nays = tally.weight_of_all(&self.nays);
+        }
+    }
+}
+
+/// Title of a proposal, bounded to 128 bytes.
+pub type ProposalTitle = BoundedVec<u8, ConstU32<128>>;
+
+/// Discussion URL of a proposal, bounded to 256 bytes.
+pub type ProposalUrl = BoundedVec<u8, ConstU32<256>>;
+
+/// Metadata attached to a proposal to give voters its context.
+#[freeze_struct("3abbc95cfdb94b21")]
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct ProposalMetadata<AccountId, Hash> {
+    /// The title of the proposal.
+    pub title: ProposalTitle,
+    /// The hash of the full description, which is kept off-chain.
+    pub description_hash: Hash,
+    /// Where the proposal is discussed.
+    pub url: ProposalUrl,
+    /// The account that proposed the motion.
+    pub proposer: AccountId,
+}
+
+/// Tally of a motion by the voting weight of its voters.
+#[freeze_struct("99b756b92a141884")]
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct WeightedTally<AccountId> {
+    /// The voting weight of each eligible voter, captured when the motion was proposed.
+    weights: Vec<(AccountId, u64)>,
+    /// The total voting weight of the eligible voters.
+    total: u64,
+    /// The voting weight of the current ayes.
+    ayes: u64,
+    /// The voting weight of the current nays.
+    nays: u64,
+}
+
+impl<AccountId: PartialEq> WeightedTally<AccountId> {
+    /// Start a tally from the voting weights of the eligible voters.
+    fn new(weights: Vec<(AccountId, u64)>) -> Self {
+        let total = weights
+            .iter()
+            .fold(0u64, |total, (_, weight)| total.saturating_add(*weight));
+        Self {
+            weights,
+            total,
+            ayes: 0,
+            nays: 0,
+        }
+    }
+
+    /// The total voting weight of the eligible voters.
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    /// The voting weight of the current ayes.
+    pub fn ayes(&self) -> u64 {
+        self.ayes
+    }
+
+    /// The voting weight of the current nays.
+    pub fn nays(&self) -> u64 {
+        self.nays
+    }
+
+    /// The combined voting weight of the given voters. Voters which were not eligible when the
+    /// motion was proposed have no weight.
+    fn weight_of_all(&self, voters: &[AccountId]) -> u64 {
+        self.weights
+            .iter()
+            .filter(|(who, _)| voters.contains(who))
+            .fold(0u64, |total, (_, weight)| total.saturating_add(*weight))
+    }
 }
 
 #[deny(missing_docs)]
@@ -173,7 +321,7 @@ pub mod pallet {
     use frame_system::pallet_prelude::*;
 
     /// The current storage version.
-    const STORAGE_VERSION: StorageVersion = StorageVersion::new(4);
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(5);
 
     #[pallet::pallet]
     #[pallet::storage_version(STORAGE_VERSION)]
@@ -227,6 +375,12 @@ pub mod pallet {
 
         /// Members to expect in a vote
         type GetVotingMembers: GetVotingMembers<MemberCount>;
+
+        /// Voting weight of the eligible voters, `()` tallies one vote per member.
+        type VotingWeights: GetVotingWeights<Self::AccountId>;
+
+        /// Approval strategy for motions tallied by voting weight.
+        type WeightedThreshold: WeightedThreshold;
     }
 
     #[pallet::genesis_config]
@@ -277,6 +431,12 @@ pub mod pallet {
     pub type ProposalOf<T: Config<I>, I: 'static = ()> =
         StorageMap<_, Identity, T::Hash, <T as Config<I>>::Proposal, OptionQuery>;
 
+    /// Metadata of a given proposal, if it's current and was proposed with metadata.
+    #[pallet::storage]
+    #[pallet::getter(fn proposal_metadata_of)]
+    pub type ProposalMetadataOf<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Identity, T::Hash, ProposalMetadata<T::AccountId, T::Hash>, OptionQuery>;
+
     /// Votes on a given proposal, if it is ongoing.
     #[pallet::storage]
     #[pallet::getter(fn voting)]
@@ -313,6 +473,8 @@ pub mod pallet {
             proposal_hash: T::Hash,
             /// The threshold of member for the proposal.
             threshold: MemberCount,
+            /// The metadata of the proposal, if it was proposed with metadata.
+            metadata: Option<ProposalMetadata<T::AccountId, T::Hash>>,
         },
         /// A motion (given hash) has been voted on by given account, leaving
         /// a tally (yes votes and no votes given respectively as `MemberCount`).
@@ -385,6 +547,10 @@ pub mod pallet {
         ProposalLengthBoundLessThanProposalLength,
         /// The given motion duration for the proposal was too low.
         DurationLowerThanConfiguredMotionDuration,
+        /// The title of the proposal is too long.
+        ProposalTitleTooLong,
+        /// The URL of the proposal is too long.
+        ProposalUrlTooLong,
     }
 
     // Note that councillor operations are assigned to the operational class.
@@ -541,27 +707,7 @@ pub mod pallet {
             duration: BlockNumberFor<T>,
         ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin.clone())?;
-            ensure!(T::CanPropose::can_propose(&who), Error::<T, I>::NotMember);
-
-            ensure!(
-                duration >= T::MotionDuration::get(),
-                Error::<T, I>::DurationLowerThanConfiguredMotionDuration
-            );
-
-            let threshold = T::GetVotingMembers::get_count()
-                .saturating_div(2)
-                .saturating_add(1);
-
-            let members = Self::members();
-            let (proposal_len, active_proposals) =
-                Self::do_propose_proposed(who, threshold, proposal, length_bound, duration)?;
-
-            Ok(Some(T::WeightInfo::propose_proposed(
-                proposal_len,         // B
-                members.len() as u32, // M
-                active_proposals,     // P2
-            ))
-            .into())
+            Self::do_propose(who, proposal, length_bound, duration, None)
         }
 
         /// Add an aye or nay vote for the sender to the given proposal.
@@ -669,6 +815,48 @@ pub mod pallet {
 
             Self::do_close(proposal_hash, index, proposal_weight_bound, length_bound)
         }
+
+        /// Add a new proposal to be voted on, together with a metadata record giving its context.
+        ///
+        /// Requires the sender to be member.
+        ///
+        /// - `title`: The title of the proposal, at most 128 bytes.
+        /// - `description_hash`: The hash of the full description, which is kept off-chain.
+        /// - `url`: Where the proposal is discussed, at most 256 bytes.
+        ///
+        /// The metadata is stored alongside the proposal until it is closed, and emitted in the
+        /// `Proposed` event.
+        #[pallet::call_index(7)]
+        #[pallet::weight((
+			T::WeightInfo::propose_proposed(
+				*length_bound, // B
+				T::MaxMembers::get(), // M
+				T::MaxProposals::get(), // P2
+			).saturating_add(T::DbWeight::get().writes(1)),
+			DispatchClass::Operational
+		))]
+        pub fn propose_with_metadata(
+            origin: OriginFor<T>,
+            proposal: Box<<T as Config<I>>::Proposal>,
+            #[pallet::compact] length_bound: u32,
+            duration: BlockNumberFor<T>,
+            title: Vec<u8>,
+            description_hash: T::Hash,
+            url: Vec<u8>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let metadata = ProposalMetadata {
+                title: title
+                    .try_into()
+                    .map_err(|_| Error::<T, I>::ProposalTitleTooLong)?,
+                description_hash,
+                url: url
+                    .try_into()
+                    .map_err(|_| Error::<T, I>::ProposalUrlTooLong)?,
+                proposer: who.clone(),
+            };
+            Self::do_propose(who, proposal, length_bound, duration, Some(metadata))
+        }
     }
 }
 
@@ -692,6 +880,37 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
         Self::members().contains(who)
     }
 
+    /// Check that `who` may propose and add the proposal to be voted.
+    fn do_propose(
+        who: T::AccountId,
+        proposal: Box<<T as Config<I>>::Proposal>,
+        length_bound: u32,
+        duration: BlockNumberFor<T>,
+        metadata: Option<ProposalMetadata<T::AccountId, T::Hash>>,
+    ) -> DispatchResultWithPostInfo {
+        ensure!(T::CanPropose::can_propose(&who), Error::<T, I>::NotMember);
+
+        ensure!(
+            duration >= T::MotionDuration::get(),
+            Error::<T, I>::DurationLowerThanConfiguredMotionDuration
+        );
+
+        let threshold = T::GetVotingMembers::get_count()
+            .saturating_div(2)
+            .saturating_add(1);
+
+        let members = Self::members();
+        let (proposal_len, active_proposals) =
+            Self::do_propose_proposed(who, threshold, proposal, length_bound, duration, metadata)?;
+
+        Ok(Some(T::WeightInfo::propose_proposed(
+            proposal_len,         // B
+            members.len() as u32, // M
+            active_proposals,     // P2
+        ))
+        .into())
+    }
+
     /// Add a new proposal to be voted.
     pub fn do_propose_proposed(
         who: T::AccountId,
@@ -699,6 +918,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
         proposal: Box<<T as Config<I>>::Proposal>,
         length_bound: MemberCount,
         duration: BlockNumberFor<T>,
+        metadata: Option<ProposalMetadata<T::AccountId, T::Hash>>,
     ) -> Result<(u32, u32), DispatchError> {
         let proposal_len = proposal.encoded_size();
         ensure!(
@@ -728,6 +948,9 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
             Ok::<(), Error<T, I>>(())
         })?;
         <ProposalOf<T, I>>::insert(proposal_hash, proposal);
+        if let Some(metadata) = metadata.as_ref() {
+            <ProposalMetadataOf<T, I>>::insert(proposal_hash, metadata);
+        }
         let votes = {
             let end = frame_system::Pallet::<T>::block_number().saturating_add(duration);
             Votes {
@@ -736,6 +959,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
                 ayes: vec![],
                 nays: vec![],
                 end,
+                weighted: T::VotingWeights::is_weighted()
+                    .then(|| WeightedTally::new(T::VotingWeights::voting_weights())),
             }
         };
         <Voting<T, I>>::insert(proposal_hash, votes);
@@ -745,6 +970,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
             proposal_index: index,
             proposal_hash,
             threshold,
+            metadata,
         });
         Ok((proposal_len as u32, active_proposals as u32))
     }
@@ -788,6 +1014,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
                 voting.ayes.swap_remove(pos);
             }
         }
+        voting.recount_weighted();
 
         let yes_votes = voting.ayes.len() as MemberCount;
         let no_votes = voting.nays.len() as MemberCount;
@@ -820,8 +1047,21 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
         let mut no_votes = voting.nays.len() as MemberCount;
         let mut yes_votes = voting.ayes.len() as MemberCount;
         let seats = T::GetVotingMembers::get_count() as MemberCount;
-        let approved = yes_votes >= voting.threshold;
-        let disapproved = seats.saturating_sub(no_votes) < voting.threshold;
+        // Weighted motions also need the weight of the votes, on top of their number.
+        let mut weighted = voting
+            .weighted_tally()
+            .map(|tally| (tally.ayes, tally.nays, tally.total));
+        let (approved, disapproved) = match weighted {
+            Some((ayes, nays, total)) => (
+                yes_votes >= voting.threshold && T::WeightedThreshold::is_approved(ayes, total),
+                seats.saturating_sub(no_votes) < voting.threshold
+                    || !T::WeightedThreshold::is_approved(total.saturating_sub(nays), total),
+            ),
+            None => (
+                yes_votes >= voting.threshold,
+                seats.saturating_sub(no_votes) < voting.threshold,
+            ),
+        };
         // Allow (dis-)approving the proposal as soon as there are enough votes.
         if approved {
             let (proposal, len) = Self::validate_and_get_proposal(
@@ -834,8 +1074,9 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
                 yes: yes_votes,
                 no: no_votes,
             });
+            let (approvals, eligible) = Self::backing(yes_votes, seats, weighted);
             let (proposal_weight, proposal_count) =
-                Self::do_approve_proposal(seats, yes_votes, proposal_hash, proposal);
+                Self::do_approve_proposal(eligible, approvals, proposal_hash, proposal);
             return Ok((
                 Some(
                     T::WeightInfo::close_early_approved(len as u32, seats, proposal_count)
@@ -877,7 +1118,19 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
             true => yes_votes = yes_votes.saturating_add(abstentions),
             false => no_votes = no_votes.saturating_add(abstentions),
         }
-        let approved = yes_votes >= voting.threshold;
+        if let Some((ayes, nays, total)) = weighted.as_mut() {
+            let abstaining = total.saturating_sub(ayes.saturating_add(*nays));
+            match default {
+                true => *ayes = ayes.saturating_add(abstaining),
+                false => *nays = nays.saturating_add(abstaining),
+            }
+        }
+        let approved = match weighted {
+            Some((ayes, _, total)) => {
+                yes_votes >= voting.threshold && T::WeightedThreshold::is_approved(ayes, total)
+            }
+            None => yes_votes >= voting.threshold,
+        };
 
         if approved {
             let (proposal, len) = Self::validate_and_get_proposal(
@@ -890,8 +1143,9 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
                 yes: yes_votes,
                 no: no_votes,
             });
+            let (approvals, eligible) = Self::backing(yes_votes, seats, weighted);
             let (proposal_weight, proposal_count) =
-                Self::do_approve_proposal(seats, yes_votes, proposal_hash, proposal);
+                Self::do_approve_proposal(eligible, approvals, proposal_hash, proposal);
             Ok((
                 Some(
                     T::WeightInfo::close_approved(len as u32, seats, proposal_count)
@@ -915,6 +1169,24 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
         }
     }
 
+    /// The backing of an approved motion as `(approvals, eligible)` for the `Members` origin.
+    ///
+    /// Weighted motions express their backing in parts per million of the total voting weight, so
+    /// that proportion based origins such as `EnsureProportionMoreThan` keep working.
+    fn backing(
+        yes_votes: MemberCount,
+        seats: MemberCount,
+        weighted: Option<(u64, u64, u64)>,
+    ) -> (MemberCount, MemberCount) {
+        match weighted {
+            Some((ayes, _, total)) => (
+                Permill::from_rational(ayes, total).deconstruct(),
+                Permill::ACCURACY,
+            ),
+            None => (yes_votes, seats),
+        }
+    }
+
     /// Ensure that the right proposal bounds were passed and get the proposal from storage.
     ///
     /// Checks the length in storage via `storage::read` which adds an extra `size_of::<u32>() == 4`
@@ -988,6 +1260,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
     fn remove_proposal(proposal_hash: T::Hash) -> u32 {
         // remove proposal and vote
         ProposalOf::<T, I>::remove(proposal_hash);
+        ProposalMetadataOf::<T, I>::remove(proposal_hash);
         Voting::<T, I>::remove(proposal_hash);
         let num_proposals = Proposals::<T, I>::mutate(|proposals| {
             proposals.retain(|h| h != &proposal_hash);
@@ -1002,6 +1275,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
                 if let Some(mut votes) = v.take() {
                     votes.ayes.retain(|i| i != who);
                     votes.nays.retain(|i| i != who);
+                    votes.recount_weighted();
                     *v = Some(votes);
                 }
             });
@@ -1010,6 +1284,19 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
         Ok(true)
     }
 
+    /// The weighted tally of an ongoing motion, if it is tallied by voting weight.
+    pub fn weighted_tally(proposal: T::Hash) -> Option<WeightedTally<T::AccountId>> {
+        Self::voting(proposal).and_then(|votes| votes.weighted)
+    }
+
+    /// The weighted tallies of all ongoing motions tallied by voting weight.
+    pub fn weighted_tallies() -> Vec<(T::Hash, WeightedTally<T::AccountId>)> {
+        Self::proposals()
+            .into_iter()
+            .filter_map(|hash| Self::weighted_tally(hash).map(|tally| (hash, tally)))
+            .collect()
+    }
+
     pub fn has_voted(
         proposal: T::Hash,
         index: ProposalIndex,
@@ -1060,6 +1347,7 @@ impl<T: Config<I>, I: 'static> ChangeMembers<T::AccountId> for Pallet<T, I> {
                 if let Some(mut votes) = v.take() {
                     votes.ayes.retain(|i| outgoing.binary_search(i).is_err());
                     votes.nays.retain(|i| outgoing.binary_search(i).is_err());
+                    votes.recount_weighted();
                     *v = Some(votes);
                 }
             });
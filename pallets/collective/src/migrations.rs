@@ -0,0 +1,56 @@
+use super::*;
+use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade};
+
+/// Migration to storage version 5, which adds the weighted tally to `Votes`.
+pub mod v5 {
+    use super::*;
+
+    /// The `Votes` layout of storage version 4.
+    #[freeze_struct("3902570fe32ff146")]
+    #[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+    pub struct OldVotes<AccountId, BlockNumber> {
+        index: ProposalIndex,
+        threshold: MemberCount,
+        ayes: Vec<AccountId>,
+        nays: Vec<AccountId>,
+        end: BlockNumber,
+    }
+
+    pub struct MigrateToV5<T, I = ()>(PhantomData<(T, I)>);
+
+    impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV5<T, I> {
+        /// Translates the ongoing motions to the version 5 layout. Motions proposed before the
+        /// upgrade keep being tallied one vote per member.
+        fn on_runtime_upgrade() -> Weight {
+            let on_chain_version = Pallet::<T, I>::on_chain_storage_version();
+            if on_chain_version != 4 {
+                log::info!(
+                    "Collective migration to v5 skipped, on-chain version is {:?}",
+                    on_chain_version
+                );
+                return T::DbWeight::get().reads(1);
+            }
+
+            let mut translated: u64 = 0;
+            Voting::<T, I>::translate::<OldVotes<T::AccountId, BlockNumberFor<T>>, _>(|_, old| {
+                translated = translated.saturating_add(1);
+                Some(Votes {
+                    index: old.index,
+                    threshold: old.threshold,
+                    ayes: old.ayes,
+                    nays: old.nays,
+                    end: old.end,
+                    weighted: None,
+                })
+            });
+            StorageVersion::new(5).put::<Pallet<T, I>>();
+
+            log::info!(
+                "Collective migration to v5 translated {:?} motions",
+                translated
+            );
+            T::DbWeight::get()
+                .reads_writes(translated.saturating_add(1), translated.saturating_add(1))
+        }
+    }
+}
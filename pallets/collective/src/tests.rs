@@ -29,6 +29,7 @@ use sp_runtime::{
     traits::{BlakeTwo256, IdentityLookup},
     BuildStorage,
 };
+use sp_std::cell::RefCell;
 
 pub type Block = sp_runtime::generic::Block<Header, UncheckedExtrinsic>;
 pub type UncheckedExtrinsic = sp_runtime::generic::UncheckedExtrinsic<u32, u64, RuntimeCall, ()>;
@@ -138,6 +139,26 @@ impl Get<MemberCount> for GetCollectiveCount {
     }
 }
 
+thread_local! {
+    static COLLECTIVE_VOTING_WEIGHTS: RefCell<Option<Vec<(u64, u64)>>> = const { RefCell::new(None) };
+}
+
+pub struct CollectiveVotingWeights;
+impl CollectiveVotingWeights {
+    fn set(weights: Option<Vec<(u64, u64)>>) {
+        COLLECTIVE_VOTING_WEIGHTS.with(|v| *v.borrow_mut() = weights);
+    }
+}
+impl GetVotingWeights<u64> for CollectiveVotingWeights {
+    fn is_weighted() -> bool {
+        COLLECTIVE_VOTING_WEIGHTS.with(|v| v.borrow().is_some())
+    }
+
+    fn voting_weights() -> Vec<(u64, u64)> {
+        COLLECTIVE_VOTING_WEIGHTS.with(|v| v.borrow().clone().unwrap_or_default())
+    }
+}
+
 impl Config<Instance1> for Test {
     type RuntimeOrigin = RuntimeOrigin;
     type Proposal = RuntimeCall;
@@ -151,6 +172,8 @@ impl Config<Instance1> for Test {
     type CanPropose = CanProposeCollective;
     type CanVote = CanVoteCollective;
     type GetVotingMembers = GetCollectiveCount;
+    type VotingWeights = CollectiveVotingWeights;
+    type WeightedThreshold = MoreThanHalfWeight;
 }
 
 pub struct CanProposeCollectiveMajority;
@@ -192,6 +215,8 @@ impl Config<Instance2> for Test {
     type CanPropose = CanProposeCollectiveMajority;
     type CanVote = CanVoteCollectiveMajority;
     type GetVotingMembers = GetCollectiveMajorityCount;
+    type VotingWeights = ();
+    type WeightedThreshold = MoreThanHalfWeight;
 }
 impl mock_democracy::Config for Test {
     type RuntimeEvent = RuntimeEvent;
@@ -237,6 +262,8 @@ impl Config for Test {
     type CanPropose = CanProposeDefaultCollective;
     type CanVote = CanVoteDefaultCollective;
     type GetVotingMembers = GetDefaultCollectiveCount;
+    type VotingWeights = ();
+    type WeightedThreshold = MoreThanHalfWeight;
 }
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
@@ -324,7 +351,8 @@ fn close_works() {
                     account: 1,
                     proposal_index: 0,
                     proposal_hash: hash,
-                    threshold: 2
+                    threshold: 2,
+                    metadata: None
                 })),
                 record(RuntimeEvent::Collective(CollectiveEvent::Voted {
                     account: 1,
@@ -456,7 +484,8 @@ fn close_with_prime_works() {
                     account: 1,
                     proposal_index: 0,
                     proposal_hash: hash,
-                    threshold: 2
+                    threshold: 2,
+                    metadata: None
                 })),
                 record(RuntimeEvent::Collective(CollectiveEvent::Voted {
                     account: 1,
@@ -516,7 +545,8 @@ fn close_with_voting_prime_works() {
                     account: 1,
                     proposal_index: 0,
                     proposal_hash: hash,
-                    threshold: 2
+                    threshold: 2,
+                    metadata: None
                 })),
                 record(RuntimeEvent::Collective(CollectiveEvent::Voted {
                     account: 1,
@@ -598,7 +628,8 @@ fn close_with_no_prime_but_majority_works() {
                         account: 1,
                         proposal_index: 0,
                         proposal_hash: hash,
-                        threshold: 3
+                        threshold: 3,
+                        metadata: None
                     }
                 )),
                 record(RuntimeEvent::CollectiveMajority(CollectiveEvent::Voted {
@@ -665,7 +696,8 @@ fn removal_of_old_voters_votes_works() {
                 threshold: 2,
                 ayes: vec![1, 2],
                 nays: vec![],
-                end
+                end,
+                weighted: None
             })
         );
         Collective::change_members_sorted(&[4], &[1], &[2, 3, 4]);
@@ -676,7 +708,8 @@ fn removal_of_old_voters_votes_works() {
                 threshold: 2,
                 ayes: vec![2],
                 nays: vec![],
-                end
+                end,
+                weighted: None
             })
         );
 
@@ -698,7 +731,8 @@ fn removal_of_old_voters_votes_works() {
                 threshold: 2,
                 ayes: vec![2],
                 nays: vec![3],
-                end
+                end,
+                weighted: None
             })
         );
         Collective::change_members_sorted(&[], &[3], &[2, 4]);
@@ -709,7 +743,8 @@ fn removal_of_old_voters_votes_works() {
                 threshold: 2,
                 ayes: vec![2],
                 nays: vec![],
-                end
+                end,
+                weighted: None
             })
         );
     });
@@ -737,7 +772,8 @@ fn removal_of_old_voters_votes_works_with_set_members() {
                 threshold: 2,
                 ayes: vec![1, 2],
                 nays: vec![],
-                end
+                end,
+                weighted: None
             })
         );
         assert_ok!(Collective::set_members(
@@ -753,7 +789,8 @@ fn removal_of_old_voters_votes_works_with_set_members() {
                 threshold: 2,
                 ayes: vec![2],
                 nays: vec![],
-                end
+                end,
+                weighted: None
             })
         );
 
@@ -775,7 +812,8 @@ fn removal_of_old_voters_votes_works_with_set_members() {
                 threshold: 2,
                 ayes: vec![2],
                 nays: vec![3],
-                end
+                end,
+                weighted: None
             })
         );
         assert_ok!(Collective::set_members(
@@ -791,7 +829,8 @@ fn removal_of_old_voters_votes_works_with_set_members() {
                 threshold: 2,
                 ayes: vec![2],
                 nays: vec![],
-                end
+                end,
+                weighted: None
             })
         );
     });
@@ -819,7 +858,8 @@ fn propose_works() {
                 threshold: 2,
                 ayes: vec![],
                 nays: vec![],
-                end
+                end,
+                weighted: None
             })
         );
 
@@ -830,7 +870,8 @@ fn propose_works() {
                     account: 1,
                     proposal_index: 0,
                     proposal_hash: hash,
-                    threshold: 2
+                    threshold: 2,
+                    metadata: None
                 }
             ))]
         );
@@ -990,7 +1031,8 @@ fn motions_vote_after_works() {
                 threshold: 2,
                 ayes: vec![],
                 nays: vec![],
-                end
+                end,
+                weighted: None
             })
         );
         // Cast first aye vote.
@@ -1002,7 +1044,8 @@ fn motions_vote_after_works() {
                 threshold: 2,
                 ayes: vec![1],
                 nays: vec![],
-                end
+                end,
+                weighted: None
             })
         );
         // Try to cast a duplicate aye vote.
@@ -1019,7 +1062,8 @@ fn motions_vote_after_works() {
                 threshold: 2,
                 ayes: vec![],
                 nays: vec![1],
-                end
+                end,
+                weighted: None
             })
         );
         // Try to cast a duplicate nay vote.
@@ -1035,7 +1079,8 @@ fn motions_vote_after_works() {
                     account: 1,
                     proposal_index: 0,
                     proposal_hash: hash,
-                    threshold: 2
+                    threshold: 2,
+                    metadata: None
                 })),
                 record(RuntimeEvent::Collective(CollectiveEvent::Voted {
                     account: 1,
@@ -1076,7 +1121,8 @@ fn motions_all_first_vote_free_works() {
                 threshold: 2,
                 ayes: vec![],
                 nays: vec![],
-                end
+                end,
+                weighted: None
             })
         );
 
@@ -1199,7 +1245,8 @@ fn motions_approval_with_enough_votes_and_lower_voting_threshold_works() {
                     account: 1,
                     proposal_index: 0,
                     proposal_hash: hash,
-                    threshold: 2
+                    threshold: 2,
+                    metadata: None
                 })),
                 record(RuntimeEvent::Collective(CollectiveEvent::Voted {
                     account: 1,
@@ -1256,7 +1303,8 @@ fn motions_approval_with_enough_votes_and_lower_voting_threshold_works() {
                     account: 1,
                     proposal_index: 1,
                     proposal_hash: hash,
-                    threshold: 2
+                    threshold: 2,
+                    metadata: None
                 })),
                 record(RuntimeEvent::Collective(CollectiveEvent::Voted {
                     account: 1,
@@ -1329,7 +1377,8 @@ fn motions_disapproval_works() {
                     account: 1,
                     proposal_index: 0,
                     proposal_hash: hash,
-                    threshold: 2
+                    threshold: 2,
+                    metadata: None
                 })),
                 record(RuntimeEvent::Collective(CollectiveEvent::Voted {
                     account: 1,
@@ -1388,7 +1437,8 @@ fn motions_approval_works() {
                     account: 1,
                     proposal_index: 0,
                     proposal_hash: hash,
-                    threshold: 2
+                    threshold: 2,
+                    metadata: None
                 })),
                 record(RuntimeEvent::Collective(CollectiveEvent::Voted {
                     account: 1,
@@ -1440,7 +1490,8 @@ fn motion_with_no_votes_closes_with_disapproval() {
                 account: 1,
                 proposal_index: 0,
                 proposal_hash: hash,
-                threshold: 2
+                threshold: 2,
+                metadata: None
             }))
         );
 
@@ -1558,7 +1609,8 @@ fn disapprove_proposal_works() {
                     account: 1,
                     proposal_index: 0,
                     proposal_hash: hash,
-                    threshold: 2
+                    threshold: 2,
+                    metadata: None
                 })),
                 record(RuntimeEvent::Collective(CollectiveEvent::Voted {
                     account: 1,
@@ -1582,6 +1634,268 @@ fn disapprove_proposal_works() {
     })
 }
 
+#[test]
+fn propose_with_metadata_works() {
+    new_test_ext().execute_with(|| {
+        let proposal = make_proposal(42);
+        let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+        let proposal_weight = proposal.get_dispatch_info().weight;
+        let hash: H256 = proposal.blake2_256().into();
+        let description_hash: H256 = b"description".blake2_256().into();
+        let metadata = ProposalMetadata {
+            title: b"Raise the tempo".to_vec().try_into().unwrap(),
+            description_hash,
+            url: b"https://forum.example/t/1".to_vec().try_into().unwrap(),
+            proposer: 1,
+        };
+
+        assert_noop!(
+            Collective::propose_with_metadata(
+                RuntimeOrigin::signed(1),
+                Box::new(proposal.clone()),
+                proposal_len,
+                3,
+                vec![0; 129],
+                description_hash,
+                b"https://forum.example/t/1".to_vec()
+            ),
+            Error::<Test, Instance1>::ProposalTitleTooLong
+        );
+        assert_noop!(
+            Collective::propose_with_metadata(
+                RuntimeOrigin::signed(1),
+                Box::new(proposal.clone()),
+                proposal_len,
+                3,
+                b"Raise the tempo".to_vec(),
+                description_hash,
+                vec![0; 257]
+            ),
+            Error::<Test, Instance1>::ProposalUrlTooLong
+        );
+
+        assert_ok!(Collective::propose_with_metadata(
+            RuntimeOrigin::signed(1),
+            Box::new(proposal.clone()),
+            proposal_len,
+            3,
+            b"Raise the tempo".to_vec(),
+            description_hash,
+            b"https://forum.example/t/1".to_vec()
+        ));
+        assert_eq!(
+            Collective::proposal_metadata_of(hash),
+            Some(metadata.clone())
+        );
+        System::assert_last_event(RuntimeEvent::Collective(CollectiveEvent::Proposed {
+            account: 1,
+            proposal_index: 0,
+            proposal_hash: hash,
+            threshold: 2,
+            metadata: Some(metadata),
+        }));
+
+        // The metadata is removed with the proposal.
+        assert_ok!(Collective::vote(RuntimeOrigin::signed(1), hash, 0, false));
+        assert_ok!(Collective::vote(RuntimeOrigin::signed(2), hash, 0, false));
+        assert_ok!(Collective::close(
+            RuntimeOrigin::signed(2),
+            hash,
+            0,
+            proposal_weight,
+            proposal_len
+        ));
+        assert_eq!(*Collective::proposals(), vec![]);
+        assert_eq!(Collective::proposal_metadata_of(hash), None);
+    });
+}
+
+#[test]
+fn weighted_motion_is_decided_by_voting_weight() {
+    new_test_ext().execute_with(|| {
+        CollectiveVotingWeights::set(Some(vec![(1, 10), (2, 20), (3, 70)]));
+        let proposal = make_proposal(42);
+        let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+        let proposal_weight = proposal.get_dispatch_info().weight;
+        let hash: H256 = proposal.blake2_256().into();
+        assert_ok!(Collective::propose(
+            RuntimeOrigin::signed(1),
+            Box::new(proposal.clone()),
+            proposal_len,
+            TryInto::<BlockNumberFor<Test>>::try_into(3u64).expect("convert u64 to block number.")
+        ));
+
+        // Two of three members would pass the count threshold, but not the weighted one.
+        assert_ok!(Collective::vote(RuntimeOrigin::signed(1), hash, 0, true));
+        assert_ok!(Collective::vote(RuntimeOrigin::signed(2), hash, 0, true));
+        let tally = Collective::weighted_tally(hash).unwrap();
+        assert_eq!((tally.ayes(), tally.nays(), tally.total()), (30, 0, 100));
+        assert_noop!(
+            Collective::close(
+                RuntimeOrigin::signed(2),
+                hash,
+                0,
+                proposal_weight,
+                proposal_len
+            ),
+            Error::<Test, Instance1>::TooEarlyToCloseProposal
+        );
+        assert_eq!(Collective::weighted_tallies(), vec![(hash, tally)]);
+
+        // Removed members lose their weight in the tally.
+        Collective::change_members_sorted(&[], &[1], &[2, 3]);
+        let tally = Collective::weighted_tally(hash).unwrap();
+        assert_eq!((tally.ayes(), tally.nays(), tally.total()), (20, 0, 100));
+
+        // The heaviest member voting against disapproves the motion early.
+        assert_ok!(Collective::vote(RuntimeOrigin::signed(3), hash, 0, false));
+        assert_ok!(Collective::close(
+            RuntimeOrigin::signed(2),
+            hash,
+            0,
+            proposal_weight,
+            proposal_len
+        ));
+        System::assert_last_event(RuntimeEvent::Collective(CollectiveEvent::Disapproved {
+            proposal_hash: hash,
+        }));
+        assert_eq!(*Collective::proposals(), vec![]);
+        assert_eq!(Collective::weighted_tally(hash), None);
+    });
+}
+
+#[test]
+fn weighted_motion_dispatches_with_backing_of_voting_weight() {
+    new_test_ext().execute_with(|| {
+        CollectiveVotingWeights::set(Some(vec![(1, 4), (2, 26), (3, 70)]));
+        let proposal = RuntimeCall::Democracy(mock_democracy::Call::external_propose_majority {});
+        let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+        let proposal_weight = proposal.get_dispatch_info().weight;
+        let hash: H256 = proposal.blake2_256().into();
+
+        // Two members holding 74% of the weight pass the motion, but fall short of the 3/4
+        // proportion required by `ExternalMajorityOrigin`.
+        assert_ok!(Collective::propose(
+            RuntimeOrigin::signed(1),
+            Box::new(proposal.clone()),
+            proposal_len,
+            TryInto::<BlockNumberFor<Test>>::try_into(3u64).expect("convert u64 to block number.")
+        ));
+        assert_ok!(Collective::vote(RuntimeOrigin::signed(1), hash, 0, true));
+        assert_ok!(Collective::vote(RuntimeOrigin::signed(3), hash, 0, true));
+        assert_ok!(Collective::close(
+            RuntimeOrigin::signed(2),
+            hash,
+            0,
+            proposal_weight,
+            proposal_len
+        ));
+        System::assert_last_event(RuntimeEvent::Collective(CollectiveEvent::Executed {
+            proposal_hash: hash,
+            result: Err(DispatchError::BadOrigin),
+        }));
+
+        // 96% of the weight is enough.
+        assert_ok!(Collective::propose(
+            RuntimeOrigin::signed(1),
+            Box::new(proposal.clone()),
+            proposal_len,
+            TryInto::<BlockNumberFor<Test>>::try_into(3u64).expect("convert u64 to block number.")
+        ));
+        assert_ok!(Collective::vote(RuntimeOrigin::signed(2), hash, 1, true));
+        assert_ok!(Collective::vote(RuntimeOrigin::signed(3), hash, 1, true));
+        assert_ok!(Collective::close(
+            RuntimeOrigin::signed(2),
+            hash,
+            1,
+            proposal_weight,
+            proposal_len
+        ));
+        System::assert_has_event(RuntimeEvent::Democracy(
+            mock_democracy::pallet::Event::<Test>::ExternalProposed,
+        ));
+        System::assert_last_event(RuntimeEvent::Collective(CollectiveEvent::Executed {
+            proposal_hash: hash,
+            result: Ok(()),
+        }));
+    });
+}
+
+#[test]
+fn weighted_motion_needs_the_threshold_of_votes() {
+    new_test_ext().execute_with(|| {
+        CollectiveVotingWeights::set(Some(vec![(1, 10), (2, 20), (3, 70)]));
+        let proposal = make_proposal(42);
+        let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+        let proposal_weight = proposal.get_dispatch_info().weight;
+        let hash: H256 = proposal.blake2_256().into();
+        let end = 4;
+        assert_ok!(Collective::propose(
+            RuntimeOrigin::signed(1),
+            Box::new(proposal.clone()),
+            proposal_len,
+            TryInto::<BlockNumberFor<Test>>::try_into(3u64).expect("convert u64 to block number.")
+        ));
+
+        // A single member holding most of the weight is one vote short of the threshold.
+        assert_ok!(Collective::vote(RuntimeOrigin::signed(3), hash, 0, true));
+        assert_noop!(
+            Collective::close(
+                RuntimeOrigin::signed(2),
+                hash,
+                0,
+                proposal_weight,
+                proposal_len
+            ),
+            Error::<Test, Instance1>::TooEarlyToCloseProposal
+        );
+
+        System::set_block_number(end);
+        assert_ok!(Collective::close(
+            RuntimeOrigin::signed(2),
+            hash,
+            0,
+            proposal_weight,
+            proposal_len
+        ));
+        System::assert_last_event(RuntimeEvent::Collective(CollectiveEvent::Disapproved {
+            proposal_hash: hash,
+        }));
+    });
+}
+
+#[test]
+fn weighted_motion_tallies_voting_weight_captured_at_proposal() {
+    new_test_ext().execute_with(|| {
+        CollectiveVotingWeights::set(Some(vec![(1, 10), (2, 20), (3, 70)]));
+        let proposal = make_proposal(42);
+        let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+        let hash: H256 = proposal.blake2_256().into();
+        assert_ok!(Collective::propose(
+            RuntimeOrigin::signed(1),
+            Box::new(proposal.clone()),
+            proposal_len,
+            TryInto::<BlockNumberFor<Test>>::try_into(3u64).expect("convert u64 to block number.")
+        ));
+        assert_ok!(Collective::vote(RuntimeOrigin::signed(1), hash, 0, true));
+        let tally = Collective::weighted_tally(hash).unwrap();
+        assert_eq!((tally.ayes(), tally.nays(), tally.total()), (10, 0, 100));
+
+        // Moving the weight of a voter to another member after the proposal counts it once.
+        CollectiveVotingWeights::set(Some(vec![(1, 0), (2, 30), (3, 70)]));
+        assert_ok!(Collective::vote(RuntimeOrigin::signed(2), hash, 0, true));
+        let tally = Collective::weighted_tally(hash).unwrap();
+        assert_eq!((tally.ayes(), tally.nays(), tally.total()), (30, 0, 100));
+
+        // A member joining after the proposal has no weight in the motion.
+        Collective::change_members_sorted(&[4], &[], &[1, 2, 3, 4]);
+        CollectiveVotingWeights::set(Some(vec![(1, 0), (2, 30), (3, 70), (4, 100)]));
+        assert_ok!(Collective::vote(RuntimeOrigin::signed(4), hash, 0, false));
+        let tally = Collective::weighted_tally(hash).unwrap();
+        assert_eq!((tally.ayes(), tally.nays(), tally.total()), (30, 0, 100));
+    });
+}
+
 #[test]
 #[should_panic(expected = "Members cannot contain duplicate accounts.")]
 fn genesis_build_panics_with_duplicate_members() {
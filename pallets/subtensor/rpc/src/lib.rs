@@ -32,6 +32,14 @@ pub trait SubtensorCustomApi<BlockHash> {
         delegatee_account_vec: Vec<u8>,
         at: Option<BlockHash>,
     ) -> RpcResult<Vec<u8>>;
+    #[method(name = "delegateInfo_getDelegates_v2")]
+    fn get_delegates_v2(&self, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+    #[method(name = "delegateInfo_getDelegate_v2")]
+    fn get_delegate_v2(
+        &self,
+        delegate_account_vec: Vec<u8>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
 
     #[method(name = "neuronInfo_getNeuronsLite")]
     fn get_neurons_lite(&self, netuid: u16, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
@@ -139,6 +147,28 @@ where
         })
     }
 
+    fn get_delegates_v2(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_delegates_v2(at).map_err(|e| {
+            Error::RuntimeError(format!("Unable to get delegates info v2: {:?}", e)).into()
+        })
+    }
+
+    fn get_delegate_v2(
+        &self,
+        delegate_account_vec: Vec<u8>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_delegate_v2(at, delegate_account_vec).map_err(|e| {
+            Error::RuntimeError(format!("Unable to get delegate info v2: {:?}", e)).into()
+        })
+    }
+
     fn get_neurons_lite(
         &self,
         netuid: u16,
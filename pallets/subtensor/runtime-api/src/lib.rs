@@ -9,6 +9,8 @@ sp_api::decl_runtime_apis! {
         fn get_delegates() -> Vec<u8>;
         fn get_delegate( delegate_account_vec: Vec<u8> ) -> Vec<u8>;
         fn get_delegated( delegatee_account_vec: Vec<u8> ) -> Vec<u8>;
+        fn get_delegates_v2() -> Vec<u8>;
+        fn get_delegate_v2( delegate_account_vec: Vec<u8> ) -> Vec<u8>;
     }
 
     pub trait NeuronInfoRuntimeApi {
@@ -29,9 +31,40 @@ sp_api::decl_runtime_apis! {
     pub trait StakeInfoRuntimeApi {
         fn get_stake_info_for_coldkey( coldkey_account_vec: Vec<u8> ) -> Vec<u8>;
         fn get_stake_info_for_coldkeys( coldkey_account_vecs: Vec<Vec<u8>> ) -> Vec<u8>;
+        fn get_unbonding_queue( coldkey_account_vec: Vec<u8> ) -> Vec<u8>;
     }
 
     pub trait SubnetRegistrationRuntimeApi {
         fn get_network_registration_cost() -> u64;
     }
+
+    pub trait ScheduleInfoRuntimeApi {
+        fn get_scheduled_coldkey_swap( coldkey_account_vec: Vec<u8> ) -> Vec<u8>;
+        fn get_scheduled_coldkey_swaps() -> Vec<u8>;
+        fn get_scheduled_network_dissolution( netuid: u16 ) -> Vec<u8>;
+        fn get_scheduled_network_dissolutions() -> Vec<u8>;
+    }
+
+    pub trait HyperparameterInfoRuntimeApi {
+        fn get_hyperparameter_bounds() -> Vec<u8>;
+        fn get_hyperparameter_templates() -> Vec<u8>;
+    }
+
+    pub trait ChildkeyInfoRuntimeApi {
+        fn get_pending_children( hotkey_account_vec: Vec<u8>, netuid: u16 ) -> Vec<u8>;
+        fn get_childkey_info( hotkey_account_vec: Vec<u8>, netuid: u16 ) -> Vec<u8>;
+        fn get_pending_parents( child_account_vec: Vec<u8> ) -> Vec<u8>;
+        fn get_childkey_emission_history( netuid: u16 ) -> Vec<u8>;
+    }
+
+    pub trait SwapInfoRuntimeApi {
+        fn get_hotkey_swap_preview( coldkey_account_vec: Vec<u8>, old_hotkey_account_vec: Vec<u8>, new_hotkey_account_vec: Vec<u8> ) -> Vec<u8>;
+        fn get_coldkey_swap_preview( old_coldkey_account_vec: Vec<u8>, new_coldkey_account_vec: Vec<u8> ) -> Vec<u8>;
+    }
+
+    pub trait SenateVotingRuntimeApi {
+        fn get_weighted_tally( proposal_hash_vec: Vec<u8> ) -> Vec<u8>;
+        fn get_weighted_tallies() -> Vec<u8>;
+        fn get_proposal_metadata( proposal_hash_vec: Vec<u8> ) -> Vec<u8>;
+    }
 }
@@ -9,7 +9,11 @@ impl<T: Config> Pallet<T> {
         log::debug!("block_step for block: {:?} ", block_number);
         // --- 1. Adjust difficulties.
         Self::adjust_registration_terms_for_networks();
-        // --- 2. Run emission through network.
+        // --- 2. Apply the delegate take increases whose notice period has passed.
+        Self::enact_pending_delegate_takes(block_number);
+        // --- 3. Drop the children proposals which were not accepted in time.
+        Self::remove_expired_childkey_proposals(block_number);
+        // --- 4. Run emission through network.
         Self::run_coinbase();
         // Return ok.
         Ok(())
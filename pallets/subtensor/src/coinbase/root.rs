@@ -19,8 +19,11 @@ use super::*;
 use crate::epoch::math::*;
 use frame_support::dispatch::Pays;
 use frame_support::storage::{IterableStorageDoubleMap, IterableStorageMap};
+use frame_support::traits::schedule::v3::{Named as ScheduleNamed, TaskName};
 use frame_support::traits::Get;
 use frame_support::weights::Weight;
+use frame_system::ensure_signed_or_root;
+use sp_io::hashing::blake2_256;
 use sp_runtime::Saturating;
 use sp_std::vec;
 use substrate_fixed::{
@@ -638,43 +641,30 @@ impl<T: Config> Pallet<T> {
         Self::create_account_if_non_existent(&coldkey, &hotkey);
 
         // --- 4. Join the Senate if eligible.
-        // Returns the replaced member, if any.
-        let replaced = Self::join_senate_if_eligible(&hotkey)?;
+        // Emits SenateAdjusted if the hotkey joined.
+        Self::join_senate_if_eligible(&hotkey)?;
 
-        if replaced.is_none() {
-            // Not eligible to join the Senate, or no replacement needed.
-            // Check if the hotkey is *now* a member of the Senate.
-            // Otherwise, error out.
-            ensure!(
-                T::SenateMembers::is_member(&hotkey),
-                Error::<T>::StakeTooLowForRoot, // Had less stake than the lowest stake incumbent.
-            );
-        }
-
-        // --- 5. Log and announce the successful Senate adjustment.
-        log::debug!(
-            "SenateAdjusted(old_hotkey:{:?} hotkey:{:?})",
-            replaced,
-            hotkey
+        // --- 5. Check if the hotkey is *now* a member of the Senate.
+        // Otherwise, error out.
+        ensure!(
+            T::SenateMembers::is_member(&hotkey),
+            Error::<T>::StakeTooLowForRoot, // Had less stake than the lowest stake incumbent.
         );
-        Self::deposit_event(Event::SenateAdjusted {
-            old_member: replaced.cloned(),
-            new_member: hotkey,
-        });
 
         // --- 6. Finish and return success.
         Ok(())
     }
 
     // Checks if a hotkey should be a member of the Senate, and if so, adds them.
+    // Emits SenateAdjusted when the hotkey joins.
     //
     // # Arguments:
     // * 'hotkey': The hotkey that the user wants to register to the root network.
     //
     // # Returns:
-    // * 'Result<Option<&T::AccountId>, Error<T>>': A result containing the replaced member, if any.
+    // * 'Result<Option<T::AccountId>, Error<T>>': A result containing the replaced member, if any.
     //
-    fn join_senate_if_eligible(hotkey: &T::AccountId) -> Result<Option<&T::AccountId>, Error<T>> {
+    fn join_senate_if_eligible(hotkey: &T::AccountId) -> Result<Option<T::AccountId>, Error<T>> {
         // Get the root network UID.
         let root_netuid: u16 = Self::get_root_netuid();
 
@@ -696,7 +686,7 @@ impl<T: Config> Pallet<T> {
         // Add the hotkey to the Senate.
         // If we're full, we'll swap out the lowest stake member.
         let members = T::SenateMembers::members();
-        let last: Option<&T::AccountId> = None;
+        let mut replaced: Option<T::AccountId> = None;
         if (members.len() as u32) == T::SenateMembers::max_members() {
             let mut sorted_members = members.clone();
             sorted_members.sort_by(|a, b| {
@@ -706,21 +696,159 @@ impl<T: Config> Pallet<T> {
                 b_stake.cmp(&a_stake)
             });
 
-            if let Some(last) = sorted_members.last() {
-                let last_stake = Self::get_total_stake_for_hotkey(last);
-
-                if last_stake < current_stake {
+            match sorted_members.last() {
+                Some(last) if Self::get_total_stake_for_hotkey(last) < current_stake => {
                     // Swap the member with the lowest stake.
                     T::SenateMembers::swap_member(last, hotkey)
                         .map_err(|_| Error::<T>::CouldNotJoinSenate)?;
+                    replaced = Some(last.clone());
                 }
+                // Not eligible to join the Senate.
+                _ => return Ok(None),
             }
         } else {
             T::SenateMembers::add_member(hotkey).map_err(|_| Error::<T>::CouldNotJoinSenate)?;
         }
 
+        // Announce the Senate adjustment.
+        log::debug!(
+            "SenateAdjusted(old_hotkey:{:?} hotkey:{:?})",
+            replaced,
+            hotkey
+        );
+        Self::deposit_event(Event::SenateAdjusted {
+            old_member: replaced.clone(),
+            new_member: hotkey.clone(),
+        });
+
         // Return the swapped out member, if any.
-        Ok(last)
+        Ok(replaced)
+    }
+
+    /// Rebuilds the Senate from the root stakers if the block is a multiple of the
+    /// `SenateAdjustmentInterval`, an interval of 0 disables the rebuilds.
+    ///
+    /// Called from `on_initialize`, returns the weight to charge for the block.
+    pub fn adjust_senate_if_due(block_number: u64) -> Weight {
+        let weight = T::DbWeight::get().reads(1);
+        let senate_adjustment_interval: u64 = Self::get_senate_adjustment_interval();
+        if senate_adjustment_interval > 0
+            && block_number.checked_rem(senate_adjustment_interval) == Some(0)
+        {
+            weight.saturating_add(Self::adjust_senate_by_root_stake())
+        } else {
+            weight
+        }
+    }
+
+    /// Rebuilds the Senate from the root network validators with the most stake.
+    ///
+    /// Called every `SenateAdjustmentInterval` blocks. Members which are no longer among the top
+    /// `max_members` root stakers lose their pending votes and are replaced by the highest staked
+    /// root validators outside the Senate. Equal stake is resolved in favour of the incumbent, so
+    /// the Senate only changes when a member is strictly outstaked.
+    ///
+    /// The work is bounded by the root network size and `max_members`, returns its weight.
+    pub fn adjust_senate_by_root_stake() -> Weight {
+        let mut weight = T::DbWeight::get().reads(1);
+        let root_netuid: u16 = Self::get_root_netuid();
+        if !Self::if_subnet_exist(root_netuid) {
+            return weight;
+        }
+
+        // --- 1. Rank the root validators by stake, incumbents first on ties.
+        let members: Vec<T::AccountId> = T::SenateMembers::members();
+        let mut candidates: Vec<(T::AccountId, u64)> =
+            <Keys<T> as IterableStorageDoubleMap<u16, u16, T::AccountId>>::iter_prefix(root_netuid)
+                .map(|(_, hotkey)| {
+                    let stake: u64 = Self::get_total_stake_for_hotkey(&hotkey);
+                    (hotkey, stake)
+                })
+                .collect();
+        weight.saturating_accrue(
+            T::DbWeight::get().reads(
+                (candidates.len() as u64)
+                    .saturating_mul(2)
+                    .saturating_add(1),
+            ),
+        );
+        candidates.sort_by(|(a, a_stake), (b, b_stake)| {
+            b_stake
+                .cmp(a_stake)
+                .then_with(|| members.contains(b).cmp(&members.contains(a)))
+        });
+        let elected: Vec<T::AccountId> = candidates
+            .into_iter()
+            .take(T::SenateMembers::max_members() as usize)
+            .map(|(hotkey, _)| hotkey)
+            .collect();
+
+        // --- 2. Replace the members which were not elected.
+        let mut incoming = elected.iter().filter(|hotkey| !members.contains(hotkey));
+        for old_member in members.iter().filter(|member| !elected.contains(member)) {
+            weight.saturating_accrue(Self::senate_member_change_weight());
+
+            // The outgoing member's votes on open proposals no longer count.
+            if let Err(e) = T::TriumvirateInterface::remove_votes(old_member) {
+                log::error!("Failed to remove votes of {:?}: {:?}", old_member, e);
+            }
+
+            match incoming.next() {
+                Some(new_member) => {
+                    if let Err(e) = T::SenateMembers::swap_member(old_member, new_member) {
+                        log::error!("Failed to swap senate member {:?}: {:?}", old_member, e);
+                        continue;
+                    }
+                    log::debug!(
+                        "SenateAdjusted(old_hotkey:{:?} hotkey:{:?})",
+                        old_member,
+                        new_member
+                    );
+                    Self::deposit_event(Event::SenateAdjusted {
+                        old_member: Some(old_member.clone()),
+                        new_member: new_member.clone(),
+                    });
+                }
+                None => {
+                    if let Err(e) = T::SenateMembers::remove_member(old_member) {
+                        log::error!("Failed to remove senate member {:?}: {:?}", old_member, e);
+                        continue;
+                    }
+                    log::debug!("SenateMemberRemoved(hotkey:{:?})", old_member);
+                    Self::deposit_event(Event::SenateMemberRemoved {
+                        member: old_member.clone(),
+                    });
+                }
+            }
+        }
+
+        // --- 3. Fill any remaining seats.
+        for new_member in incoming {
+            weight.saturating_accrue(Self::senate_member_change_weight());
+
+            if let Err(e) = T::SenateMembers::add_member(new_member) {
+                log::error!("Failed to add senate member {:?}: {:?}", new_member, e);
+                continue;
+            }
+            log::debug!(
+                "SenateAdjusted(old_hotkey:{:?} hotkey:{:?})",
+                None::<T::AccountId>,
+                new_member
+            );
+            Self::deposit_event(Event::SenateAdjusted {
+                old_member: None,
+                new_member: new_member.clone(),
+            });
+        }
+
+        weight
+    }
+
+    /// The weight of one change of the Senate by `adjust_senate_by_root_stake`, as charged for the
+    /// membership change of `root_register`. It covers the membership, the collective members and
+    /// the votes of the outgoing member.
+    fn senate_member_change_weight() -> Weight {
+        Weight::from_parts(164_000_000, 0).saturating_add(T::DbWeight::get().reads_writes(23, 20))
     }
 
     pub fn do_set_root_weights(
@@ -896,10 +1024,12 @@ impl<T: Config> Pallet<T> {
     /// # Args:
     /// * `origin` (`T::RuntimeOrigin`): The calling origin. Must be signed.
     /// * `identity` (`Option<SubnetIdentityOf>`): Optional identity to be associated with the new subnetwork.
+    /// * `template_id` (`Option<u16>`): Optional hyperparameter template to apply to the new subnetwork.
     ///
     /// # Events:
     /// * `NetworkAdded(netuid, modality)`: Emitted when a new network is successfully added.
     /// * `SubnetIdentitySet(netuid)`: Emitted when a custom identity is set for a new subnetwork.
+    /// * `HyperparameterTemplateApplied`: Emitted when a hyperparameter template is applied to the new subnetwork.
     /// * `NetworkRemoved(netuid)`: Emitted when an existing network is removed to make room for the new one.
     /// * `SubnetIdentityRemoved(netuid)`: Emitted when the identity of a removed network is also deleted.
     ///
@@ -907,10 +1037,12 @@ impl<T: Config> Pallet<T> {
     /// * 'TxRateLimitExceeded': If the rate limit for network registration is exceeded.
     /// * 'NotEnoughBalanceToStake': If there isn't enough balance to stake for network registration.
     /// * 'BalanceWithdrawalError': If an error occurs during balance withdrawal for network registration.
+    /// * 'HyperparameterTemplateNotFound': If the hyperparameter template does not exist.
     ///
     pub fn user_add_network(
         origin: T::RuntimeOrigin,
         identity: Option<SubnetIdentityOf>,
+        template_id: Option<u16>,
     ) -> dispatch::DispatchResult {
         // --- 0. Ensure the caller is a signed user.
         let coldkey = ensure_signed(origin)?;
@@ -931,6 +1063,14 @@ impl<T: Config> Pallet<T> {
             Error::<T>::NotEnoughBalanceToStake
         );
 
+        // --- 3. Ensure the hyperparameter template exists.
+        if let Some(template_id) = template_id {
+            ensure!(
+                HyperparameterTemplates::<T>::contains_key(template_id),
+                Error::<T>::HyperparameterTemplateNotFound
+            );
+        }
+
         // --- 4. Determine the netuid to register.
         let netuid_to_register: u16 = {
             log::debug!(
@@ -972,6 +1112,9 @@ impl<T: Config> Pallet<T> {
         // --- 6. Set initial and custom parameters for the network.
         Self::init_new_network(netuid_to_register, 360);
         log::debug!("init_new_network: {:?}", netuid_to_register,);
+        if let Some(template_id) = template_id {
+            Self::apply_hyperparameter_template(netuid_to_register, template_id)?;
+        }
 
         // --- 7. Add the identity if it exists
         if let Some(identity_value) = identity {
@@ -1044,6 +1187,105 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    /// Performs a network dissolution scheduled by `schedule_dissolve_network`.
+    ///
+    /// The record of the dissolution is dropped whether the dissolution succeeds or not. A failed
+    /// dissolution, for example after the subnet changed owner, emits `DissolveNetworkFailed`
+    /// instead of an error so that the removal of its record is kept.
+    ///
+    /// # Args:
+    /// * 'netuid': ('u16'): The unique identifier of the network.
+    ///
+    /// # Event:
+    /// * 'NetworkRemoved': Emitted when the network is removed.
+    /// * 'DissolveNetworkFailed': Emitted when the network could not be removed.
+    ///
+    /// # Raises:
+    /// * 'DissolveNetworkNotScheduled': If no dissolution is recorded for the network.
+    ///
+    pub fn do_dissolve_scheduled_network(netuid: u16) -> dispatch::DispatchResult {
+        // --- 1. Take the record of the dissolution.
+        let (coldkey, _) = ScheduledNetworkDissolutions::<T>::take(netuid)
+            .ok_or(Error::<T>::DissolveNetworkNotScheduled)?;
+
+        // --- 2. Dissolve the network for the account which scheduled it.
+        if let Err(error) = Self::user_remove_network(coldkey.clone(), netuid) {
+            log::debug!(
+                "DissolveNetworkFailed( account:{:?}, netuid:{:?}, error:{:?} )",
+                coldkey,
+                netuid,
+                error
+            );
+            Self::deposit_event(Event::DissolveNetworkFailed {
+                account: coldkey,
+                netuid,
+                error,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Cancels a network dissolution scheduled with `schedule_dissolve_network`.
+    ///
+    /// # Args:
+    /// * 'origin': ('T::RuntimeOrigin'): Root, the account which scheduled the dissolution,
+    ///   or the current subnet owner.
+    /// * 'netuid': ('u16'): The unique identifier of the network.
+    ///
+    /// # Event:
+    /// * 'DissolveNetworkCancelled': Emitted when the dissolution is cancelled.
+    ///
+    /// # Raises:
+    /// * 'DissolveNetworkNotScheduled': If no named dissolution is recorded for the network.
+    /// * 'BadOrigin': If the signer neither scheduled the dissolution nor owns the subnet.
+    /// * 'FailedToCancelSchedule': If the scheduler fails to cancel the pending task.
+    ///
+    pub fn do_cancel_scheduled_dissolve_network(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+    ) -> dispatch::DispatchResult {
+        // --- 1. Ensure a dissolution is recorded for this subnet.
+        let (account, _) = ScheduledNetworkDissolutions::<T>::get(netuid)
+            .ok_or(Error::<T>::DissolveNetworkNotScheduled)?;
+
+        // --- 2. Ensure the caller may cancel it.
+        if let Some(who) = ensure_signed_or_root(origin)? {
+            ensure!(
+                who == account || who == SubnetOwner::<T>::get(netuid),
+                DispatchError::BadOrigin
+            );
+        }
+
+        // --- 3. Cancel the task if it is still in the scheduler.
+        let task_name = Self::dissolve_network_task_name(netuid);
+        if T::Scheduler::next_dispatch_time(task_name).is_ok() {
+            T::Scheduler::cancel_named(task_name)
+                .map_err(|_| Error::<T>::FailedToCancelSchedule)?;
+        }
+        ScheduledNetworkDissolutions::<T>::remove(netuid);
+
+        // --- 4. Emit the DissolveNetworkCancelled event.
+        log::debug!(
+            "DissolveNetworkCancelled( account:{:?}, netuid:{:?} )",
+            account,
+            netuid
+        );
+        Self::deposit_event(Event::DissolveNetworkCancelled { account, netuid });
+
+        Ok(())
+    }
+
+    /// Returns the scheduler task name of the dissolution of `netuid`.
+    pub fn dissolve_network_task_name(netuid: u16) -> TaskName {
+        (b"subtensor/dissolve_network", netuid).using_encoded(blake2_256)
+    }
+
+    /// Returns true if a dissolution of `netuid` is waiting in the scheduler.
+    pub fn is_network_dissolution_scheduled(netuid: u16) -> bool {
+        T::Scheduler::next_dispatch_time(Self::dissolve_network_task_name(netuid)).is_ok()
+    }
+
     /// Sets initial and custom parameters for a new network.
     pub fn init_new_network(netuid: u16, tempo: u16) {
         // --- 1. Set network to 0 size.
@@ -1207,17 +1449,30 @@ impl<T: Config> Pallet<T> {
         RegistrationsThisInterval::<T>::remove(netuid);
         POWRegistrationsThisInterval::<T>::remove(netuid);
         BurnRegistrationsThisInterval::<T>::remove(netuid);
+        MaxChildren::<T>::remove(netuid);
+        MinChildProportion::<T>::remove(netuid);
+        SetChildrenCooldown::<T>::remove(netuid);
+        SubnetMinChildkeyTake::<T>::remove(netuid);
+        SubnetMaxChildkeyTake::<T>::remove(netuid);
 
         // --- 12. Add the balance back to the owner.
         Self::add_balance_to_coldkey_account(&owner_coldkey, reserved_amount);
         Self::set_subnet_locked_balance(netuid, 0);
         SubnetOwner::<T>::remove(netuid);
+        PendingSubnetOwnerTransfer::<T>::remove(netuid);
+        Self::clear_pending_hyperparameter_changes(netuid);
+        ChildkeyEmissionHistory::<T>::remove(netuid);
 
         // --- 13. Remove subnet identity if it exists.
         if SubnetIdentities::<T>::contains_key(netuid) {
             SubnetIdentities::<T>::remove(netuid);
             Self::deposit_event(Event::SubnetIdentityRemoved(netuid));
         }
+
+        // --- 14. Drop a dissolution still scheduled for this netuid so it cannot hit a later subnet.
+        if ScheduledNetworkDissolutions::<T>::take(netuid).is_some() {
+            let _ = T::Scheduler::cancel_named(Self::dissolve_network_task_name(netuid));
+        }
     }
 
     #[allow(clippy::arithmetic_side_effects)]
@@ -120,16 +120,26 @@ impl<T: Config> Pallet<T> {
                 );
 
                 // 4.4 Accumulate the tuples on hotkeys:
+                let mut transfers: Vec<(T::AccountId, T::AccountId, u64)> = Vec::new();
                 for (hotkey, mining_emission, validator_emission) in hotkey_emission {
                     // 4.5 Accumulate the emission on the hotkey and parent hotkeys.
-                    Self::accumulate_hotkey_emission(
-                        &hotkey,
-                        *netuid,
-                        validator_emission, // Amount received from validating
-                        mining_emission,    // Amount recieved from mining.
-                    );
+                    let parent_emission: Vec<(T::AccountId, u64)> =
+                        Self::accumulate_hotkey_emission(
+                            &hotkey,
+                            *netuid,
+                            validator_emission, // Amount received from validating
+                            mining_emission,    // Amount recieved from mining.
+                        );
                     log::debug!("Accumulated emissions on hotkey {:?} for netuid {:?}: mining {:?}, validator {:?}", hotkey, *netuid, mining_emission, validator_emission);
+                    transfers.extend(
+                        parent_emission
+                            .into_iter()
+                            .map(|(parent, amount)| (hotkey.clone(), parent, amount)),
+                    );
                 }
+
+                // 4.6 Record what the children passed to their parents in this epoch.
+                Self::record_childkey_emission(*netuid, current_block, transfers);
             } else {
                 // No epoch, increase blocks since last step and continue
                 Self::set_blocks_since_last_step(
@@ -153,7 +163,10 @@ impl<T: Config> Pallet<T> {
             }
 
             // --- 5.1 Check if we should drain the hotkey emission on this block.
-            if Self::should_drain_hotkey(&hotkey, current_block, emission_tempo) {
+            // A hotkey being swapped keeps accumulating until its stake rows have all moved.
+            if Self::should_drain_hotkey(&hotkey, current_block, emission_tempo)
+                && !Self::is_key_swapping(&hotkey)
+            {
                 // --- 5.2 Drain the hotkey emission and distribute it to nominators.
                 let total_new_tao: u64 =
                     Self::drain_hotkey_emission(&hotkey, hotkey_emission, current_block);
@@ -184,12 +197,15 @@ impl<T: Config> Pallet<T> {
     /// * `mining_emission` - The amount of mining emission allocated to the hotkey.
     /// * `validator_emission` - The amount of validator emission allocated to the hotkey.
     ///
+    /// # Returns
+    /// * `Vec<(T::AccountId, u64)>` - The emission passed to each parent.
+    ///
     pub fn accumulate_hotkey_emission(
         hotkey: &T::AccountId,
         netuid: u16,
         validating_emission: u64,
         mining_emission: u64,
-    ) {
+    ) -> Vec<(T::AccountId, u64)> {
         // --- 1. First, calculate the hotkey's share of the emission.
         let take_proportion: I64F64 = I64F64::from_num(Self::get_childkey_take(hotkey, netuid))
             .saturating_div(I64F64::from_num(u16::MAX));
@@ -201,8 +217,9 @@ impl<T: Config> Pallet<T> {
         // --- 2. Compute the remaining emission after the hotkey's share is deducted.
         let emission_minus_take: u64 = validating_emission.saturating_sub(hotkey_take);
 
-        // --- 3. Track the remaining emission for accounting purposes.
+        // --- 3. Track the remaining emission and what goes to each parent for accounting purposes.
         let mut remaining_emission: u64 = emission_minus_take;
+        let mut parent_emission: Vec<(T::AccountId, u64)> = Vec::new();
 
         // --- 4. Calculate the total stake of the hotkey, adjusted by the stakes of parents and children.
         // Parents contribute to the stake, while children reduce it.
@@ -227,9 +244,12 @@ impl<T: Config> Pallet<T> {
                     .to_num::<u64>();
 
                 // --- 5.5. Accumulate emissions for the parent hotkey.
-                PendingdHotkeyEmission::<T>::mutate(parent, |parent_accumulated| {
+                PendingdHotkeyEmission::<T>::mutate(&parent, |parent_accumulated| {
                     *parent_accumulated = parent_accumulated.saturating_add(parent_emission_take)
                 });
+                if parent_emission_take > 0 {
+                    parent_emission.push((parent, parent_emission_take));
+                }
 
                 // --- 5.6. Subtract the parent's share from the remaining emission for this hotkey.
                 remaining_emission = remaining_emission.saturating_sub(parent_emission_take);
@@ -244,6 +264,53 @@ impl<T: Config> Pallet<T> {
                     .saturating_add(mining_emission),
             )
         });
+
+        // --- 7. Return what the parents received.
+        parent_emission
+    }
+
+    /// Records the emission passed from children to parents in an epoch of a subnet, keeping the
+    /// last `CHILDKEY_EMISSION_HISTORY_EPOCHS` epochs.
+    ///
+    /// # Arguments
+    /// * `netuid` - The subnet which ran its epoch.
+    /// * `block_number` - The block of the epoch.
+    /// * `transfers` - The emission passed, as (child, parent, amount).
+    pub fn record_childkey_emission(
+        netuid: u16,
+        block_number: u64,
+        transfers: Vec<(T::AccountId, T::AccountId, u64)>,
+    ) {
+        if transfers.is_empty() {
+            return;
+        }
+
+        let total_emission: u64 = transfers
+            .iter()
+            .fold(0u64, |total, (_, _, amount)| total.saturating_add(*amount));
+        let transfer_count: u32 = transfers.len().try_into().unwrap_or(u32::MAX);
+        ChildkeyEmissionHistory::<T>::mutate(netuid, |history| {
+            history.push(ChildkeyEmissionRecord {
+                block: block_number,
+                transfers,
+            });
+            let excess: usize = history
+                .len()
+                .saturating_sub(CHILDKEY_EMISSION_HISTORY_EPOCHS);
+            history.drain(..excess);
+        });
+
+        log::debug!(
+            "ChildkeyEmissionRecorded( netuid:{:?}, transfers:{:?}, total_emission:{:?} )",
+            netuid,
+            transfer_count,
+            total_emission
+        );
+        Self::deposit_event(Event::ChildkeyEmissionRecorded {
+            netuid,
+            transfers: transfer_count,
+            total_emission,
+        });
     }
 
     //. --- 4. Drains the accumulated hotkey emission through to the nominators. The hotkey takes a proportion of the emission.
@@ -254,12 +321,12 @@ impl<T: Config> Pallet<T> {
     /// 1. It resets the accumulated emissions for the hotkey to zero.
     /// 4. It calculates the total stake for the hotkey and determines the hotkey's own take from the emissions based on its delegation status.
     /// 5. It then calculates the remaining emissions after the hotkey's take and distributes this remaining amount proportionally among the hotkey's nominators.
-    /// 6. Each nominator's share of the emissions is added to their stake, but only if their stake was not manually increased since the last emission drain.
+    /// 6. Each nominator's share of the emissions is added to their stake, or paid to a free balance as set in `RewardPayouts`, but only if their stake was not manually increased since the last emission drain.
     /// 7. Finally, the hotkey's own take and any undistributed emissions are added to the hotkey's total stake.
     ///
     /// This function ensures that emissions are fairly distributed according to stake proportions and delegation agreements, and it updates the necessary records to reflect these changes.
     pub fn drain_hotkey_emission(hotkey: &T::AccountId, emission: u64, block_number: u64) -> u64 {
-        // --- 0. For accounting purposes record the total new added stake.
+        // --- 0. For accounting purposes record the total new tao, staked or paid to free balances.
         let mut total_new_tao: u64 = 0;
 
         // --- 1.0 Drain the hotkey emission.
@@ -310,8 +377,8 @@ impl<T: Config> Pallet<T> {
                     .checked_div(I64F64::from_num(total_viable_nominator_stake))
                     .unwrap_or(I64F64::from_num(0));
 
-                // --- 12 Increase the stake for the nominator.
-                Self::increase_stake_on_coldkey_hotkey_account(
+                // --- 12 Pay the nominator, increasing its stake unless it set a payout to a free balance.
+                Self::pay_nominator_emission(
                     &nominator,
                     hotkey,
                     nominator_emission.to_num::<u64>(),
@@ -327,11 +394,48 @@ impl<T: Config> Pallet<T> {
         let hotkey_new_tao: u64 = hotkey_take.saturating_add(remainder);
         Self::increase_stake_on_hotkey_account(hotkey, hotkey_new_tao);
 
-        // --- 15 Record new tao creation event and return the amount created.
+        // --- 15 Add the drain to the hotkey's history, total_new_tao is so far what the nominators received.
+        Self::record_hotkey_drain(hotkey, block_number, emission, hotkey_take, total_new_tao);
+
+        // --- 16 Record new tao creation event and return the amount created.
         total_new_tao = total_new_tao.saturating_add(hotkey_new_tao);
         total_new_tao
     }
 
+    /// Adds a drain to the daily totals of a hotkey, dropping the days older than `DRAIN_HISTORY_DAYS`.
+    ///
+    /// # Arguments
+    /// * `hotkey` - The drained hotkey.
+    /// * `block_number` - The block of the drain.
+    /// * `drained` - The emission drained from the hotkey.
+    /// * `take` - The take collected by the hotkey.
+    /// * `distributed` - The emission distributed to the nominators.
+    pub fn record_hotkey_drain(
+        hotkey: &T::AccountId,
+        block_number: u64,
+        drained: u64,
+        take: u64,
+        distributed: u64,
+    ) {
+        let day: u64 = block_number.checked_div(BLOCKS_PER_DAY).unwrap_or(0);
+        HotkeyDrainHistory::<T>::mutate(hotkey, |history| {
+            history.retain(|record| record.day.saturating_add(DRAIN_HISTORY_DAYS) > day);
+            match history.last_mut() {
+                Some(record) if record.day == day => {
+                    record.drained = record.drained.saturating_add(drained);
+                    record.take = record.take.saturating_add(take);
+                    record.distributed = record.distributed.saturating_add(distributed);
+                }
+                _ => history.push(HotkeyDrainRecord {
+                    day,
+                    drained,
+                    take,
+                    distributed,
+                }),
+            }
+        });
+    }
+
     ///////////////
     /// Helpers ///
     ///////////////
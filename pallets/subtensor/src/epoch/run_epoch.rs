@@ -45,14 +45,11 @@ impl<T: Config> Pallet<T> {
         // Iterate over children to calculate the total stake allocated to them.
         for (proportion, _) in children {
             // Calculate the stake proportion allocated to the child based on the initial stake.
-            let normalized_proportion: I96F32 =
-                I96F32::from_num(proportion).saturating_div(I96F32::from_num(u64::MAX));
-            let stake_proportion_to_child: I96F32 =
-                I96F32::from_num(initial_stake).saturating_mul(normalized_proportion);
+            let stake_proportion_to_child: u64 =
+                Self::get_childkey_stake_proportion(initial_stake, proportion);
 
             // Accumulate the total stake given to children.
-            stake_to_children =
-                stake_to_children.saturating_add(stake_proportion_to_child.to_num::<u64>());
+            stake_to_children = stake_to_children.saturating_add(stake_proportion_to_child);
         }
 
         // Iterate over parents to calculate the total stake received from them.
@@ -60,14 +57,11 @@ impl<T: Config> Pallet<T> {
             // Retrieve the parent's total stake.
             let parent_stake: u64 = Self::get_total_stake_for_hotkey(&parent);
             // Calculate the stake proportion received from the parent.
-            let normalized_proportion: I96F32 =
-                I96F32::from_num(proportion).saturating_div(I96F32::from_num(u64::MAX));
-            let stake_proportion_from_parent: I96F32 =
-                I96F32::from_num(parent_stake).saturating_mul(normalized_proportion);
+            let stake_proportion_from_parent: u64 =
+                Self::get_childkey_stake_proportion(parent_stake, proportion);
 
             // Accumulate the total stake received from parents.
-            stake_from_parents =
-                stake_from_parents.saturating_add(stake_proportion_from_parent.to_num::<u64>());
+            stake_from_parents = stake_from_parents.saturating_add(stake_proportion_from_parent);
         }
 
         // Calculate the final stake for the hotkey by adjusting the initial stake with the stakes
@@ -86,6 +80,19 @@ impl<T: Config> Pallet<T> {
         finalized_stake
     }
 
+    /// Returns the part of a parent's stake lent to a child for a u64 normalized proportion.
+    ///
+    /// # Arguments
+    /// * `stake` - The total stake of the parent.
+    /// * `proportion` - The proportion of the child, where u64::MAX is the whole stake.
+    pub fn get_childkey_stake_proportion(stake: u64, proportion: u64) -> u64 {
+        let normalized_proportion: I96F32 =
+            I96F32::from_num(proportion).saturating_div(I96F32::from_num(u64::MAX));
+        I96F32::from_num(stake)
+            .saturating_mul(normalized_proportion)
+            .to_num::<u64>()
+    }
+
     /// Calculates reward consensus and returns the emissions for uids/hotkeys in a given `netuid`.
     /// (Dense version used only for testing purposes.)
     #[allow(clippy::indexing_slicing)]
@@ -1288,23 +1295,8 @@ impl<T: Config> Pallet<T> {
         // --- 2. Ensure the function caller is the subnet owner or root.
         Self::ensure_subnet_owner_or_root(origin, netuid)?;
 
-        // --- 3. Ensure liquid alpha is enabled
-        ensure!(
-            Self::get_liquid_alpha_enabled(netuid),
-            Error::<T>::LiquidAlphaDisabled
-        );
-
-        let max_u16: u32 = u16::MAX as u32; // 65535
-        let min_alpha_high: u16 = (max_u16.saturating_mul(4).saturating_div(5)) as u16; // 52428
-
-        // --- 4. Ensure alpha high is greater than the minimum
-        ensure!(alpha_high >= min_alpha_high, Error::<T>::AlphaHighTooLow);
-
-        // -- 5. Ensure alpha low is within range
-        ensure!(
-            alpha_low > 0 && alpha_low < min_alpha_high,
-            Error::<T>::AlphaLowOutOfRange
-        );
+        // --- 3. Ensure liquid alpha is enabled and the values are within range
+        Self::ensure_valid_alpha_values(netuid, alpha_low, alpha_high)?;
 
         AlphaValues::<T>::insert(netuid, (alpha_low, alpha_high));
 
@@ -1316,4 +1308,28 @@ impl<T: Config> Pallet<T> {
         );
         Ok(())
     }
+
+    /// Ensures liquid alpha is enabled on the subnet and the alpha values are within range.
+    ///
+    /// # Raises
+    ///
+    /// * `LiquidAlphaDisabled` - Liquid alpha is not enabled on the subnet.
+    /// * `AlphaHighTooLow` - The upper alpha is below `MIN_ALPHA_HIGH`.
+    /// * `AlphaLowOutOfRange` - The lower alpha is 0 or not below `MIN_ALPHA_HIGH`.
+    pub fn ensure_valid_alpha_values(
+        netuid: u16,
+        alpha_low: u16,
+        alpha_high: u16,
+    ) -> Result<(), DispatchError> {
+        ensure!(
+            Self::get_liquid_alpha_enabled(netuid),
+            Error::<T>::LiquidAlphaDisabled
+        );
+        ensure!(alpha_high >= MIN_ALPHA_HIGH, Error::<T>::AlphaHighTooLow);
+        ensure!(
+            alpha_low > 0 && alpha_low < MIN_ALPHA_HIGH,
+            Error::<T>::AlphaLowOutOfRange
+        );
+        Ok(())
+    }
 }
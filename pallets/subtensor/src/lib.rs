@@ -93,6 +93,43 @@ pub mod pallet {
     /// Minimum balance required to perform a coldkey swap
     pub const MIN_BALANCE_TO_PERFORM_COLDKEY_SWAP: u64 = 100_000_000; // 0.1 TAO in RAO
 
+    /// Maximum number of destinations recorded for a contested coldkey swap
+    pub const MAX_COLDKEY_SWAP_DESTINATIONS: usize = 5;
+
+    /// Maximum number of guardians who can recover a coldkey
+    pub const MAX_RECOVERY_GUARDIANS: usize = 10;
+
+    /// Maximum number of entries in the unbonding queue of a coldkey
+    pub const MAX_UNBONDING_CHUNKS: usize = 32;
+
+    /// Maximum number of children a subnet may allow a hotkey
+    pub const MAX_CHILDREN_LIMIT: u16 = 10;
+
+    /// Minimum number of blocks a subnet may set between setting the children of a hotkey
+    pub const MIN_SET_CHILDREN_COOLDOWN: u64 = 10;
+
+    /// Lowest upper liquid alpha of a subnet, 4/5 of `u16::MAX`
+    pub const MIN_ALPHA_HIGH: u16 = 52428;
+
+    /// Default range of rho a subnet owner may set
+    pub const DEFAULT_RHO_BOUNDS: (u16, u16) = (1, 100);
+
+    /// Lowest kappa a subnet owner may set by default, 1/2 of `u16::MAX` so that consensus
+    /// needs a majority of the stake
+    pub const DEFAULT_MIN_KAPPA: u16 = 32_767;
+
+    /// Lowest activity cutoff a subnet owner may set by default, one default tempo in blocks
+    pub const DEFAULT_MIN_ACTIVITY_CUTOFF: u16 = 360;
+
+    /// Number of blocks in a day, at 12 seconds per block
+    pub const BLOCKS_PER_DAY: u64 = 7200;
+
+    /// Number of days of drains kept in the history of a hotkey
+    pub const DRAIN_HISTORY_DAYS: u64 = 30;
+
+    /// Number of epochs of childkey emission kept in the history of a subnet
+    pub const CHILDKEY_EMISSION_HISTORY_EPOCHS: usize = 16;
+
     #[pallet::pallet]
     #[pallet::without_storage_info]
     #[pallet::storage_version(STORAGE_VERSION)]
@@ -182,6 +219,184 @@ pub mod pallet {
         /// The subnet's contact
         pub subnet_contact: Vec<u8>,
     }
+
+    /// Subnet hyperparameters which governance can change through `pallet_admin_utils`.
+    ///
+    /// Values are carried as `u64`; booleans are 0 or 1.
+    #[derive(Encode, Decode, TypeInfo, Clone, Copy, PartialEq, Eq, Debug)]
+    pub enum SubnetHyperparameter {
+        /// Blocks between axon and prometheus updates.
+        ServingRateLimit,
+        /// Lower bound of the POW difficulty.
+        MinDifficulty,
+        /// Upper bound of the POW difficulty.
+        MaxDifficulty,
+        /// Weights version key required to set weights.
+        WeightsVersionKey,
+        /// Blocks between weight updates of a validator.
+        WeightsSetRateLimit,
+        /// Blocks between registration cost adjustments.
+        AdjustmentInterval,
+        /// Smoothing of the registration cost adjustments.
+        AdjustmentAlpha,
+        /// Maximum weight a validator may set.
+        MaxWeightLimit,
+        /// Blocks a new neuron is protected from pruning.
+        ImmunityPeriod,
+        /// Minimum number of weights a validator must set.
+        MinAllowedWeights,
+        /// Maximum number of UIDs.
+        MaxAllowedUids,
+        /// Consensus majority ratio.
+        Kappa,
+        /// Steepness of the trust sigmoid.
+        Rho,
+        /// Blocks without weight updates before a validator is inactive.
+        ActivityCutoff,
+        /// Whether registrations are allowed.
+        NetworkRegistrationAllowed,
+        /// Whether POW registrations are allowed.
+        NetworkPowRegistrationAllowed,
+        /// Target registrations per adjustment interval.
+        TargetRegistrationsPerInterval,
+        /// Lower bound of the registration burn.
+        MinBurn,
+        /// Upper bound of the registration burn.
+        MaxBurn,
+        /// Current POW difficulty.
+        Difficulty,
+        /// Maximum number of validators.
+        MaxAllowedValidators,
+        /// Moving average of the bonds.
+        BondsMovingAverage,
+        /// Maximum registrations per block.
+        MaxRegistrationsPerBlock,
+        /// Blocks between epochs.
+        Tempo,
+        /// Intervals between committing and revealing weights.
+        CommitRevealWeightsInterval,
+        /// Whether weights are committed before they are revealed.
+        CommitRevealWeightsEnabled,
+        /// Whether liquid alpha is used for the bonds.
+        LiquidAlphaEnabled,
+        /// Maximum stake allowed on the subnet.
+        NetworkMaxStake,
+        /// Maximum number of children of a hotkey.
+        MaxChildren,
+        /// Minimum proportion of each child.
+        MinChildProportion,
+        /// Blocks between setting the children of a hotkey.
+        SetChildrenCooldown,
+        /// Lower bound of the childkey take.
+        MinChildkeyTake,
+        /// Upper bound of the childkey take.
+        MaxChildkeyTake,
+        /// Lower liquid alpha.
+        AlphaLow,
+        /// Upper liquid alpha.
+        AlphaHigh,
+    }
+
+    /// Data structure for hyperparameter templates
+    #[crate::freeze_struct("40f30613c809edff")]
+    #[derive(Encode, Decode, Default, TypeInfo, Clone, PartialEq, Eq, Debug)]
+    pub struct HyperparameterTemplate {
+        /// The name of the template, such as "small-experimental"
+        pub name: Vec<u8>,
+        /// The hyperparameters set on top of the defaults of a new subnet
+        pub params: Vec<(SubnetHyperparameter, u64)>,
+    }
+
+    /// Data structure for children proposed by a parent hotkey
+    #[crate::freeze_struct("7aff416c42e53a7a")]
+    #[derive(Encode, Decode, Default, TypeInfo, Clone, PartialEq, Eq, Debug)]
+    pub struct ChildkeyProposal<AccountId> {
+        /// The proposed children with their proportions
+        pub children: Vec<(u64, AccountId)>,
+        /// The children whose coldkeys have accepted
+        pub accepted: Vec<AccountId>,
+        /// The block at which the proposal is dropped if not accepted by all children
+        pub expires_at: u64,
+    }
+
+    /// Data structure for the emission passed from children to parents in one epoch
+    #[crate::freeze_struct("7900e69845ae9920")]
+    #[derive(Encode, Decode, Default, TypeInfo, Clone, PartialEq, Eq, Debug)]
+    pub struct ChildkeyEmissionRecord<AccountId> {
+        /// The block the epoch ran at
+        pub block: u64,
+        /// The emission passed from each child to each parent, as (child, parent, amount)
+        pub transfers: Vec<(AccountId, AccountId, u64)>,
+    }
+
+    /// Data structure for the emission drained from a hotkey on one day
+    #[crate::freeze_struct("4a12d22dd8af780a")]
+    #[derive(Encode, Decode, Default, TypeInfo, Clone, PartialEq, Eq, Debug)]
+    pub struct HotkeyDrainRecord {
+        /// The day of the drains, as the block number divided by the blocks per day
+        pub day: u64,
+        /// The total emission drained from the hotkey
+        pub drained: u64,
+        /// The take collected by the hotkey
+        pub take: u64,
+        /// The emission distributed to the nominators
+        pub distributed: u64,
+    }
+
+    /// Where the emission a nominator earns on a hotkey is paid
+    #[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug)]
+    pub enum RewardPayout<AccountId> {
+        /// Added to the stake of the nominator on the hotkey.
+        Compound,
+        /// Paid to the free balance of the nominator.
+        FreeBalance,
+        /// Paid to the free balance of this account.
+        Account(AccountId),
+    }
+
+    /// The phase a key swap continued over several blocks is at
+    #[derive(Encode, Decode, TypeInfo, Clone, Copy, PartialEq, Eq, Debug)]
+    pub enum KeySwapPhase {
+        /// Moving the registrations of a hotkey, from the subnet with this netuid on.
+        HotkeySubnets(u16),
+        /// Moving the stake rows of a hotkey.
+        HotkeyStake,
+        /// Moving the stake rows of a coldkey.
+        ColdkeyStake,
+    }
+
+    /// Data structure for a key swap continued over several blocks
+    #[crate::freeze_struct("c6a853cbef7f91b2")]
+    #[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug)]
+    pub struct KeySwapProgress<AccountId> {
+        /// The key taking the place of the swapped key
+        pub new_key: AccountId,
+        /// The phase the swap is at
+        pub phase: KeySwapPhase,
+        /// The number of subnets and stake rows moved so far
+        pub entries_moved: u32,
+    }
+
+    /// Data structure for the guardians who can recover a coldkey
+    #[crate::freeze_struct("ed7f50338d23c849")]
+    #[derive(Encode, Decode, Default, TypeInfo, Clone, PartialEq, Eq, Debug)]
+    pub struct RecoveryGuardians<AccountId> {
+        /// The guardians of the coldkey
+        pub guardians: Vec<AccountId>,
+        /// The number of guardians who must vouch for a recovery
+        pub threshold: u32,
+    }
+
+    /// Data structure for a coldkey recovery vouched for by guardians
+    #[crate::freeze_struct("61151ea08a7d82e3")]
+    #[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug)]
+    pub struct ColdkeyRecovery<AccountId, BlockNumber> {
+        /// The guardians who vouched for the recovery, with the coldkey each vouched to swap to
+        pub vouches: Vec<(AccountId, AccountId)>,
+        /// The coldkey the recovery swaps to and the block the swap is scheduled at, once enough
+        /// guardians vouched for the same coldkey
+        pub scheduled: Option<(AccountId, BlockNumber)>,
+    }
     /// ============================
     /// ==== Staking + Accounts ====
     /// ============================
@@ -272,6 +487,11 @@ pub mod pallet {
         0
     }
     #[pallet::type_value]
+    /// Default blocks the children have to accept a proposal, one week.
+    pub fn DefaultChildkeyProposalExpiry<T: Config>() -> u64 {
+        50400
+    }
+    #[pallet::type_value]
     /// Default accumulated emission for a hotkey
     pub fn DefaultAccumulatedEmission<T: Config>() -> u64 {
         0
@@ -572,6 +792,26 @@ pub mod pallet {
         T::InitialHotkeyEmissionTempo::get()
     }
     #[pallet::type_value]
+    /// Default value for senate adjustment interval.
+    pub fn DefaultSenateAdjustmentInterval<T: Config>() -> u64 {
+        T::InitialSenateAdjustmentInterval::get()
+    }
+    #[pallet::type_value]
+    /// Default value for delegate take notice period.
+    pub fn DefaultDelegateTakeNoticePeriod<T: Config>() -> u64 {
+        T::InitialDelegateTakeNoticePeriod::get()
+    }
+    #[pallet::type_value]
+    /// Default hyperparameter enactment delay.
+    pub fn DefaultHyperparameterEnactmentDelay<T: Config>() -> u64 {
+        0
+    }
+    #[pallet::type_value]
+    /// Default unbonding period.
+    pub fn DefaultUnbondingPeriod<T: Config>() -> u64 {
+        0
+    }
+    #[pallet::type_value]
     /// Default value for rate limiting
     pub fn DefaultTxRateLimit<T: Config>() -> u64 {
         T::InitialTxRateLimit::get()
@@ -626,6 +866,16 @@ pub mod pallet {
     pub fn DefaultNetworkMaxStake<T: Config>() -> u64 {
         T::InitialNetworkMaxStake::get()
     }
+    #[pallet::type_value]
+    /// Default value for the maximum number of children of a hotkey.
+    pub fn DefaultMaxChildren<T: Config>() -> u16 {
+        5
+    }
+    #[pallet::type_value]
+    /// Default value for the blocks between setting children, twice the default tempo.
+    pub fn DefaultSetChildrenCooldown<T: Config>() -> u64 {
+        u64::from(DefaultTempo::<T>::get()).saturating_mul(2)
+    }
 
     #[pallet::type_value]
     /// Default value for coldkey swap schedule duration
@@ -647,6 +897,20 @@ pub mod pallet {
     pub type DissolveNetworkScheduleDuration<T: Config> =
         StorageValue<_, BlockNumberFor<T>, ValueQuery, DefaultDissolveNetworkScheduleDuration<T>>;
 
+    #[pallet::type_value]
+    /// Default value for subnet owner transfer schedule duration
+    pub fn DefaultSubnetOwnerTransferScheduleDuration<T: Config>() -> BlockNumberFor<T> {
+        T::InitialSubnetOwnerTransferScheduleDuration::get()
+    }
+
+    #[pallet::storage]
+    pub type SubnetOwnerTransferScheduleDuration<T: Config> = StorageValue<
+        _,
+        BlockNumberFor<T>,
+        ValueQuery,
+        DefaultSubnetOwnerTransferScheduleDuration<T>,
+    >;
+
     #[pallet::storage]
     pub type SenateRequiredStakePercentage<T> =
         StorageValue<_, u64, ValueQuery, DefaultSenateRequiredStakePercentage<T>>;
@@ -656,10 +920,11 @@ pub mod pallet {
     /// ============================
     /// The Subtensor [`TotalIssuance`] represents the total issuance of tokens on the Bittensor network.
     ///
-    /// It is comprised of three parts:
+    /// It is comprised of four parts:
     /// - The total amount of issued tokens, tracked in the TotalIssuance of the Balances pallet
     /// - The total amount of tokens staked in the system, tracked in [`TotalStake`]
     /// - The total amount of tokens locked up for subnet reg, tracked in [`TotalSubnetLocked`] attained by iterating over subnet lock.
+    /// - The total amount of tokens removed from stake and waiting to unbond, tracked in [`TotalUnbonding`]
     ///
     /// Eventually, Bittensor should migrate to using Holds afterwhich time we will not require this
     /// separate accounting.
@@ -667,6 +932,15 @@ pub mod pallet {
     pub type TotalIssuance<T> = StorageValue<_, u64, ValueQuery, DefaultTotalIssuance<T>>;
     #[pallet::storage] // --- ITEM ( total_stake )
     pub type TotalStake<T> = StorageValue<_, u64, ValueQuery>;
+    #[pallet::storage] // --- ITEM ( total_unbonding )
+    pub type TotalUnbonding<T> = StorageValue<_, u64, ValueQuery>;
+    #[pallet::storage]
+    /// ITEM ( unbonding_period ) | Blocks before removed stake can be withdrawn, 0 returns it immediately.
+    pub type UnbondingPeriod<T> = StorageValue<_, u64, ValueQuery, DefaultUnbondingPeriod<T>>;
+    #[pallet::storage]
+    /// MAP ( cold ) --> Vec<(amount, unlock_block)> | Removed stake waiting for its unbonding period.
+    pub type UnbondingQueue<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, Vec<(u64, u64)>, ValueQuery>;
     #[pallet::storage] // --- ITEM ( default_delegate_take )
     pub type MaxDelegateTake<T> = StorageValue<_, u16, ValueQuery, DefaultDelegateTake<T>>;
     #[pallet::storage] // --- ITEM ( min_delegate_take )
@@ -734,6 +1008,17 @@ pub mod pallet {
         DefaultAccountTake<T>,
     >;
     #[pallet::storage]
+    /// DMAP ( hot, cold ) --> payout | Where the nominator emission of the coldkey on the hotkey is paid, compounded if absent.
+    pub type RewardPayouts<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Identity,
+        T::AccountId,
+        RewardPayout<T::AccountId>,
+        OptionQuery,
+    >;
+    #[pallet::storage]
     /// Map ( hot ) --> last_hotkey_emission_drain | Last block we drained this hotkey's emission.
     pub type LastHotkeyEmissionDrain<T: Config> = StorageMap<
         _,
@@ -744,10 +1029,33 @@ pub mod pallet {
         DefaultAccumulatedEmission<T>,
     >;
     #[pallet::storage]
+    /// Map ( hot ) --> drain_history | Daily totals of this hotkey's drains over the last DRAIN_HISTORY_DAYS days.
+    pub type HotkeyDrainHistory<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, Vec<HotkeyDrainRecord>, ValueQuery>;
+    #[pallet::storage]
     /// ITEM ( hotkey_emission_tempo )
     pub type HotkeyEmissionTempo<T> =
         StorageValue<_, u64, ValueQuery, DefaultHotkeyEmissionTempo<T>>;
     #[pallet::storage]
+    /// ITEM ( senate_adjustment_interval )
+    pub type SenateAdjustmentInterval<T> =
+        StorageValue<_, u64, ValueQuery, DefaultSenateAdjustmentInterval<T>>;
+    #[pallet::storage]
+    /// ITEM ( senate_stake_weighted_voting ) | Whether senate motions are tallied by the stake of the members.
+    pub type SenateStakeWeightedVoting<T> = StorageValue<_, bool, ValueQuery>;
+    #[pallet::storage]
+    /// ITEM ( delegate_take_notice_period ) | Blocks before a take increase applies, 0 applies it immediately.
+    pub type DelegateTakeNoticePeriod<T> =
+        StorageValue<_, u64, ValueQuery, DefaultDelegateTakeNoticePeriod<T>>;
+    #[pallet::storage]
+    /// ITEM ( hyperparameter_enactment_delay ) | Blocks before a hyperparameter change applies, 0 applies it immediately.
+    pub type HyperparameterEnactmentDelay<T> =
+        StorageValue<_, u64, ValueQuery, DefaultHyperparameterEnactmentDelay<T>>;
+    #[pallet::storage]
+    /// --- MAP ( hyperparameter ) --> (min, max) | Range a subnet owner may set, unbounded if absent.
+    pub type HyperparameterBounds<T> =
+        StorageMap<_, Twox64Concat, SubnetHyperparameter, (u64, u64), OptionQuery>;
+    #[pallet::storage]
     /// Map ( hot ) --> emission | Accumulated hotkey emission.
     pub type PendingdHotkeyEmission<T: Config> = StorageMap<
         _,
@@ -758,6 +1066,19 @@ pub mod pallet {
         DefaultAccumulatedEmission<T>,
     >;
     #[pallet::storage]
+    /// Map ( hot ) --> (take, effective_block) | Take increase waiting for its notice period.
+    pub type PendingDelegateTakes<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, (u16, u64), OptionQuery>;
+    #[pallet::storage]
+    /// Map ( effective_block ) --> Vec<hot> | Hotkeys whose announced take increase applies at the block.
+    pub type PendingDelegateTakesByBlock<T: Config> =
+        StorageMap<_, Identity, u64, Vec<T::AccountId>, ValueQuery>;
+    #[pallet::storage]
+    /// Map ( cold ) --> (amount, last_drain, expiry_block) | Stake unstaked from hotkeys with an
+    /// announced take increase, which can be staked again without waiting for a drain.
+    pub type TakeNoticeUnstakes<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, (u64, u64, u64), OptionQuery>;
+    #[pallet::storage]
     /// Map ( hot, cold ) --> block_number | Last add stake increase.
     pub type LastAddStakeIncrease<T: Config> = StorageDoubleMap<
         _,
@@ -793,6 +1114,40 @@ pub mod pallet {
         ValueQuery,
         DefaultAccountLinkage<T>,
     >;
+    #[pallet::storage]
+    /// DMAP ( parent, netuid ) --> proposal | Children awaiting the consent of their coldkeys.
+    pub type PendingChildKeys<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Identity,
+        u16,
+        ChildkeyProposal<T::AccountId>,
+        OptionQuery,
+    >;
+    #[pallet::storage]
+    /// MAP ( expires_at ) --> Vec<(parent, netuid)> | Children proposals which are dropped at the block.
+    pub type PendingChildKeysByExpiry<T: Config> =
+        StorageMap<_, Identity, u64, Vec<(T::AccountId, u16)>, ValueQuery>;
+    #[pallet::storage]
+    /// DMAP ( child, netuid ) --> Vec<parent> | Parents proposing the hotkey as their child.
+    pub type PendingParentKeys<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Identity,
+        u16,
+        Vec<T::AccountId>,
+        ValueQuery,
+    >;
+    #[pallet::storage]
+    /// MAP ( netuid ) --> Vec<ChildkeyEmissionRecord> | Emission passed to parents in the last CHILDKEY_EMISSION_HISTORY_EPOCHS epochs.
+    pub type ChildkeyEmissionHistory<T: Config> =
+        StorageMap<_, Identity, u16, Vec<ChildkeyEmissionRecord<T::AccountId>>, ValueQuery>;
+    #[pallet::storage]
+    /// ITEM ( childkey_proposal_expiry ) | Blocks the children have to accept a proposal.
+    pub type ChildkeyProposalExpiry<T> =
+        StorageValue<_, u64, ValueQuery, DefaultChildkeyProposalExpiry<T>>;
     #[pallet::storage] // --- DMAP ( cold ) --> Vec<hot> | Maps coldkey to hotkeys that stake to it
     pub type StakingHotkeys<T: Config> =
         StorageMap<_, Blake2_128Concat, T::AccountId, Vec<T::AccountId>, ValueQuery>;
@@ -804,6 +1159,40 @@ pub mod pallet {
     pub type ColdkeySwapScheduled<T: Config> =
         StorageMap<_, Blake2_128Concat, T::AccountId, (), ValueQuery>;
 
+    #[pallet::storage] // --- MAP ( cold ) --> (new_cold, execution_block) | Destination and execution block of a scheduled coldkey swap.
+    pub type ScheduledColdkeySwaps<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        (T::AccountId, BlockNumberFor<T>),
+        OptionQuery,
+    >;
+
+    #[pallet::storage] // --- MAP ( cold ) --> Vec<new_cold> | Requested destinations of a contested coldkey swap awaiting arbitration.
+    pub type ColdkeySwapDisputes<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, Vec<T::AccountId>, OptionQuery>;
+
+    #[pallet::storage] // --- MAP ( old_key ) --> KeySwapProgress | Key swaps left for on_idle to continue.
+    pub type PendingKeySwaps<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, KeySwapProgress<T::AccountId>, OptionQuery>;
+
+    #[pallet::storage] // --- MAP ( key ) --> old_key | Keys locked until the swap of the old key completes.
+    pub type KeySwapLocks<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, T::AccountId, OptionQuery>;
+
+    #[pallet::storage] // --- MAP ( cold ) --> RecoveryGuardians | Guardians who can jointly recover the coldkey.
+    pub type ColdkeyRecoveryGuardians<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, RecoveryGuardians<T::AccountId>, OptionQuery>;
+
+    #[pallet::storage] // --- MAP ( cold ) --> ColdkeyRecovery | Recovery of the coldkey vouched for by its guardians.
+    pub type ColdkeyRecoveries<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        ColdkeyRecovery<T::AccountId, BlockNumberFor<T>>,
+        OptionQuery,
+    >;
+
     /// ============================
     /// ==== Global Parameters =====
     /// ============================
@@ -919,6 +1308,33 @@ pub mod pallet {
     pub type SubnetLocked<T: Config> =
         StorageMap<_, Identity, u16, u64, ValueQuery, DefaultSubnetLocked<T>>;
     #[pallet::storage]
+    /// --- MAP ( netuid ) --> (new_owner, executable_block) | Pending subnet ownership transfer.
+    pub type PendingSubnetOwnerTransfer<T: Config> =
+        StorageMap<_, Identity, u16, (T::AccountId, BlockNumberFor<T>), OptionQuery>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> (account, execution_block) | Scheduled network dissolution.
+    pub type ScheduledNetworkDissolutions<T: Config> =
+        StorageMap<_, Identity, u16, (T::AccountId, BlockNumberFor<T>), OptionQuery>;
+    #[pallet::storage]
+    /// --- DMAP ( netuid, hyperparameter ) --> (value, effective_block) | Pending hyperparameter change.
+    pub type PendingHyperparameterChanges<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        u16,
+        Twox64Concat,
+        SubnetHyperparameter,
+        (u64, u64),
+        OptionQuery,
+    >;
+    #[pallet::storage]
+    /// --- MAP ( effective_block ) --> Vec<(netuid, hyperparameter)> | Pending hyperparameter changes which apply at the block.
+    pub type PendingHyperparameterChangesByBlock<T: Config> =
+        StorageMap<_, Identity, u64, Vec<(u16, SubnetHyperparameter)>, ValueQuery>;
+    #[pallet::storage]
+    /// --- MAP ( template_id ) --> hyperparameter template | Templates a new subnet can be registered with.
+    pub type HyperparameterTemplates<T: Config> =
+        StorageMap<_, Identity, u16, HyperparameterTemplate, OptionQuery>;
+    #[pallet::storage]
     /// --- MAP ( netuid ) --> serving_rate_limit
     pub type ServingRateLimit<T> =
         StorageMap<_, Identity, u16, u64, ValueQuery, DefaultServingRateLimit<T>>;
@@ -1061,6 +1477,22 @@ pub mod pallet {
     #[pallet::storage]
     pub type NetworkMaxStake<T> =
         StorageMap<_, Identity, u16, u64, ValueQuery, DefaultNetworkMaxStake<T>>;
+    /// MAP ( netuid ) --> maximum number of children of a hotkey on a subnet.
+    #[pallet::storage]
+    pub type MaxChildren<T> = StorageMap<_, Identity, u16, u16, ValueQuery, DefaultMaxChildren<T>>;
+    /// MAP ( netuid ) --> minimum proportion of each child on a subnet.
+    #[pallet::storage]
+    pub type MinChildProportion<T> = StorageMap<_, Identity, u16, u64, ValueQuery>;
+    /// MAP ( netuid ) --> blocks between setting the children of a hotkey on a subnet.
+    #[pallet::storage]
+    pub type SetChildrenCooldown<T> =
+        StorageMap<_, Identity, u16, u64, ValueQuery, DefaultSetChildrenCooldown<T>>;
+    /// MAP ( netuid ) --> lower bound of the childkey take on a subnet, the global bound if unset.
+    #[pallet::storage]
+    pub type SubnetMinChildkeyTake<T> = StorageMap<_, Identity, u16, u16, OptionQuery>;
+    /// MAP ( netuid ) --> upper bound of the childkey take on a subnet, the global bound if unset.
+    #[pallet::storage]
+    pub type SubnetMaxChildkeyTake<T> = StorageMap<_, Identity, u16, u16, OptionQuery>;
 
     /// =======================================
     /// ==== Subnetwork Consensus Storage  ====
@@ -1310,12 +1742,16 @@ pub enum CallType {
 #[derive(Debug, PartialEq)]
 pub enum CustomTransactionError {
     ColdkeyInSwapSchedule,
+    ColdkeyInArbitration,
+    KeySwapInProgress,
 }
 
 impl From<CustomTransactionError> for u8 {
     fn from(variant: CustomTransactionError) -> u8 {
         match variant {
             CustomTransactionError::ColdkeyInSwapSchedule => 0,
+            CustomTransactionError::ColdkeyInArbitration => 6,
+            CustomTransactionError::KeySwapInProgress => 7,
         }
     }
 }
@@ -1441,14 +1877,24 @@ where
                     Err(InvalidTransaction::Custom(4).into())
                 }
             }
-            Some(Call::add_stake { .. }) => Ok(ValidTransaction {
-                priority: Self::get_priority_vanilla(),
-                ..Default::default()
-            }),
-            Some(Call::remove_stake { .. }) => Ok(ValidTransaction {
-                priority: Self::get_priority_vanilla(),
-                ..Default::default()
-            }),
+            Some(Call::add_stake { .. } | Call::remove_stake { .. } | Call::move_stake { .. }) => {
+                if ColdkeySwapDisputes::<T>::contains_key(who) {
+                    return InvalidTransaction::Custom(
+                        CustomTransactionError::ColdkeyInArbitration.into(),
+                    )
+                    .into();
+                }
+                if Pallet::<T>::is_key_swapping(who) {
+                    return InvalidTransaction::Custom(
+                        CustomTransactionError::KeySwapInProgress.into(),
+                    )
+                    .into();
+                }
+                Ok(ValidTransaction {
+                    priority: Self::get_priority_vanilla(),
+                    ..Default::default()
+                })
+            }
             Some(Call::register { netuid, .. } | Call::burned_register { netuid, .. }) => {
                 let registrations_this_interval =
                     Pallet::<T>::get_registrations_this_interval(*netuid);
@@ -1468,6 +1914,48 @@ where
                 priority: Self::get_priority_vanilla(),
                 ..Default::default()
             }),
+            Some(Call::transfer_stake { .. } | Call::set_reward_payout { .. }) => {
+                if ColdkeySwapDisputes::<T>::contains_key(who) {
+                    return InvalidTransaction::Custom(
+                        CustomTransactionError::ColdkeyInArbitration.into(),
+                    )
+                    .into();
+                }
+                if ColdkeySwapScheduled::<T>::contains_key(who) {
+                    return InvalidTransaction::Custom(
+                        CustomTransactionError::ColdkeyInSwapSchedule.into(),
+                    )
+                    .into();
+                }
+                if Pallet::<T>::is_key_swapping(who) {
+                    return InvalidTransaction::Custom(
+                        CustomTransactionError::KeySwapInProgress.into(),
+                    )
+                    .into();
+                }
+                Ok(ValidTransaction {
+                    priority: Self::get_priority_vanilla(),
+                    ..Default::default()
+                })
+            }
+            Some(Call::set_recovery_guardians { .. } | Call::veto_coldkey_recovery { .. }) => {
+                if ColdkeySwapDisputes::<T>::contains_key(who) {
+                    return InvalidTransaction::Custom(
+                        CustomTransactionError::ColdkeyInArbitration.into(),
+                    )
+                    .into();
+                }
+                if ColdkeySwapScheduled::<T>::contains_key(who) {
+                    return InvalidTransaction::Custom(
+                        CustomTransactionError::ColdkeyInSwapSchedule.into(),
+                    )
+                    .into();
+                }
+                Ok(ValidTransaction {
+                    priority: Self::get_priority_vanilla(),
+                    ..Default::default()
+                })
+            }
             Some(Call::dissolve_network { .. }) => {
                 if ColdkeySwapScheduled::<T>::contains_key(who) {
                     InvalidTransaction::Custom(CustomTransactionError::ColdkeyInSwapSchedule.into())
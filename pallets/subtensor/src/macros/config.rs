@@ -36,8 +36,8 @@ mod config {
         /// Interface to allow other pallets to control who can register identities
         type TriumvirateInterface: crate::CollectiveInterface<Self::AccountId, Self::Hash, u32>;
 
-        /// The scheduler type used for scheduling delayed calls.
-        type Scheduler: ScheduleAnon<
+        /// The scheduler type used for scheduling delayed, named calls.
+        type Scheduler: ScheduleNamed<
             BlockNumberFor<Self>,
             LocalCallOf<Self>,
             PalletsOriginOf<Self>,
@@ -210,5 +210,14 @@ mod config {
         /// Dissolve network schedule duration
         #[pallet::constant]
         type InitialDissolveNetworkScheduleDuration: Get<BlockNumberFor<Self>>;
+        /// Subnet owner transfer schedule duration.
+        #[pallet::constant]
+        type InitialSubnetOwnerTransferScheduleDuration: Get<BlockNumberFor<Self>>;
+        /// Initial number of blocks between automatic senate rebalances, 0 disables them.
+        #[pallet::constant]
+        type InitialSenateAdjustmentInterval: Get<u64>;
+        /// Initial number of blocks between a delegate take increase and its enactment.
+        #[pallet::constant]
+        type InitialDelegateTakeNoticePeriod: Get<u64>;
     }
 }
@@ -4,7 +4,7 @@ use frame_support::pallet_macros::pallet_section;
 /// This can later be imported into the pallet using [`import_section`].
 #[pallet_section]
 mod dispatches {
-    use frame_support::traits::schedule::v3::Anon as ScheduleAnon;
+    use frame_support::traits::schedule::v3::Named as ScheduleNamed;
     use frame_support::traits::schedule::DispatchTime;
     use frame_system::pallet_prelude::BlockNumberFor;
     use sp_runtime::traits::Saturating;
@@ -422,11 +422,17 @@ mod dispatches {
         /// * 'NotEnoughStakeToWithdraw':
         /// 	- Thrown if there is not enough stake on the hotkey to withdwraw this amount.
         ///
+        /// * 'UnbondingQueueFull':
+        /// 	- Thrown if the stake has to unbond and the unbonding queue of the coldkey is full.
+        ///
+        /// With an unbonding period set, the removed stake is queued and released with
+        /// `withdraw_unbonded` once the period has passed.
+        ///
         #[pallet::call_index(3)]
         #[pallet::weight((Weight::from_parts(111_000_000, 0)
 		.saturating_add(Weight::from_parts(0, 43991))
-		.saturating_add(T::DbWeight::get().reads(10))
-		.saturating_add(T::DbWeight::get().writes(7)), DispatchClass::Normal, Pays::No))]
+		.saturating_add(T::DbWeight::get().reads(12))
+		.saturating_add(T::DbWeight::get().writes(9)), DispatchClass::Normal, Pays::No))]
         pub fn remove_stake(
             origin: OriginFor<T>,
             hotkey: T::AccountId,
@@ -644,6 +650,9 @@ mod dispatches {
         }
 
         /// The extrinsic for user to change its hotkey
+        ///
+        /// The subnets and stake rows the call cannot move within `get_key_swap_call_budget` are
+        /// moved in `on_idle`, both hotkeys stay locked until then.
         #[pallet::call_index(70)]
         #[pallet::weight((Weight::from_parts(1_940_000_000, 0)
         .saturating_add(T::DbWeight::get().reads(272))
@@ -656,6 +665,24 @@ mod dispatches {
             Self::do_swap_hotkey(origin, &hotkey, &new_hotkey)
         }
 
+        /// The extrinsic for user to change its hotkey on a single subnet.
+        ///
+        /// The hotkey keeps its registrations on other subnets. Its stake, delegate take and
+        /// senate seat stay with it unless `move_stake` is set.
+        #[pallet::call_index(90)]
+        #[pallet::weight((Weight::from_parts(1_940_000_000, 0)
+        .saturating_add(T::DbWeight::get().reads(272))
+        .saturating_add(T::DbWeight::get().writes(527)), DispatchClass::Operational, Pays::No))]
+        pub fn swap_hotkey_on_subnet(
+            origin: OriginFor<T>,
+            hotkey: T::AccountId,
+            new_hotkey: T::AccountId,
+            netuid: u16,
+            move_stake: bool,
+        ) -> DispatchResultWithPostInfo {
+            Self::do_swap_hotkey_on_subnet(origin, &hotkey, &new_hotkey, netuid, move_stake)
+        }
+
         /// The extrinsic for user to change the coldkey associated with their account.
         ///
         /// # Arguments
@@ -670,12 +697,15 @@ mod dispatches {
         ///
         /// # Weight
         ///
-        /// Weight is calculated based on the number of database reads and writes.
+        /// Weight is calculated based on the number of database reads and writes. The stake rows
+        /// the call cannot move within `get_key_swap_call_budget` are moved in `on_idle`, both
+        /// coldkeys stay locked until then.
         #[pallet::call_index(71)]
         #[pallet::weight((Weight::from_parts(127_713_000, 0)
         .saturating_add(Weight::from_parts(0, 11645))
         .saturating_add(T::DbWeight::get().reads(18))
-        .saturating_add(T::DbWeight::get().writes(12)), DispatchClass::Operational, Pays::No))]
+        .saturating_add(T::DbWeight::get().writes(12))
+        .saturating_add(Pallet::<T>::get_key_swap_call_budget()), DispatchClass::Operational, Pays::No))]
         pub fn swap_coldkey(
             origin: OriginFor<T>,
             old_coldkey: T::AccountId,
@@ -901,8 +931,8 @@ mod dispatches {
         #[pallet::weight((Weight::from_parts(157_000_000, 0)
 		.saturating_add(T::DbWeight::get().reads(16))
 		.saturating_add(T::DbWeight::get().writes(30)), DispatchClass::Operational, Pays::No))]
-        pub fn register_network(origin: OriginFor<T>) -> DispatchResult {
-            Self::user_add_network(origin, None)
+        pub fn register_network(origin: OriginFor<T>, template_id: Option<u16>) -> DispatchResult {
+            Self::user_add_network(origin, None, template_id)
         }
 
         /// Facility extrinsic for user to get taken from faucet
@@ -999,6 +1029,96 @@ mod dispatches {
             Ok(().into())
         }
 
+        /// ---- Accepts a hotkey as the child of a parent hotkey.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        ///     - The signature of the coldkey owning the child.
+        ///
+        /// * 'parent' (T::AccountId):
+        ///     - The hotkey which proposed the child.
+        ///
+        /// * 'child' (T::AccountId):
+        ///     - The accepting child hotkey.
+        ///
+        /// * 'netuid' (u16):
+        ///     - The network of the proposal.
+        ///
+        /// # Event:
+        /// * ChildAccepted;
+        ///     - On successfully accepting the proposal.
+        ///
+        /// * SetChildren;
+        ///     - Once every proposed child has accepted.
+        ///
+        /// # Raises:
+        /// * 'NonAssociatedColdKey':
+        ///     - The caller does not own the child.
+        ///
+        /// * 'ChildkeyProposalNotFound':
+        ///     - The parent has no children proposed on this network.
+        ///
+        /// * 'ChildNotProposed':
+        ///     - The child is not among the proposed children.
+        ///
+        /// * 'ChildAlreadyAccepted':
+        ///     - The child has already accepted the proposal.
+        ///
+        #[pallet::call_index(88)]
+        #[pallet::weight((Weight::from_parts(65_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(36))
+		.saturating_add(T::DbWeight::get().writes(33)), DispatchClass::Operational, Pays::Yes))]
+        pub fn accept_child(
+            origin: OriginFor<T>,
+            parent: T::AccountId,
+            child: T::AccountId,
+            netuid: u16,
+        ) -> DispatchResult {
+            Self::do_accept_child(origin, parent, child, netuid)
+        }
+
+        /// ---- Rejects a hotkey as the child of a parent hotkey, dropping the proposal.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        ///     - The signature of the coldkey owning the child.
+        ///
+        /// * 'parent' (T::AccountId):
+        ///     - The hotkey which proposed the child.
+        ///
+        /// * 'child' (T::AccountId):
+        ///     - The rejecting child hotkey.
+        ///
+        /// * 'netuid' (u16):
+        ///     - The network of the proposal.
+        ///
+        /// # Event:
+        /// * ChildRejected;
+        ///     - On successfully rejecting the proposal.
+        ///
+        /// # Raises:
+        /// * 'NonAssociatedColdKey':
+        ///     - The caller does not own the child.
+        ///
+        /// * 'ChildkeyProposalNotFound':
+        ///     - The parent has no children proposed on this network.
+        ///
+        /// * 'ChildNotProposed':
+        ///     - The child is not among the proposed children.
+        ///
+        #[pallet::call_index(89)]
+        #[pallet::weight((Weight::from_parts(50_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(15))
+		.saturating_add(T::DbWeight::get().writes(12)), DispatchClass::Operational, Pays::Yes))]
+        pub fn reject_child(
+            origin: OriginFor<T>,
+            parent: T::AccountId,
+            child: T::AccountId,
+            netuid: u16,
+        ) -> DispatchResult {
+            Self::do_reject_child(origin, parent, child, netuid)
+        }
+
         /// Schedules a coldkey swap operation to be executed at a future block.
         ///
         /// This function allows a user to schedule the swapping of their coldkey to a new one
@@ -1024,6 +1144,8 @@ mod dispatches {
         /// # Notes
         ///
         /// - The actual swap is not performed by this function. It merely schedules the swap operation.
+        /// - The swap cost is burned when the scheduled swap runs. A request which contests an
+        ///   already scheduled swap also burns the swap cost immediately.
         /// - The weight of this call is set to a fixed value and may need adjustment based on benchmarking.
         ///
         /// # TODO
@@ -1041,10 +1163,16 @@ mod dispatches {
         ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
             ensure!(
-                !ColdkeySwapScheduled::<T>::contains_key(&who),
-                Error::<T>::SwapAlreadyScheduled
+                !ColdkeySwapDisputes::<T>::contains_key(&new_coldkey),
+                Error::<T>::NewColdkeyIsInArbitration
             );
 
+            // A second request to a different destination contests the scheduled swap.
+            if ColdkeySwapScheduled::<T>::contains_key(&who) {
+                Self::do_contest_swap_coldkey(&who, &new_coldkey)?;
+                return Ok(().into());
+            }
+
             let current_block: BlockNumberFor<T> = <frame_system::Pallet<T>>::block_number();
             let duration: BlockNumberFor<T> = ColdkeySwapScheduleDuration::<T>::get();
             let when: BlockNumberFor<T> = current_block.saturating_add(duration);
@@ -1057,7 +1185,8 @@ mod dispatches {
             let bound_call = T::Preimages::bound(LocalCallOf::<T>::from(call.clone()))
                 .map_err(|_| Error::<T>::FailedToSchedule)?;
 
-            T::Scheduler::schedule(
+            T::Scheduler::schedule_named(
+                Self::coldkey_swap_task_name(&who),
                 DispatchTime::At(when),
                 None,
                 63,
@@ -1067,6 +1196,7 @@ mod dispatches {
             .map_err(|_| Error::<T>::FailedToSchedule)?;
 
             ColdkeySwapScheduled::<T>::insert(&who, ());
+            ScheduledColdkeySwaps::<T>::insert(&who, (new_coldkey.clone(), when));
             // Emit the SwapScheduled event
             Self::deposit_event(Event::ColdkeySwapScheduled {
                 old_coldkey: who.clone(),
@@ -1081,7 +1211,7 @@ mod dispatches {
         ///
         /// # Arguments
         ///
-        /// * `origin` - The origin of the call, must be signed by the sender.
+        /// * `origin` - The origin of the call, must be root or signed by the subnet owner.
         /// * `netuid` - The u16 network identifier to be dissolved.
         ///
         /// # Returns
@@ -1094,27 +1224,42 @@ mod dispatches {
 
         #[pallet::call_index(74)]
         #[pallet::weight((Weight::from_parts(119_000_000, 0)
-		.saturating_add(T::DbWeight::get().reads(6))
+		.saturating_add(T::DbWeight::get().reads(8))
 		.saturating_add(T::DbWeight::get().writes(31)), DispatchClass::Operational, Pays::Yes))]
         pub fn schedule_dissolve_network(
             origin: OriginFor<T>,
             netuid: u16,
         ) -> DispatchResultWithPostInfo {
-            let who = ensure_signed(origin)?;
+            let signer = frame_system::ensure_signed_or_root(origin)?;
+            ensure!(
+                Self::if_subnet_exist(netuid),
+                Error::<T>::SubNetworkDoesNotExist
+            );
+            // Root schedules the dissolution on behalf of the subnet owner.
+            let owner: T::AccountId = SubnetOwner::<T>::get(netuid);
+            let who: T::AccountId = match signer {
+                Some(who) => {
+                    ensure!(who == owner, Error::<T>::NotSubnetOwner);
+                    who
+                }
+                None => owner,
+            };
+            ensure!(
+                !Self::is_network_dissolution_scheduled(netuid),
+                Error::<T>::DissolveNetworkAlreadyScheduled
+            );
 
             let current_block: BlockNumberFor<T> = <frame_system::Pallet<T>>::block_number();
             let duration: BlockNumberFor<T> = DissolveNetworkScheduleDuration::<T>::get();
             let when: BlockNumberFor<T> = current_block.saturating_add(duration);
 
-            let call = Call::<T>::dissolve_network {
-                coldkey: who.clone(),
-                netuid,
-            };
+            let call = Call::<T>::dissolve_scheduled_network { netuid };
 
             let bound_call = T::Preimages::bound(LocalCallOf::<T>::from(call.clone()))
                 .map_err(|_| Error::<T>::FailedToSchedule)?;
 
-            T::Scheduler::schedule(
+            T::Scheduler::schedule_named(
+                Self::dissolve_network_task_name(netuid),
                 DispatchTime::At(when),
                 None,
                 63,
@@ -1123,6 +1268,8 @@ mod dispatches {
             )
             .map_err(|_| Error::<T>::FailedToSchedule)?;
 
+            ScheduledNetworkDissolutions::<T>::insert(netuid, (who.clone(), when));
+
             // Emit the SwapScheduled event
             Self::deposit_event(Event::DissolveNetworkScheduled {
                 account: who.clone(),
@@ -1207,8 +1354,397 @@ mod dispatches {
         pub fn register_network_with_identity(
             origin: OriginFor<T>,
             identity: Option<SubnetIdentityOf>,
+            template_id: Option<u16>,
+        ) -> DispatchResult {
+            Self::user_add_network(origin, identity, template_id)
+        }
+
+        /// ---- The current owner of a subnet proposes a new owner coldkey.
+        /// The new owner can accept the transfer once `SubnetOwnerTransferScheduleDuration`
+        /// blocks have passed.
+        /// # Args:
+        /// * `origin` - (<T as frame_system::Config>::Origin):
+        ///     - The signature of the current subnet owner coldkey.
+        ///
+        /// * `netuid` (u16):
+        ///     - The unique network identifier of the subnet.
+        ///
+        /// * `new_owner` (T::AccountId):
+        ///     - The coldkey proposed as the new owner of the subnet.
+        #[pallet::call_index(80)]
+        #[pallet::weight((Weight::from_parts(45_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(4))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Operational, Pays::Yes))]
+        pub fn propose_subnet_owner_transfer(
+            origin: OriginFor<T>,
+            netuid: u16,
+            new_owner: T::AccountId,
+        ) -> DispatchResult {
+            Self::do_propose_subnet_owner_transfer(origin, netuid, new_owner)
+        }
+
+        /// ---- The proposed owner accepts a pending subnet ownership transfer.
+        /// # Args:
+        /// * `origin` - (<T as frame_system::Config>::Origin):
+        ///     - The signature of the proposed new owner coldkey.
+        ///
+        /// * `netuid` (u16):
+        ///     - The unique network identifier of the subnet.
+        #[pallet::call_index(81)]
+        #[pallet::weight((Weight::from_parts(45_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(2))
+		.saturating_add(T::DbWeight::get().writes(2)), DispatchClass::Operational, Pays::Yes))]
+        pub fn accept_subnet_owner_transfer(origin: OriginFor<T>, netuid: u16) -> DispatchResult {
+            Self::do_accept_subnet_owner_transfer(origin, netuid)
+        }
+
+        /// ---- Cancels a pending subnet ownership transfer.
+        /// # Args:
+        /// * `origin` - (<T as frame_system::Config>::Origin):
+        ///     - The current owner, the proposed new owner, or root.
+        ///
+        /// * `netuid` (u16):
+        ///     - The unique network identifier of the subnet.
+        #[pallet::call_index(82)]
+        #[pallet::weight((Weight::from_parts(45_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(2))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Operational, Pays::Yes))]
+        pub fn cancel_subnet_owner_transfer(origin: OriginFor<T>, netuid: u16) -> DispatchResult {
+            Self::do_cancel_subnet_owner_transfer(origin, netuid)
+        }
+
+        /// Cancels a coldkey swap scheduled with `schedule_swap_coldkey`.
+        ///
+        /// # Arguments
+        ///
+        /// * `origin` - Root, or the signature of the coldkey which scheduled the swap.
+        /// * `coldkey` - The coldkey whose scheduled swap is cancelled.
+        ///
+        /// # Errors
+        ///
+        /// * `BadOrigin` - The signer is not the coldkey which scheduled the swap.
+        /// * `SwapNotScheduled` - No named swap is scheduled for the coldkey.
+        /// * `FailedToCancelSchedule` - The scheduler could not cancel the task.
+        #[pallet::call_index(83)]
+        #[pallet::weight((Weight::from_parts(45_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(3))
+		.saturating_add(T::DbWeight::get().writes(4)), DispatchClass::Operational, Pays::Yes))]
+        pub fn cancel_scheduled_swap_coldkey(
+            origin: OriginFor<T>,
+            coldkey: T::AccountId,
+        ) -> DispatchResult {
+            Self::do_cancel_scheduled_swap_coldkey(origin, coldkey)
+        }
+
+        /// Cancels a network dissolution scheduled with `schedule_dissolve_network`.
+        ///
+        /// # Arguments
+        ///
+        /// * `origin` - Root, the account which scheduled the dissolution, or the subnet owner.
+        /// * `netuid` - The network whose scheduled dissolution is cancelled.
+        ///
+        /// # Errors
+        ///
+        /// * `BadOrigin` - The signer is neither the scheduler of the dissolution nor the subnet owner.
+        /// * `DissolveNetworkNotScheduled` - No named dissolution is scheduled for the network.
+        /// * `FailedToCancelSchedule` - The scheduler could not cancel the task.
+        #[pallet::call_index(84)]
+        #[pallet::weight((Weight::from_parts(45_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(3))
+		.saturating_add(T::DbWeight::get().writes(3)), DispatchClass::Operational, Pays::Yes))]
+        pub fn cancel_scheduled_dissolve_network(
+            origin: OriginFor<T>,
+            netuid: u16,
+        ) -> DispatchResult {
+            Self::do_cancel_scheduled_dissolve_network(origin, netuid)
+        }
+
+        /// Resolves a contested coldkey swap. Callable by root or a triumvirate majority.
+        ///
+        /// # Arguments
+        ///
+        /// * `origin` - Root or the council origin.
+        /// * `coldkey` - The coldkey whose swap is contested.
+        /// * `new_coldkey` - The destination to swap to, or `None` to reject every request and
+        ///   release the coldkey.
+        ///
+        /// # Errors
+        ///
+        /// * `BadOrigin` - The origin is neither root nor the council.
+        /// * `ColdkeyNotInArbitration` - The coldkey has no contested swap.
+        /// * `NotContestedColdkeySwapDestination` - The destination was not requested in the swap.
+        /// * Any error returned by the coldkey swap itself.
+        #[pallet::call_index(85)]
+        #[pallet::weight((Weight::from_parts(127_713_000, 0)
+        .saturating_add(Weight::from_parts(0, 11645))
+        .saturating_add(T::DbWeight::get().reads(19))
+        .saturating_add(T::DbWeight::get().writes(14)), DispatchClass::Operational, Pays::No))]
+        pub fn resolve_coldkey_swap_dispute(
+            origin: OriginFor<T>,
+            coldkey: T::AccountId,
+            new_coldkey: Option<T::AccountId>,
+        ) -> DispatchResultWithPostInfo {
+            Self::do_resolve_coldkey_swap_dispute(origin, coldkey, new_coldkey)
+        }
+
+        /// Sets the guardians who can jointly recover the calling coldkey.
+        ///
+        /// # Arguments
+        ///
+        /// * `origin` - The signature of the coldkey.
+        /// * `guardians` - The guardians of the coldkey, empty to remove them.
+        /// * `threshold` - The number of guardians who must vouch for a recovery.
+        ///
+        /// # Errors
+        ///
+        /// * `ColdkeyRecoveryInProgress` - A recovery of the coldkey is in progress.
+        /// * `InvalidRecoveryGuardians` - The guardians or threshold are not valid.
+        #[pallet::call_index(91)]
+        #[pallet::weight((Weight::from_parts(25_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(1))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::Yes))]
+        pub fn set_recovery_guardians(
+            origin: OriginFor<T>,
+            guardians: Vec<T::AccountId>,
+            threshold: u32,
+        ) -> DispatchResult {
+            Self::do_set_recovery_guardians(origin, guardians, threshold)
+        }
+
+        /// Vouches for the recovery of a coldkey as one of its guardians, moving an earlier vouch
+        /// of the guardian for another coldkey. Once the threshold of the guardians has vouched
+        /// for `new_coldkey`, the swap to it is scheduled after `ColdkeySwapScheduleDuration`
+        /// blocks.
+        ///
+        /// # Arguments
+        ///
+        /// * `origin` - The signature of the guardian.
+        /// * `coldkey` - The coldkey to recover.
+        /// * `new_coldkey` - The coldkey the recovery swaps to.
+        ///
+        /// # Errors
+        ///
+        /// * `NotRecoveryGuardian` - The signer is not a guardian of the coldkey.
+        /// * `ColdkeyRecoveryInProgress` - The recovery is already scheduled.
+        /// * `ColdkeyRecoveryAlreadyVouched` - The guardian has already vouched for `new_coldkey`.
+        /// * `FailedToSchedule` - The scheduler could not schedule the swap.
+        #[pallet::call_index(92)]
+        #[pallet::weight((Weight::from_parts(45_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(7))
+		.saturating_add(T::DbWeight::get().writes(4)), DispatchClass::Normal, Pays::Yes))]
+        pub fn vouch_coldkey_recovery(
+            origin: OriginFor<T>,
+            coldkey: T::AccountId,
+            new_coldkey: T::AccountId,
+        ) -> DispatchResult {
+            Self::do_vouch_coldkey_recovery(origin, coldkey, new_coldkey)
+        }
+
+        /// Vetoes the recovery of the calling coldkey, cancelling the swap if it is scheduled.
+        ///
+        /// # Errors
+        ///
+        /// * `ColdkeyRecoveryNotFound` - No recovery of the coldkey is in progress.
+        /// * `FailedToCancelSchedule` - The scheduler could not cancel the task.
+        #[pallet::call_index(93)]
+        #[pallet::weight((Weight::from_parts(45_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(3))
+		.saturating_add(T::DbWeight::get().writes(4)), DispatchClass::Operational, Pays::Yes))]
+        pub fn veto_coldkey_recovery(origin: OriginFor<T>) -> DispatchResult {
+            Self::do_veto_coldkey_recovery(origin)
+        }
+
+        /// Swaps a coldkey recovered by its guardians. Scheduled by `vouch_coldkey_recovery`.
+        ///
+        /// # Errors
+        ///
+        /// * `BadOrigin` - The origin is not root.
+        /// * `ColdkeyRecoveryNotFound` - The recovery is not due for the new coldkey.
+        /// * Any error returned by the coldkey swap itself.
+        #[pallet::call_index(94)]
+        #[pallet::weight((Weight::from_parts(127_713_000, 0)
+        .saturating_add(Weight::from_parts(0, 11645))
+        .saturating_add(T::DbWeight::get().reads(19))
+        .saturating_add(T::DbWeight::get().writes(12))
+        .saturating_add(Pallet::<T>::get_key_swap_call_budget()), DispatchClass::Operational, Pays::No))]
+        pub fn recover_coldkey(
+            origin: OriginFor<T>,
+            coldkey: T::AccountId,
+            new_coldkey: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            Self::do_recover_coldkey(&coldkey, &new_coldkey)
+        }
+
+        /// Moves stake from one hotkey to another without unstaking it. The call must be made
+        /// from the coldkey owning the stake.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The signature of the caller's coldkey.
+        ///
+        /// * 'origin_hotkey' (T::AccountId):
+        /// 	- The hotkey the stake is moved from.
+        ///
+        /// * 'destination_hotkey' (T::AccountId):
+        /// 	- The hotkey the stake is moved to.
+        ///
+        /// * 'amount' (u64):
+        /// 	- The amount of stake to move.
+        ///
+        /// # Event:
+        /// * StakeMoved;
+        /// 	- On successfully moving the stake.
+        ///
+        /// # Raises:
+        /// * 'CannotMoveStakeToSameHotkey':
+        /// 	- Thrown if both hotkeys are the same.
+        ///
+        /// * 'HotKeyAccountNotExists':
+        /// 	- Thrown if either hotkey does not exist.
+        ///
+        /// * 'HotKeyNotDelegateAndSignerNotOwnHotKey':
+        /// 	- Thrown if either hotkey is neither a delegate nor owned by the caller.
+        ///
+        /// * 'NotEnoughStakeToWithdraw':
+        /// 	- Thrown if there is not enough stake on the origin hotkey to move this amount.
+        ///
+        /// * 'NomStakeBelowMinimumThreshold':
+        /// 	- Thrown if the resulting nomination is below the minimum nominator stake.
+        ///
+        #[pallet::call_index(86)]
+        #[pallet::weight((Weight::from_parts(164_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(14))
+		.saturating_add(T::DbWeight::get().writes(10)), DispatchClass::Normal, Pays::No))]
+        pub fn move_stake(
+            origin: OriginFor<T>,
+            origin_hotkey: T::AccountId,
+            destination_hotkey: T::AccountId,
+            amount: u64,
+        ) -> DispatchResult {
+            Self::do_move_stake(origin, origin_hotkey, destination_hotkey, amount)
+        }
+
+        /// Transfers stake on a hotkey to another coldkey without unstaking it. The call must be
+        /// made from the coldkey owning the stake.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The signature of the caller's coldkey.
+        ///
+        /// * 'destination_coldkey' (T::AccountId):
+        /// 	- The coldkey receiving the stake.
+        ///
+        /// * 'hotkey' (T::AccountId):
+        /// 	- The hotkey the stake is held on.
+        ///
+        /// * 'amount' (u64):
+        /// 	- The amount of stake to transfer.
+        ///
+        /// # Event:
+        /// * StakeTransferred;
+        /// 	- On successfully transferring the stake.
+        ///
+        /// # Raises:
+        /// * 'SameColdkey':
+        /// 	- Thrown if the destination coldkey is the caller.
+        ///
+        /// * 'SwapAlreadyScheduled':
+        /// 	- Thrown if the caller has a coldkey swap scheduled.
+        ///
+        /// * 'HotKeyNotDelegateAndSignerNotOwnHotKey':
+        /// 	- Thrown if the hotkey is neither a delegate nor owned by the destination coldkey.
+        ///
+        /// * 'NotEnoughStakeToWithdraw':
+        /// 	- Thrown if there is not enough stake on the hotkey to transfer this amount.
+        ///
+        /// * 'NomStakeBelowMinimumThreshold':
+        /// 	- Thrown if the resulting nomination is below the minimum nominator stake.
+        ///
+        #[pallet::call_index(87)]
+        #[pallet::weight((Weight::from_parts(160_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(14))
+		.saturating_add(T::DbWeight::get().writes(11)), DispatchClass::Normal, Pays::No))]
+        pub fn transfer_stake(
+            origin: OriginFor<T>,
+            destination_coldkey: T::AccountId,
+            hotkey: T::AccountId,
+            amount: u64,
+        ) -> DispatchResult {
+            Self::do_transfer_stake(origin, destination_coldkey, hotkey, amount)
+        }
+
+        /// Withdraws the stake of the caller's coldkey which has finished unbonding onto its free
+        /// balance.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The signature of the caller's coldkey.
+        ///
+        /// # Event:
+        /// * UnbondedWithdrawn;
+        /// 	- On successfully withdrawing the unbonded stake.
+        ///
+        /// # Raises:
+        /// * 'NoUnbondedStakeToWithdraw':
+        /// 	- Thrown if none of the unbonding stake of the coldkey has reached its unlock block.
+        ///
+        #[pallet::call_index(95)]
+        #[pallet::weight((Weight::from_parts(45_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(3))
+		.saturating_add(T::DbWeight::get().writes(3)), DispatchClass::Normal, Pays::No))]
+        pub fn withdraw_unbonded(origin: OriginFor<T>) -> DispatchResult {
+            Self::do_withdraw_unbonded(origin)
+        }
+
+        /// Sets where the emission the caller's coldkey earns as a nominator of a hotkey is paid:
+        /// compounded into its stake, which is the default, paid to its free balance, or paid to
+        /// the free balance of another account.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The signature of the caller's coldkey.
+        ///
+        /// * 'hotkey' (T::AccountId):
+        /// 	- The hotkey the coldkey stakes on.
+        ///
+        /// * 'payout' (RewardPayout<T::AccountId>):
+        /// 	- Where the emission is paid.
+        ///
+        /// # Event:
+        /// * RewardPayoutSet;
+        /// 	- On successfully setting the payout.
+        ///
+        /// # Raises:
+        /// * 'NoStakeOnHotkey':
+        /// 	- Thrown if the coldkey has no stake on the hotkey.
+        ///
+        #[pallet::call_index(98)]
+        #[pallet::weight((Weight::from_parts(30_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(1))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::Yes))]
+        pub fn set_reward_payout(
+            origin: OriginFor<T>,
+            hotkey: T::AccountId,
+            payout: RewardPayout<T::AccountId>,
         ) -> DispatchResult {
-            Self::user_add_network(origin, identity)
+            Self::do_set_reward_payout(origin, hotkey, payout)
+        }
+
+        /// Dissolves a network as scheduled by `schedule_dissolve_network`, for the account
+        /// which scheduled it. The record of the dissolution is dropped even if it fails.
+        ///
+        /// # Errors
+        ///
+        /// * `BadOrigin` - The origin is not root.
+        /// * `DissolveNetworkNotScheduled` - No dissolution is recorded for the network.
+        #[pallet::call_index(99)]
+        #[pallet::weight((Weight::from_parts(119_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(7))
+		.saturating_add(T::DbWeight::get().writes(32)), DispatchClass::Operational, Pays::No))]
+        pub fn dissolve_scheduled_network(origin: OriginFor<T>, netuid: u16) -> DispatchResult {
+            ensure_root(origin)?;
+            Self::do_dissolve_scheduled_network(netuid)
         }
     }
 }
@@ -164,7 +164,7 @@ mod errors {
         DuplicateChild,
         /// Proportion overflow when setting children.
         ProportionOverflow,
-        /// Too many children MAX 5.
+        /// Too many children for the subnet.
         TooManyChildren,
         /// Default transaction rate limit exceeded.
         TxRateLimitExceeded,
@@ -184,5 +184,69 @@ mod errors {
         TxChildkeyTakeRateLimitExceeded,
         /// Invalid identity.
         InvalidIdentity,
+        /// A subnet ownership transfer is already pending for this network.
+        SubnetOwnerTransferAlreadyPending,
+        /// No subnet ownership transfer is pending for this network.
+        NoPendingSubnetOwnerTransfer,
+        /// The proposed new subnet owner is already the owner.
+        NewSubnetOwnerIsCurrentOwner,
+        /// The caller is not the proposed new owner of the subnet.
+        NotProposedSubnetOwner,
+        /// The subnet ownership transfer delay has not elapsed yet.
+        SubnetOwnerTransferNotReady,
+        /// No coldkey swap is scheduled for this coldkey.
+        SwapNotScheduled,
+        /// A dissolution is already scheduled for this network.
+        DissolveNetworkAlreadyScheduled,
+        /// No dissolution is scheduled for this network.
+        DissolveNetworkNotScheduled,
+        /// The scheduler failed to cancel the scheduled call.
+        FailedToCancelSchedule,
+        /// The coldkey has no contested swap awaiting arbitration.
+        ColdkeyNotInArbitration,
+        /// Stake cannot be moved onto the hotkey it is moved from.
+        CannotMoveStakeToSameHotkey,
+        /// No change of this hyperparameter is pending for this network.
+        NoPendingHyperparameterChange,
+        /// The value is outside the range a subnet owner may set the hyperparameter to.
+        HyperparameterOutOfBounds,
+        /// The lower bound of a hyperparameter is above its upper bound.
+        InvalidHyperparameterBounds,
+        /// No hyperparameter template exists with the given id.
+        HyperparameterTemplateNotFound,
+        /// The hyperparameter template has an empty or too long name, or too many hyperparameters.
+        InvalidHyperparameterTemplate,
+        /// No children are proposed by this hotkey on this network.
+        ChildkeyProposalNotFound,
+        /// The hotkey is not among the proposed children.
+        ChildNotProposed,
+        /// The child has already accepted the proposal.
+        ChildAlreadyAccepted,
+        /// A child proportion is below the minimum of the subnet.
+        ChildProportionTooLow,
+        /// The key is locked until a key swap it is part of completes.
+        KeySwapInProgress,
+        /// The guardians are empty, too many, duplicated, include the coldkey, or the threshold is out of range.
+        InvalidRecoveryGuardians,
+        /// The account is not a guardian of the coldkey.
+        NotRecoveryGuardian,
+        /// A recovery of the coldkey is already in progress.
+        ColdkeyRecoveryInProgress,
+        /// No recovery of the coldkey is in progress.
+        ColdkeyRecoveryNotFound,
+        /// The guardian has already vouched for the recovery to the coldkey.
+        ColdkeyRecoveryAlreadyVouched,
+        /// The unbonding queue of the coldkey is full.
+        UnbondingQueueFull,
+        /// None of the unbonding stake of the coldkey is unlocked.
+        NoUnbondedStakeToWithdraw,
+        /// The coldkey has no stake on the hotkey.
+        NoStakeOnHotkey,
+        /// The destination is not one of the contested destinations of the coldkey swap.
+        NotContestedColdkeySwapDestination,
+        /// The new hotkey already has children, parents or proposed children on the subnet.
+        NewHotKeyHasChildkeys,
+        /// The new hotkey already has stake, which the moved stake would be merged into.
+        NewHotKeyHasStake,
     }
 }
@@ -147,6 +147,26 @@ mod events {
             /// the account ID of the new senate member
             new_member: T::AccountId,
         },
+        /// a member of the senate is removed without replacement
+        SenateMemberRemoved {
+            /// the account ID of the removed senate member
+            member: T::AccountId,
+        },
+        /// the senate adjustment interval is set
+        SenateAdjustmentIntervalSet(u64),
+        /// the delegate take notice period is set
+        DelegateTakeNoticePeriodSet(u64),
+        /// A delegate take increase has been announced and applies once its notice period has passed
+        TakeIncreaseScheduled {
+            /// The coldkey owning the hotkey
+            coldkey: T::AccountId,
+            /// The delegate hotkey
+            hotkey: T::AccountId,
+            /// The announced take
+            take: u16,
+            /// The block at which the take applies
+            effective_block: u64,
+        },
         /// A coldkey has been swapped
         ColdkeySwapped {
             /// the account ID of old coldkey
@@ -185,6 +205,16 @@ mod events {
         HotkeyEmissionTempoSet(u64),
         /// The network maximum stake has been set
         NetworkMaxStakeSet(u16, u64),
+        /// The maximum number of children on a subnet has been set
+        MaxChildrenSet(u16, u16),
+        /// The minimum proportion of each child on a subnet has been set
+        MinChildProportionSet(u16, u64),
+        /// The blocks between setting children on a subnet have been set
+        SetChildrenCooldownSet(u16, u64),
+        /// The lower bound of the childkey take on a subnet has been set
+        SubnetMinChildkeyTakeSet(u16, u16),
+        /// The upper bound of the childkey take on a subnet has been set
+        SubnetMaxChildkeyTakeSet(u16, u16),
         /// The identity of a coldkey has been set
         ChainIdentitySet(T::AccountId),
         /// The identity of a subnet has been set
@@ -204,5 +234,312 @@ mod events {
         ColdkeySwapScheduleDurationSet(BlockNumberFor<T>),
         /// The duration of dissolve network has been set
         DissolveNetworkScheduleDurationSet(BlockNumberFor<T>),
+        /// A subnet ownership transfer has been proposed by the current owner
+        SubnetOwnerTransferProposed {
+            /// The network ID being transferred
+            netuid: u16,
+            /// The account ID of the current owner
+            current_owner: T::AccountId,
+            /// The account ID of the proposed new owner
+            new_owner: T::AccountId,
+            /// The first block at which the new owner can accept the transfer
+            executable_block: BlockNumberFor<T>,
+        },
+        /// A pending subnet ownership transfer has been cancelled
+        SubnetOwnerTransferCancelled {
+            /// The network ID of the cancelled transfer
+            netuid: u16,
+            /// The account ID of the proposed new owner
+            new_owner: T::AccountId,
+        },
+        /// The ownership of a subnet has been transferred
+        SubnetOwnerTransferred {
+            /// The network ID that changed ownership
+            netuid: u16,
+            /// The account ID of the previous owner
+            old_owner: T::AccountId,
+            /// The account ID of the new owner
+            new_owner: T::AccountId,
+        },
+        /// The duration of subnet owner transfer schedule has been set
+        SubnetOwnerTransferScheduleDurationSet(BlockNumberFor<T>),
+        /// A scheduled coldkey swap has been cancelled
+        ColdkeySwapCancelled {
+            /// The account ID of the old coldkey
+            old_coldkey: T::AccountId,
+            /// The account ID of the new coldkey the swap was scheduled to
+            new_coldkey: T::AccountId,
+        },
+        /// A scheduled coldkey swap has been contested by a request to another destination
+        ColdkeySwapContested {
+            /// The account ID of the coldkey in arbitration
+            old_coldkey: T::AccountId,
+            /// The account ID of the newly requested destination
+            new_coldkey: T::AccountId,
+        },
+        /// Governance has resolved a contested coldkey swap
+        ColdkeySwapDisputeResolved {
+            /// The account ID of the coldkey which was in arbitration
+            old_coldkey: T::AccountId,
+            /// The destination chosen by governance, or None if every request was rejected
+            new_coldkey: Option<T::AccountId>,
+        },
+        /// A scheduled network dissolution has been cancelled
+        DissolveNetworkCancelled {
+            /// The account ID which scheduled the dissolution
+            account: T::AccountId,
+            /// The network ID which will no longer be dissolved
+            netuid: u16,
+        },
+        /// Stake has been moved from one hotkey to another
+        StakeMoved {
+            /// The account ID of the coldkey owning the stake
+            coldkey: T::AccountId,
+            /// The account ID of the hotkey the stake was moved from
+            origin_hotkey: T::AccountId,
+            /// The account ID of the hotkey the stake was moved to
+            destination_hotkey: T::AccountId,
+            /// The amount of stake moved
+            amount: u64,
+        },
+        /// Stake on a hotkey has been transferred to another coldkey
+        StakeTransferred {
+            /// The account ID of the coldkey the stake was transferred from
+            origin_coldkey: T::AccountId,
+            /// The account ID of the coldkey the stake was transferred to
+            destination_coldkey: T::AccountId,
+            /// The account ID of the hotkey the stake is held on
+            hotkey: T::AccountId,
+            /// The amount of stake transferred
+            amount: u64,
+        },
+        /// The delay before hyperparameter changes apply has been set
+        HyperparameterEnactmentDelaySet(u64),
+        /// A hyperparameter change has been scheduled
+        HyperparameterChangeScheduled {
+            /// The network ID of the subnet
+            netuid: u16,
+            /// The hyperparameter being changed
+            param: SubnetHyperparameter,
+            /// The value it will be set to
+            value: u64,
+            /// The block at which the change applies
+            effective_block: u64,
+        },
+        /// A pending hyperparameter change has been applied
+        HyperparameterChangeEnacted {
+            /// The network ID of the subnet
+            netuid: u16,
+            /// The hyperparameter which changed
+            param: SubnetHyperparameter,
+            /// The value it was set to
+            value: u64,
+        },
+        /// A pending hyperparameter change has been cancelled
+        HyperparameterChangeCancelled {
+            /// The network ID of the subnet
+            netuid: u16,
+            /// The hyperparameter which will no longer change
+            param: SubnetHyperparameter,
+        },
+        /// The range a subnet owner may set a hyperparameter to has been set
+        HyperparameterBoundsSet {
+            /// The bounded hyperparameter
+            param: SubnetHyperparameter,
+            /// The lowest value a subnet owner may set
+            min: u64,
+            /// The highest value a subnet owner may set
+            max: u64,
+        },
+        /// A hyperparameter template has been set
+        HyperparameterTemplateSet(u16),
+        /// A hyperparameter template has been removed
+        HyperparameterTemplateRemoved(u16),
+        /// A hyperparameter template has been applied to a new subnet
+        HyperparameterTemplateApplied {
+            /// The network ID of the subnet
+            netuid: u16,
+            /// The applied template
+            template_id: u16,
+        },
+        /// Children have been proposed for a hotkey and await the consent of their coldkeys
+        ChildrenProposed {
+            /// The parent hotkey
+            hotkey: T::AccountId,
+            /// The network ID of the subnet
+            netuid: u16,
+            /// The proposed children with their proportions
+            children: Vec<(u64, T::AccountId)>,
+            /// The block at which the proposal is dropped if not accepted by all children
+            expires_at: u64,
+        },
+        /// A child has accepted to be set as the child of a hotkey
+        ChildAccepted {
+            /// The parent hotkey
+            parent: T::AccountId,
+            /// The accepting child hotkey
+            child: T::AccountId,
+            /// The network ID of the subnet
+            netuid: u16,
+        },
+        /// A child has rejected to be set as the child of a hotkey, dropping the proposal
+        ChildRejected {
+            /// The parent hotkey
+            parent: T::AccountId,
+            /// The rejecting child hotkey
+            child: T::AccountId,
+            /// The network ID of the subnet
+            netuid: u16,
+        },
+        /// Proposed children were not accepted in time
+        ChildkeyProposalExpired {
+            /// The parent hotkey
+            hotkey: T::AccountId,
+            /// The network ID of the subnet
+            netuid: u16,
+        },
+        /// The emission passed from children to parents in an epoch has been recorded
+        ChildkeyEmissionRecorded {
+            /// The network ID of the subnet
+            netuid: u16,
+            /// The number of child to parent transfers
+            transfers: u32,
+            /// The total emission passed to parents
+            total_emission: u64,
+        },
+        /// The hotkey is swapped on a single subnet
+        HotkeySwappedOnSubnet {
+            /// the account ID of coldkey
+            coldkey: T::AccountId,
+            /// the account ID of old hotkey
+            old_hotkey: T::AccountId,
+            /// the account ID of new hotkey
+            new_hotkey: T::AccountId,
+            /// the subnet the hotkey is swapped on
+            netuid: u16,
+            /// whether the stake moved to the new hotkey
+            move_stake: bool,
+        },
+        /// A key swap too large for one block has moved more entries, the keys stay locked
+        KeySwapProgressed {
+            /// the account ID of the swapped key
+            old_key: T::AccountId,
+            /// the account ID of the new key
+            new_key: T::AccountId,
+            /// the number of subnets and stake rows moved so far
+            entries_moved: u32,
+        },
+        /// A key swap continued over several blocks has completed, the keys are unlocked
+        KeySwapCompleted {
+            /// the account ID of the swapped key
+            old_key: T::AccountId,
+            /// the account ID of the new key
+            new_key: T::AccountId,
+            /// the number of subnets and stake rows moved
+            entries_moved: u32,
+        },
+        /// The guardians who can recover a coldkey are set, none when empty
+        RecoveryGuardiansSet {
+            /// the account ID of the coldkey
+            coldkey: T::AccountId,
+            /// the guardians of the coldkey
+            guardians: Vec<T::AccountId>,
+            /// the number of guardians who must vouch for a recovery
+            threshold: u32,
+        },
+        /// A guardian has vouched for the recovery of a coldkey
+        ColdkeyRecoveryVouched {
+            /// the account ID of the coldkey
+            coldkey: T::AccountId,
+            /// the account ID of the guardian
+            guardian: T::AccountId,
+            /// the coldkey the recovery swaps to
+            new_coldkey: T::AccountId,
+            /// the number of guardians who vouched for the new coldkey so far
+            vouches: u32,
+        },
+        /// Enough guardians vouched, the recovery swap is scheduled
+        ColdkeyRecoveryScheduled {
+            /// the account ID of the coldkey
+            coldkey: T::AccountId,
+            /// the coldkey the recovery swaps to
+            new_coldkey: T::AccountId,
+            /// the block the swap is executed at
+            execution_block: BlockNumberFor<T>,
+        },
+        /// The coldkey has vetoed its recovery
+        ColdkeyRecoveryVetoed {
+            /// the account ID of the coldkey
+            coldkey: T::AccountId,
+            /// the coldkey the recovery would have swapped to, if it was scheduled
+            new_coldkey: Option<T::AccountId>,
+        },
+        /// The coldkey has been recovered by its guardians
+        ColdkeyRecovered {
+            /// the account ID of the recovered coldkey
+            old_coldkey: T::AccountId,
+            /// the coldkey it is swapped to
+            new_coldkey: T::AccountId,
+        },
+        /// the unbonding period is set
+        UnbondingPeriodSet(u64),
+        /// Removed stake is waiting for the unbonding period before it can be withdrawn
+        StakeUnbonding {
+            /// the account ID of the coldkey
+            coldkey: T::AccountId,
+            /// the hotkey the stake was removed from
+            hotkey: T::AccountId,
+            /// the amount unbonding
+            amount: u64,
+            /// the block from which the amount can be withdrawn
+            unlock_block: u64,
+        },
+        /// Unbonded stake has been withdrawn to the free balance of the coldkey
+        UnbondedWithdrawn {
+            /// the account ID of the coldkey
+            coldkey: T::AccountId,
+            /// the amount withdrawn
+            amount: u64,
+        },
+        /// A coldkey has set where its nominator emission on a hotkey is paid
+        RewardPayoutSet {
+            /// the account ID of the coldkey
+            coldkey: T::AccountId,
+            /// the hotkey the coldkey stakes on
+            hotkey: T::AccountId,
+            /// where the emission is paid
+            payout: RewardPayout<T::AccountId>,
+        },
+        /// Nominator emission has been paid to a free balance instead of compounding
+        NominatorRewardPaid {
+            /// the account ID of the nominator coldkey
+            coldkey: T::AccountId,
+            /// the drained hotkey
+            hotkey: T::AccountId,
+            /// the account the emission is paid to
+            destination: T::AccountId,
+            /// the amount paid
+            amount: u64,
+        },
+        /// stake weighted senate voting is enabled or disabled
+        SenateStakeWeightedVotingSet(bool),
+        /// A scheduled network dissolution has run but failed, its record is dropped
+        DissolveNetworkFailed {
+            /// The account ID which scheduled the dissolution
+            account: T::AccountId,
+            /// The network ID which was not dissolved
+            netuid: u16,
+            /// The reason the dissolution failed
+            error: DispatchError,
+        },
+        /// Proposed children were accepted by all children but could no longer be set
+        ChildkeyProposalDropped {
+            /// The parent hotkey
+            hotkey: T::AccountId,
+            /// The network ID of the subnet
+            netuid: u16,
+            /// The reason the children could not be set
+            error: DispatchError,
+        },
     }
 }
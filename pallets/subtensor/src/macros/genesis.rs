@@ -11,6 +11,9 @@ mod genesis {
             // Set initial total issuance from balances
             TotalIssuance::<T>::put(self.balances_issuance);
 
+            // Bound the owner-settable hyperparameters by their current limits
+            Pallet::<T>::seed_default_hyperparameter_bounds();
+
             // Subnet config values
             let netuid: u16 = 3;
             let tempo = 99;
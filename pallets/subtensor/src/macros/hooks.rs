@@ -15,6 +15,12 @@ mod hooks {
         // 	* 'n': (BlockNumberFor<T>):
         // 		- The number of the block we are initializing.
         fn on_initialize(_block_number: BlockNumberFor<T>) -> Weight {
+            // --- Continue the key swaps in progress within a fixed budget, so they complete and
+            // unlock their keys even in blocks without weight left for on_idle.
+            let key_swap_weight =
+                Self::continue_pending_key_swaps(Self::get_key_swap_call_budget());
+            // --- Rebalance the senate by root stake every `SenateAdjustmentInterval` blocks.
+            let senate_weight = Self::adjust_senate_if_due(Self::get_current_block_as_u64());
             let block_step_result = Self::block_step();
             match block_step_result {
                 Ok(_) => {
@@ -23,6 +29,8 @@ mod hooks {
                     Weight::from_parts(110_634_229_000_u64, 0)
                         .saturating_add(T::DbWeight::get().reads(8304_u64))
                         .saturating_add(T::DbWeight::get().writes(110_u64))
+                        .saturating_add(key_swap_weight)
+                        .saturating_add(senate_weight)
                 }
                 Err(e) => {
                     // --- If the block step was unsuccessful, return the weight anyway.
@@ -30,10 +38,23 @@ mod hooks {
                     Weight::from_parts(110_634_229_000_u64, 0)
                         .saturating_add(T::DbWeight::get().reads(8304_u64))
                         .saturating_add(T::DbWeight::get().writes(110_u64))
+                        .saturating_add(key_swap_weight)
+                        .saturating_add(senate_weight)
                 }
             }
         }
 
+        // ---- Called with the weight left at the end of the block, continues the key swaps in progress.
+        //
+        // # Args:
+        // 	* 'n': (BlockNumberFor<T>):
+        // 		- The number of the block.
+        // 	* 'remaining_weight': (Weight):
+        // 		- The weight left in the block.
+        fn on_idle(_block_number: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            Self::continue_pending_key_swaps(remaining_weight)
+        }
+
         fn on_runtime_upgrade() -> frame_support::weights::Weight {
             // --- Migrate storage
             let mut weight = frame_support::weights::Weight::from_parts(0, 0);
@@ -70,7 +91,9 @@ mod hooks {
                 // Storage version v8 -> v9
                 .saturating_add(migrations::migrate_fix_total_coldkey_stake::migrate_fix_total_coldkey_stake::<T>())
                 // Migrate Delegate Ids on chain
-                .saturating_add(migrations::migrate_chain_identity::migrate_set_hotkey_identities::<T>());
+                .saturating_add(migrations::migrate_chain_identity::migrate_set_hotkey_identities::<T>())
+                // Bound the owner-settable hyperparameters by their current limits
+                .saturating_add(migrations::migrate_seed_hyperparameter_bounds::migrate_seed_hyperparameter_bounds::<T>());
             weight
         }
 
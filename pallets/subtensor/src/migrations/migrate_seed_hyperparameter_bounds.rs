@@ -0,0 +1,48 @@
+use super::*;
+use alloc::string::String;
+use frame_support::{traits::Get, weights::Weight};
+
+/// Bounds the owner-settable hyperparameters by the limits they are already checked against,
+/// and rho, kappa and the activity cutoff by their default ranges, see
+/// `get_default_hyperparameter_bounds`. Bounds root has already set are kept.
+///
+/// # Returns
+/// The weight of the migration process.
+pub fn migrate_seed_hyperparameter_bounds<T: Config>() -> Weight {
+    let migration_name = b"seed_hyperparameter_bounds".to_vec();
+
+    // Initialize the weight with one read operation.
+    let mut weight = T::DbWeight::get().reads(1);
+
+    // Check if the migration has already run
+    if HasMigrationRun::<T>::get(&migration_name) {
+        log::info!(
+            "Migration '{:?}' has already run. Skipping.",
+            migration_name
+        );
+        return weight;
+    }
+
+    log::info!(
+        "Running migration '{}'",
+        String::from_utf8_lossy(&migration_name)
+    );
+
+    // Seed the bounds missing from the table
+    let bounds = Pallet::<T>::get_default_hyperparameter_bounds().len() as u64;
+    let seeded = Pallet::<T>::seed_default_hyperparameter_bounds();
+    weight = weight.saturating_add(T::DbWeight::get().reads_writes(bounds, seeded));
+
+    // Mark the migration as completed
+    HasMigrationRun::<T>::insert(&migration_name, true);
+    weight = weight.saturating_add(T::DbWeight::get().writes(1));
+
+    log::info!(
+        "Migration '{}' completed. {} hyperparameters bounded.",
+        String::from_utf8_lossy(&migration_name),
+        seeded
+    );
+
+    // Return the migration weight.
+    weight
+}
@@ -0,0 +1,134 @@
+use super::*;
+use frame_support::pallet_prelude::{Decode, Encode};
+extern crate alloc;
+use codec::Compact;
+use sp_core::hexdisplay::AsBytesRef;
+
+#[freeze_struct("2ee75e6271780c71")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct PendingChildrenInfo<T: Config> {
+    parent: T::AccountId,
+    netuid: Compact<u16>,
+    children: Vec<(Compact<u64>, T::AccountId)>, // Proposed children with their proportions
+    accepted: Vec<T::AccountId>,                 // Children which have accepted
+    expires_at: Compact<u64>,
+}
+
+#[freeze_struct("8f5630eab373c803")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct ChildkeyStakeInfo<T: Config> {
+    hotkey: T::AccountId,
+    proportion: Compact<u64>,
+    stake: Compact<u64>, // Stake inherited from the parent, or lent to the child
+}
+
+#[freeze_struct("7d89efbe06ce83f")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct ChildkeyInfo<T: Config> {
+    hotkey: T::AccountId,
+    netuid: Compact<u16>,
+    total_stake: Compact<u64>, // Stake on the hotkey itself, before lending and inheriting
+    parents: Vec<ChildkeyStakeInfo<T>>,
+    children: Vec<ChildkeyStakeInfo<T>>,
+    childkey_take: Compact<u16>,
+    effective_stake: Compact<u64>, // Stake the hotkey has on the subnet, as used by the epoch
+}
+
+impl<T: Config> Pallet<T> {
+    pub fn get_childkey_info(hotkey_account_vec: Vec<u8>, netuid: u16) -> Option<ChildkeyInfo<T>> {
+        if hotkey_account_vec.len() != 32 {
+            return None; // Invalid hotkey
+        }
+        if !Self::if_subnet_exist(netuid) {
+            return None;
+        }
+
+        let hotkey = T::AccountId::decode(&mut hotkey_account_vec.as_bytes_ref()).ok()?;
+        let total_stake: u64 = Self::get_total_stake_for_hotkey(&hotkey);
+
+        let parents: Vec<ChildkeyStakeInfo<T>> = Self::get_parents(&hotkey, netuid)
+            .into_iter()
+            .map(|(proportion, parent)| {
+                let parent_stake: u64 = Self::get_total_stake_for_hotkey(&parent);
+                ChildkeyStakeInfo {
+                    hotkey: parent,
+                    proportion: proportion.into(),
+                    stake: Self::get_childkey_stake_proportion(parent_stake, proportion).into(),
+                }
+            })
+            .collect();
+        let children: Vec<ChildkeyStakeInfo<T>> = Self::get_children(&hotkey, netuid)
+            .into_iter()
+            .map(|(proportion, child)| ChildkeyStakeInfo {
+                hotkey: child,
+                proportion: proportion.into(),
+                stake: Self::get_childkey_stake_proportion(total_stake, proportion).into(),
+            })
+            .collect();
+
+        Some(ChildkeyInfo {
+            netuid: netuid.into(),
+            total_stake: total_stake.into(),
+            parents,
+            children,
+            childkey_take: Self::get_childkey_take(&hotkey, netuid).into(),
+            effective_stake: Self::get_stake_for_hotkey_on_subnet(&hotkey, netuid).into(),
+            hotkey,
+        })
+    }
+
+    fn get_pending_children_info_from_proposal(
+        parent: T::AccountId,
+        netuid: u16,
+        proposal: ChildkeyProposal<T::AccountId>,
+    ) -> PendingChildrenInfo<T> {
+        PendingChildrenInfo {
+            parent,
+            netuid: netuid.into(),
+            children: proposal
+                .children
+                .into_iter()
+                .map(|(proportion, child)| (proportion.into(), child))
+                .collect(),
+            accepted: proposal.accepted,
+            expires_at: proposal.expires_at.into(),
+        }
+    }
+
+    pub fn get_childkey_emission_history(netuid: u16) -> Vec<ChildkeyEmissionRecord<T::AccountId>> {
+        ChildkeyEmissionHistory::<T>::get(netuid)
+    }
+
+    pub fn get_pending_children_info(
+        hotkey_account_vec: Vec<u8>,
+        netuid: u16,
+    ) -> Option<PendingChildrenInfo<T>> {
+        if hotkey_account_vec.len() != 32 {
+            return None; // Invalid hotkey
+        }
+
+        let parent = T::AccountId::decode(&mut hotkey_account_vec.as_bytes_ref()).ok()?;
+        let proposal = Self::get_pending_children(&parent, netuid)?;
+
+        Some(Self::get_pending_children_info_from_proposal(
+            parent, netuid, proposal,
+        ))
+    }
+
+    pub fn get_pending_parents_info(child_account_vec: Vec<u8>) -> Vec<PendingChildrenInfo<T>> {
+        if child_account_vec.len() != 32 {
+            return Vec::new(); // Invalid hotkey
+        }
+
+        let Ok(child) = T::AccountId::decode(&mut child_account_vec.as_bytes_ref()) else {
+            return Vec::new(); // Invalid hotkey
+        };
+
+        Self::get_pending_parents(&child)
+            .into_iter()
+            .map(|(parent, netuid, proposal)| {
+                Self::get_pending_children_info_from_proposal(parent, netuid, proposal)
+            })
+            .collect()
+    }
+}
@@ -20,6 +20,30 @@ pub struct DelegateInfo<T: Config> {
     total_daily_return: Compact<u64>, // Delegators current daily return
 }
 
+#[freeze_struct("645807e1e878c002")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct DelegateYield {
+    drained: Compact<u64>,     // Emission drained from the delegate over the window
+    take: Compact<u64>,        // Take collected by the delegate over the window
+    distributed: Compact<u64>, // Emission distributed to the nominators over the window
+    return_per_1000: Compact<u64>, // Distributed emission per 1000 TAO currently staked
+}
+
+#[freeze_struct("94eea9a80545f21b")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct DelegateInfoV2<T: Config> {
+    delegate_ss58: T::AccountId,
+    take: Compact<u16>,
+    nominators: Vec<(T::AccountId, Compact<u64>)>, // map of nominator_ss58 to stake amount
+    owner_ss58: T::AccountId,
+    registrations: Vec<Compact<u16>>, // Vec of netuid this delegate is registered on
+    validator_permits: Vec<Compact<u16>>, // Vec of netuid this delegate has validator permit on
+    total_stake: Compact<u64>,        // Total stake on the delegate
+    yield_1d: DelegateYield,          // Realized yield over the last day
+    yield_7d: DelegateYield,          // Realized yield over the last 7 days
+    yield_30d: DelegateYield,         // Realized yield over the last 30 days
+}
+
 impl<T: Config> Pallet<T> {
     fn get_delegate_by_existing_account(delegate: AccountIdOf<T>) -> DelegateInfo<T> {
         let mut nominators = Vec::<(T::AccountId, Compact<u64>)>::new();
@@ -82,6 +106,62 @@ impl<T: Config> Pallet<T> {
         }
     }
 
+    fn get_delegate_v2_by_existing_account(delegate: AccountIdOf<T>) -> DelegateInfoV2<T> {
+        let info = Self::get_delegate_by_existing_account(delegate.clone());
+        let total_stake: u64 = Self::get_total_stake_for_hotkey(&delegate);
+        let history = HotkeyDrainHistory::<T>::get(&delegate);
+
+        DelegateInfoV2 {
+            delegate_ss58: info.delegate_ss58,
+            take: info.take,
+            nominators: info.nominators,
+            owner_ss58: info.owner_ss58,
+            registrations: info.registrations,
+            validator_permits: info.validator_permits,
+            total_stake: total_stake.into(),
+            yield_1d: Self::get_delegate_yield(&history, 1, total_stake),
+            yield_7d: Self::get_delegate_yield(&history, 7, total_stake),
+            yield_30d: Self::get_delegate_yield(&history, DRAIN_HISTORY_DAYS, total_stake),
+        }
+    }
+
+    /// Sums the drains of the last `days` days, today included.
+    fn get_delegate_yield(
+        history: &[HotkeyDrainRecord],
+        days: u64,
+        total_stake: u64,
+    ) -> DelegateYield {
+        let today: u64 = Self::get_current_block_as_u64()
+            .checked_div(BLOCKS_PER_DAY)
+            .unwrap_or(0);
+        let mut drained: u64 = 0;
+        let mut take: u64 = 0;
+        let mut distributed: u64 = 0;
+        for record in history
+            .iter()
+            .filter(|record| record.day.saturating_add(days) > today)
+        {
+            drained = drained.saturating_add(record.drained);
+            take = take.saturating_add(record.take);
+            distributed = distributed.saturating_add(record.distributed);
+        }
+
+        let total_stake: U64F64 = U64F64::from_num(total_stake);
+        let return_per_1000: U64F64 = if total_stake > U64F64::from_num(0) {
+            U64F64::from_num(distributed)
+                .saturating_div(total_stake.saturating_div(U64F64::from_num(1000)))
+        } else {
+            U64F64::from_num(0)
+        };
+
+        DelegateYield {
+            drained: drained.into(),
+            take: take.into(),
+            distributed: distributed.into(),
+            return_per_1000: U64F64::to_num::<u64>(return_per_1000).into(),
+        }
+    }
+
     pub fn get_delegate(delegate_account_vec: Vec<u8>) -> Option<DelegateInfo<T>> {
         if delegate_account_vec.len() != 32 {
             return None;
@@ -110,6 +190,29 @@ impl<T: Config> Pallet<T> {
         delegates
     }
 
+    pub fn get_delegate_v2(delegate_account_vec: Vec<u8>) -> Option<DelegateInfoV2<T>> {
+        if delegate_account_vec.len() != 32 {
+            return None;
+        }
+
+        let delegate: AccountIdOf<T> =
+            T::AccountId::decode(&mut delegate_account_vec.as_bytes_ref()).ok()?;
+        // Check delegate exists
+        if !<Delegates<T>>::contains_key(delegate.clone()) {
+            return None;
+        }
+
+        Some(Self::get_delegate_v2_by_existing_account(delegate))
+    }
+
+    /// get all delegates info from storage, with the yield realized by their drains
+    ///
+    pub fn get_delegates_v2() -> Vec<DelegateInfoV2<T>> {
+        <Delegates<T> as IterableStorageMap<T::AccountId, u16>>::iter_keys()
+            .map(Self::get_delegate_v2_by_existing_account)
+            .collect()
+    }
+
     /// get all delegate info and staked token amount for a given delegatee account
     ///
     pub fn get_delegated(delegatee_account_vec: Vec<u8>) -> Vec<(DelegateInfo<T>, Compact<u64>)> {
@@ -1,5 +1,8 @@
 use super::*;
+pub mod childkey_info;
 pub mod delegate_info;
 pub mod neuron_info;
+pub mod schedule_info;
 pub mod stake_info;
 pub mod subnet_info;
+pub mod swap_info;
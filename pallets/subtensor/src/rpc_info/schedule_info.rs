@@ -0,0 +1,77 @@
+use super::*;
+use frame_support::pallet_prelude::{Decode, Encode};
+extern crate alloc;
+use codec::Compact;
+use sp_core::hexdisplay::AsBytesRef;
+use sp_runtime::SaturatedConversion;
+
+#[freeze_struct("9cab8939aa80d0a4")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct ScheduledColdkeySwapInfo<T: Config> {
+    old_coldkey: T::AccountId,
+    new_coldkey: T::AccountId,
+    execution_block: Compact<u64>,
+}
+
+#[freeze_struct("45723634a9b2b85c")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct ScheduledNetworkDissolutionInfo<T: Config> {
+    netuid: Compact<u16>,
+    account: T::AccountId, // the account which scheduled the dissolution
+    execution_block: Compact<u64>,
+}
+
+impl<T: Config> Pallet<T> {
+    pub fn get_scheduled_coldkey_swap(
+        coldkey_account_vec: Vec<u8>,
+    ) -> Option<ScheduledColdkeySwapInfo<T>> {
+        if coldkey_account_vec.len() != 32 {
+            return None; // Invalid coldkey
+        }
+
+        let old_coldkey = T::AccountId::decode(&mut coldkey_account_vec.as_bytes_ref()).ok()?;
+        let (new_coldkey, execution_block) = ScheduledColdkeySwaps::<T>::get(&old_coldkey)?;
+
+        Some(ScheduledColdkeySwapInfo {
+            old_coldkey,
+            new_coldkey,
+            execution_block: execution_block.saturated_into::<u64>().into(),
+        })
+    }
+
+    pub fn get_scheduled_coldkey_swaps() -> Vec<ScheduledColdkeySwapInfo<T>> {
+        ScheduledColdkeySwaps::<T>::iter()
+            .map(
+                |(old_coldkey, (new_coldkey, execution_block))| ScheduledColdkeySwapInfo {
+                    old_coldkey,
+                    new_coldkey,
+                    execution_block: execution_block.saturated_into::<u64>().into(),
+                },
+            )
+            .collect()
+    }
+
+    pub fn get_scheduled_network_dissolution(
+        netuid: u16,
+    ) -> Option<ScheduledNetworkDissolutionInfo<T>> {
+        let (account, execution_block) = ScheduledNetworkDissolutions::<T>::get(netuid)?;
+
+        Some(ScheduledNetworkDissolutionInfo {
+            netuid: netuid.into(),
+            account,
+            execution_block: execution_block.saturated_into::<u64>().into(),
+        })
+    }
+
+    pub fn get_scheduled_network_dissolutions() -> Vec<ScheduledNetworkDissolutionInfo<T>> {
+        ScheduledNetworkDissolutions::<T>::iter()
+            .map(
+                |(netuid, (account, execution_block))| ScheduledNetworkDissolutionInfo {
+                    netuid: netuid.into(),
+                    account,
+                    execution_block: execution_block.saturated_into::<u64>().into(),
+                },
+            )
+            .collect()
+    }
+}
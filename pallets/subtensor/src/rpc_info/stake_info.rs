@@ -12,6 +12,13 @@ pub struct StakeInfo<T: Config> {
     stake: Compact<u64>,
 }
 
+#[freeze_struct("b3dca5e356fbb6bf")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct UnbondingInfo {
+    amount: Compact<u64>,
+    unlock_block: Compact<u64>,
+}
+
 impl<T: Config> Pallet<T> {
     fn _get_stake_info_for_coldkeys(
         coldkeys: Vec<T::AccountId>,
@@ -81,4 +88,22 @@ impl<T: Config> Pallet<T> {
             first.1.clone()
         }
     }
+
+    pub fn get_unbonding_queue(coldkey_account_vec: Vec<u8>) -> Vec<UnbondingInfo> {
+        if coldkey_account_vec.len() != 32 {
+            return Vec::new(); // Invalid coldkey
+        }
+
+        let Ok(coldkey) = T::AccountId::decode(&mut coldkey_account_vec.as_bytes_ref()) else {
+            return Vec::new();
+        };
+
+        UnbondingQueue::<T>::get(coldkey)
+            .into_iter()
+            .map(|(amount, unlock_block)| UnbondingInfo {
+                amount: amount.into(),
+                unlock_block: unlock_block.into(),
+            })
+            .collect()
+    }
 }
@@ -51,7 +51,7 @@ pub struct SubnetInfov2<T: Config> {
     identity: Option<SubnetIdentity>,
 }
 
-#[freeze_struct("55b472510f10e76a")]
+#[freeze_struct("6004ac6fd44863c")]
 #[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
 pub struct SubnetHyperparams {
     rho: Compact<u16>,
@@ -81,6 +81,21 @@ pub struct SubnetHyperparams {
     alpha_high: Compact<u16>,
     alpha_low: Compact<u16>,
     liquid_alpha_enabled: bool,
+    max_children: Compact<u16>,
+    min_child_proportion: Compact<u64>,
+    set_children_cooldown: Compact<u64>,
+    min_childkey_take: Compact<u16>,
+    max_childkey_take: Compact<u16>,
+    pending_changes: Vec<PendingHyperparameter>,
+}
+
+/// A hyperparameter change waiting for its enactment delay to pass.
+#[freeze_struct("67bd0cb6020a4c04")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct PendingHyperparameter {
+    param: SubnetHyperparameter,
+    value: Compact<u64>,
+    effective_block: Compact<u64>,
 }
 
 impl<T: Config> Pallet<T> {
@@ -256,6 +271,19 @@ impl<T: Config> Pallet<T> {
         let commit_reveal_weights_enabled = Self::get_commit_reveal_weights_enabled(netuid);
         let liquid_alpha_enabled = Self::get_liquid_alpha_enabled(netuid);
         let (alpha_low, alpha_high): (u16, u16) = Self::get_alpha_values(netuid);
+        let max_children = Self::get_max_children(netuid);
+        let min_child_proportion = Self::get_min_child_proportion(netuid);
+        let set_children_cooldown = Self::get_set_children_cooldown(netuid);
+        let min_childkey_take = Self::get_subnet_min_childkey_take(netuid);
+        let max_childkey_take = Self::get_subnet_max_childkey_take(netuid);
+        let pending_changes = Self::get_pending_hyperparameter_changes(netuid)
+            .into_iter()
+            .map(|(param, value, effective_block)| PendingHyperparameter {
+                param,
+                value: value.into(),
+                effective_block: effective_block.into(),
+            })
+            .collect();
 
         Some(SubnetHyperparams {
             rho: rho.into(),
@@ -285,6 +313,12 @@ impl<T: Config> Pallet<T> {
             alpha_high: alpha_high.into(),
             alpha_low: alpha_low.into(),
             liquid_alpha_enabled,
+            max_children: max_children.into(),
+            min_child_proportion: min_child_proportion.into(),
+            set_children_cooldown: set_children_cooldown.into(),
+            min_childkey_take: min_childkey_take.into(),
+            max_childkey_take: max_childkey_take.into(),
+            pending_changes,
         })
     }
 }
@@ -0,0 +1,166 @@
+use super::*;
+use frame_support::pallet_prelude::{Decode, Encode};
+use frame_support::storage::{with_transaction, TransactionOutcome};
+extern crate alloc;
+use codec::Compact;
+use sp_core::hexdisplay::AsBytesRef;
+
+#[freeze_struct("dc078641e1691d64")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct HotkeySwapPreview<T: Config> {
+    pub coldkey: T::AccountId,
+    pub old_hotkey: T::AccountId,
+    pub new_hotkey: T::AccountId,
+    pub swap_cost: Compact<u64>, // Burned from the coldkey by the swap
+    pub stake_moved: Compact<u64>,
+    pub stake_entries: Compact<u32>, // Stake and StakingHotkeys rows, one per staking coldkey
+    pub subnets: Compact<u32>,       // Subnets the hotkey is registered on
+    pub axons: Compact<u32>,
+    pub prometheus: Compact<u32>,
+    pub weight_commits: Compact<u32>,
+    pub child_keys: Compact<u32>, // Subnets on which the hotkey has children
+    pub parent_keys: Compact<u32>, // Subnets on which the hotkey has parents
+    pub pending_child_keys: Compact<u32>,
+    pub is_delegate: bool,
+    pub is_senate_member: bool,
+    pub error: Option<Vec<u8>>, // The error the swap would fail with
+}
+
+#[freeze_struct("80a55ac313792eb2")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct ColdkeySwapPreview<T: Config> {
+    pub old_coldkey: T::AccountId,
+    pub new_coldkey: T::AccountId,
+    pub swap_cost: Compact<u64>, // Burned from the old coldkey by the swap
+    pub stake_moved: Compact<u64>,
+    pub balance_moved: Compact<u64>, // Free balance left to move once the swap cost is burned
+    pub stake_entries: Compact<u32>, // Stake rows, one per staking hotkey
+    pub owned_hotkeys: Compact<u32>,
+    pub subnets_owned: Compact<u32>,
+    pub has_identity: bool,
+    pub error: Option<Vec<u8>>, // The error the swap would fail with
+}
+
+impl<T: Config> Pallet<T> {
+    /// Previews a hotkey swap without applying it.
+    ///
+    /// The swap is run and rolled back to find the error it would fail with, if any.
+    pub fn get_hotkey_swap_preview(
+        coldkey_account_vec: Vec<u8>,
+        old_hotkey_account_vec: Vec<u8>,
+        new_hotkey_account_vec: Vec<u8>,
+    ) -> Option<HotkeySwapPreview<T>> {
+        if coldkey_account_vec.len() != 32
+            || old_hotkey_account_vec.len() != 32
+            || new_hotkey_account_vec.len() != 32
+        {
+            return None; // Invalid account
+        }
+
+        let coldkey = T::AccountId::decode(&mut coldkey_account_vec.as_bytes_ref()).ok()?;
+        let old_hotkey = T::AccountId::decode(&mut old_hotkey_account_vec.as_bytes_ref()).ok()?;
+        let new_hotkey = T::AccountId::decode(&mut new_hotkey_account_vec.as_bytes_ref()).ok()?;
+
+        let netuids: Vec<u16> = Self::get_all_subnet_netuids();
+        let count = |filter: &dyn Fn(u16) -> bool| -> u32 {
+            netuids.iter().filter(|netuid| filter(**netuid)).count() as u32
+        };
+        let subnets = count(&|netuid| IsNetworkMember::<T>::get(&old_hotkey, netuid));
+        let axons = count(&|netuid| Axons::<T>::contains_key(netuid, &old_hotkey));
+        let prometheus = count(&|netuid| Prometheus::<T>::contains_key(netuid, &old_hotkey));
+        let weight_commits = count(&|netuid| WeightCommits::<T>::contains_key(netuid, &old_hotkey));
+        let child_keys = count(&|netuid| !ChildKeys::<T>::get(&old_hotkey, netuid).is_empty());
+        let parent_keys = count(&|netuid| !ParentKeys::<T>::get(&old_hotkey, netuid).is_empty());
+        let pending_child_keys =
+            count(&|netuid| PendingChildKeys::<T>::contains_key(&old_hotkey, netuid));
+
+        let error = Self::swap_error(|| {
+            Self::do_swap_hotkey(
+                frame_system::RawOrigin::Signed(coldkey.clone()).into(),
+                &old_hotkey,
+                &new_hotkey,
+            )
+            .map(|_| ())
+            .map_err(|e| e.error)
+        });
+
+        Some(HotkeySwapPreview {
+            swap_cost: Self::get_key_swap_cost().into(),
+            stake_moved: TotalHotkeyStake::<T>::get(&old_hotkey).into(),
+            stake_entries: (Stake::<T>::iter_prefix(&old_hotkey).count() as u32).into(),
+            subnets: subnets.into(),
+            axons: axons.into(),
+            prometheus: prometheus.into(),
+            weight_commits: weight_commits.into(),
+            child_keys: child_keys.into(),
+            parent_keys: parent_keys.into(),
+            pending_child_keys: pending_child_keys.into(),
+            is_delegate: Delegates::<T>::contains_key(&old_hotkey),
+            is_senate_member: T::SenateMembers::is_member(&old_hotkey),
+            error,
+            coldkey,
+            old_hotkey,
+            new_hotkey,
+        })
+    }
+
+    /// Previews a coldkey swap without applying it.
+    ///
+    /// The swap is run and rolled back to find the error it would fail with, if any.
+    pub fn get_coldkey_swap_preview(
+        old_coldkey_account_vec: Vec<u8>,
+        new_coldkey_account_vec: Vec<u8>,
+    ) -> Option<ColdkeySwapPreview<T>> {
+        if old_coldkey_account_vec.len() != 32 || new_coldkey_account_vec.len() != 32 {
+            return None; // Invalid account
+        }
+
+        let old_coldkey = T::AccountId::decode(&mut old_coldkey_account_vec.as_bytes_ref()).ok()?;
+        let new_coldkey = T::AccountId::decode(&mut new_coldkey_account_vec.as_bytes_ref()).ok()?;
+
+        let swap_cost: u64 = Self::get_key_swap_cost();
+        let stake_entries = StakingHotkeys::<T>::get(&old_coldkey)
+            .iter()
+            .filter(|hotkey| Stake::<T>::contains_key(hotkey, &old_coldkey))
+            .count() as u32;
+        let subnets_owned = Self::get_all_subnet_netuids()
+            .into_iter()
+            .filter(|netuid| SubnetOwner::<T>::get(netuid) == old_coldkey)
+            .count() as u32;
+
+        let error = Self::swap_error(|| {
+            Self::do_swap_coldkey(&old_coldkey, &new_coldkey)
+                .map(|_| ())
+                .map_err(|e| e.error)
+        });
+
+        Some(ColdkeySwapPreview {
+            swap_cost: swap_cost.into(),
+            stake_moved: TotalColdkeyStake::<T>::get(&old_coldkey).into(),
+            balance_moved: Self::get_coldkey_balance(&old_coldkey)
+                .saturating_sub(swap_cost)
+                .into(),
+            stake_entries: stake_entries.into(),
+            owned_hotkeys: (OwnedHotkeys::<T>::get(&old_coldkey).len() as u32).into(),
+            subnets_owned: subnets_owned.into(),
+            has_identity: Identities::<T>::contains_key(&old_coldkey),
+            error,
+            old_coldkey,
+            new_coldkey,
+        })
+    }
+
+    /// Runs a swap in a storage transaction which is always rolled back, and returns the name of
+    /// the error the swap failed with.
+    fn swap_error(swap: impl FnOnce() -> DispatchResult) -> Option<Vec<u8>> {
+        let result: Result<DispatchResult, DispatchError> =
+            with_transaction(|| TransactionOutcome::Rollback(Ok(swap())));
+        match result {
+            Ok(Ok(())) => None,
+            Ok(Err(error)) | Err(error) => {
+                let name: &'static str = error.into();
+                Some(name.as_bytes().to_vec())
+            }
+        }
+    }
+}
@@ -30,6 +30,9 @@ impl<T: Config> Pallet<T> {
     /// * 'TxRateLimitExceeded':
     ///     -  Thrown if key has hit transaction rate limit
     ///
+    /// * 'KeySwapInProgress':
+    ///     -  Thrown if the coldkey or hotkey is locked by a key swap in progress.
+    ///
     pub fn do_add_stake(
         origin: T::RuntimeOrigin,
         hotkey: T::AccountId,
@@ -44,6 +47,12 @@ impl<T: Config> Pallet<T> {
             stake_to_be_added
         );
 
+        // Ensure neither key is being swapped.
+        ensure!(
+            !Self::is_key_swapping(&coldkey) && !Self::is_key_swapping(&hotkey),
+            Error::<T>::KeySwapInProgress
+        );
+
         // Ensure the callers coldkey has enough stake to perform the transaction.
         ensure!(
             Self::can_remove_balance_from_coldkey_account(&coldkey, stake_to_be_added),
@@ -70,8 +79,11 @@ impl<T: Config> Pallet<T> {
             Error::<T>::StakeRateLimitExceeded
         );
 
-        // Set the last time the stake increased for nominator drain protection.
-        LastAddStakeIncrease::<T>::insert(&hotkey, &coldkey, Self::get_current_block_as_u64());
+        // Set the last time the stake increased for nominator drain protection, unless the stake
+        // was unstaked during a take notice period and cannot be paid twice.
+        if !Self::take_notice_restake(&coldkey, &hotkey, stake_to_be_added) {
+            LastAddStakeIncrease::<T>::insert(&hotkey, &coldkey, Self::get_current_block_as_u64());
+        }
 
         // If coldkey is not owner of the hotkey, it's a nomination stake.
         if !Self::coldkey_owns_hotkey(&coldkey, &hotkey) {
@@ -54,8 +54,9 @@ impl<T: Config> Pallet<T> {
         let min_take = MinDelegateTake::<T>::get();
         ensure!(take >= min_take, Error::<T>::DelegateTakeTooLow);
 
-        // --- 4. Set the new take value.
+        // --- 4. Set the new take value, dropping any increase announced before.
         Delegates::<T>::insert(hotkey.clone(), take);
+        Self::cancel_pending_delegate_take(&hotkey);
 
         // --- 5. Emit the take value.
         log::debug!(
@@ -249,6 +249,7 @@ impl<T: Config> Pallet<T> {
     }
 
     // Decreases the stake on the cold - hot pairing by the decrement while decreasing other counters.
+    // The reward payout of the pairing is dropped once its stake is gone.
     //
     pub fn decrease_stake_on_coldkey_hotkey_account(
         coldkey: &T::AccountId,
@@ -260,11 +261,11 @@ impl<T: Config> Pallet<T> {
             hotkey,
             TotalHotkeyStake::<T>::get(hotkey).saturating_sub(decrement),
         );
-        Stake::<T>::insert(
-            hotkey,
-            coldkey,
-            Stake::<T>::get(hotkey, coldkey).saturating_sub(decrement),
-        );
+        let new_stake: u64 = Stake::<T>::get(hotkey, coldkey).saturating_sub(decrement);
+        Stake::<T>::insert(hotkey, coldkey, new_stake);
+        if new_stake == 0 {
+            RewardPayouts::<T>::remove(hotkey, coldkey);
+        }
         TotalStake::<T>::put(TotalStake::<T>::get().saturating_sub(decrement));
 
         // TODO: Tech debt: Remove StakingHotkeys entry if stake goes to 0
@@ -290,6 +291,7 @@ impl<T: Config> Pallet<T> {
         TotalColdkeyStake::<T>::mutate(coldkey, |old| *old = old.saturating_sub(current_stake));
         TotalHotkeyStake::<T>::mutate(hotkey, |stake| *stake = stake.saturating_sub(current_stake));
         Stake::<T>::remove(hotkey, coldkey);
+        RewardPayouts::<T>::remove(hotkey, coldkey);
         TotalStake::<T>::mutate(|stake| *stake = stake.saturating_sub(current_stake));
 
         // Update StakingHotkeys map
@@ -313,8 +315,8 @@ impl<T: Config> Pallet<T> {
                 // Remove the stake from the nominator account. (this is a more forceful unstake operation which )
                 // Actually deletes the staking account.
                 let cleared_stake = Self::empty_stake_on_coldkey_hotkey_account(coldkey, hotkey);
-                // Add the stake to the coldkey account.
-                Self::add_balance_to_coldkey_account(coldkey, cleared_stake);
+                // Credit the stake to the coldkey, unbonding it like any other removed stake.
+                Self::credit_removed_stake(coldkey, hotkey, cleared_stake);
             }
         }
     }
@@ -429,8 +431,8 @@ impl<T: Config> Pallet<T> {
             // Remove the stake from the coldkey - hotkey pairing.
             Self::decrease_stake_on_coldkey_hotkey_account(&delegate_coldkey_i, hotkey, stake_i);
 
-            // Add the balance to the coldkey account.
-            Self::add_balance_to_coldkey_account(&delegate_coldkey_i, stake_i);
+            // Credit the stake to the coldkey, unbonding it like any other removed stake.
+            Self::credit_removed_stake(&delegate_coldkey_i, hotkey, stake_i);
         }
     }
 }
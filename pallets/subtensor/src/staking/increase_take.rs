@@ -17,6 +17,10 @@ impl<T: Config> Pallet<T> {
     /// * TakeIncreased;
     ///     - On successfully setting a increased take for this hotkey.
     ///
+    /// * TakeIncreaseScheduled;
+    ///     - On announcing the increased take, if a notice period is set. The take applies once
+    ///       the notice period has passed and replaces any increase already announced.
+    ///
     /// # Raises:
     /// * 'NotRegistered':
     ///     - The hotkey we are delegating is not registered on the network.
@@ -70,19 +74,145 @@ impl<T: Config> Pallet<T> {
         // Set last block for rate limiting
         Self::set_last_tx_block_delegate_take(&coldkey, block);
 
-        // --- 6. Set the new take value.
-        Delegates::<T>::insert(hotkey.clone(), take);
+        // --- 6. Set the new take value, or announce it if nominators are given notice.
+        let notice_period: u64 = DelegateTakeNoticePeriod::<T>::get();
+        if notice_period == 0 {
+            Delegates::<T>::insert(hotkey.clone(), take);
 
-        // --- 7. Emit the take value.
-        log::debug!(
-            "TakeIncreased( coldkey:{:?}, hotkey:{:?}, take:{:?} )",
-            coldkey,
-            hotkey,
-            take
-        );
-        Self::deposit_event(Event::TakeIncreased(coldkey, hotkey, take));
+            // --- 7. Emit the take value.
+            log::debug!(
+                "TakeIncreased( coldkey:{:?}, hotkey:{:?}, take:{:?} )",
+                coldkey,
+                hotkey,
+                take
+            );
+            Self::deposit_event(Event::TakeIncreased(coldkey, hotkey, take));
+        } else {
+            let effective_block: u64 = block.saturating_add(notice_period);
+            Self::schedule_pending_delegate_take(&hotkey, take, effective_block);
+
+            // --- 7. Emit the announced take value.
+            log::debug!(
+                "TakeIncreaseScheduled( coldkey:{:?}, hotkey:{:?}, take:{:?}, effective_block:{:?} )",
+                coldkey,
+                hotkey,
+                take,
+                effective_block
+            );
+            Self::deposit_event(Event::TakeIncreaseScheduled {
+                coldkey,
+                hotkey,
+                take,
+                effective_block,
+            });
+        }
 
         // --- 8. Ok and return.
         Ok(())
     }
+
+    /// Applies the announced take increases whose notice period ends at this block.
+    ///
+    /// The take is capped at the current `MaxDelegateTake`, which may have been lowered during
+    /// the notice period.
+    pub fn enact_pending_delegate_takes(block_number: u64) {
+        for hotkey in PendingDelegateTakesByBlock::<T>::take(block_number) {
+            // The increase may have been replaced or dropped since it was indexed.
+            let Some((take, effective_block)) = PendingDelegateTakes::<T>::get(&hotkey) else {
+                continue;
+            };
+            if effective_block > block_number {
+                continue;
+            }
+
+            PendingDelegateTakes::<T>::remove(&hotkey);
+            let take: u16 = take.min(MaxDelegateTake::<T>::get());
+            Delegates::<T>::insert(&hotkey, take);
+
+            let coldkey: T::AccountId = Owner::<T>::get(&hotkey);
+            log::debug!(
+                "TakeIncreased( coldkey:{:?}, hotkey:{:?}, take:{:?} )",
+                coldkey,
+                hotkey,
+                take
+            );
+            Self::deposit_event(Event::TakeIncreased(coldkey, hotkey, take));
+        }
+    }
+
+    /// Announces a take increase of a hotkey, replacing any increase announced before.
+    pub fn schedule_pending_delegate_take(hotkey: &T::AccountId, take: u16, effective_block: u64) {
+        Self::cancel_pending_delegate_take(hotkey);
+        PendingDelegateTakes::<T>::insert(hotkey, (take, effective_block));
+        PendingDelegateTakesByBlock::<T>::append(effective_block, hotkey.clone());
+    }
+
+    /// Drops the announced take increase of a hotkey, returning it as `(take, effective_block)`.
+    pub fn cancel_pending_delegate_take(hotkey: &T::AccountId) -> Option<(u16, u64)> {
+        let pending = PendingDelegateTakes::<T>::take(hotkey);
+        if let Some((_, effective_block)) = pending {
+            PendingDelegateTakesByBlock::<T>::mutate_exists(effective_block, |hotkeys| {
+                if let Some(indexed) = hotkeys {
+                    indexed.retain(|indexed_hotkey| indexed_hotkey != hotkey);
+                    if indexed.is_empty() {
+                        *hotkeys = None;
+                    }
+                }
+            });
+        }
+        pending
+    }
+
+    /// Records stake unstaked from a hotkey with an announced take increase.
+    ///
+    /// Until the increase applies, the coldkey can stake the amount again on a hotkey which has
+    /// drained no earlier than this one without waiting for a drain, see `take_notice_restake`.
+    /// The stake cannot be paid twice, since the emission it earned on this hotkey since its last
+    /// drain is forfeited by unstaking.
+    pub fn record_take_notice_unstake(coldkey: &T::AccountId, hotkey: &T::AccountId, amount: u64) {
+        let Some((_, effective_block)) = PendingDelegateTakes::<T>::get(hotkey) else {
+            return;
+        };
+        let last_drain: u64 = LastHotkeyEmissionDrain::<T>::get(hotkey);
+        TakeNoticeUnstakes::<T>::mutate(coldkey, |unstaked| {
+            *unstaked = Some(match unstaked.take() {
+                Some((unstaked_amount, unstaked_drain, expiry_block)) => (
+                    unstaked_amount.saturating_add(amount),
+                    unstaked_drain.max(last_drain),
+                    expiry_block.max(effective_block),
+                ),
+                None => (amount, last_drain, effective_block),
+            })
+        });
+    }
+
+    /// Returns true if stake added to a hotkey is stake unstaked during a take notice period,
+    /// which then keeps its emission eligibility. The amount is used up from the record.
+    pub fn take_notice_restake(coldkey: &T::AccountId, hotkey: &T::AccountId, amount: u64) -> bool {
+        let Some((unstaked_amount, last_drain, expiry_block)) =
+            TakeNoticeUnstakes::<T>::get(coldkey)
+        else {
+            return false;
+        };
+        if Self::get_current_block_as_u64() > expiry_block {
+            TakeNoticeUnstakes::<T>::remove(coldkey);
+            return false;
+        }
+        if amount > unstaked_amount || LastHotkeyEmissionDrain::<T>::get(hotkey) < last_drain {
+            return false;
+        }
+
+        let remaining: u64 = unstaked_amount.saturating_sub(amount);
+        if remaining == 0 {
+            TakeNoticeUnstakes::<T>::remove(coldkey);
+        } else {
+            TakeNoticeUnstakes::<T>::insert(coldkey, (remaining, last_drain, expiry_block));
+        }
+        true
+    }
+
+    /// Returns the announced take increase of a hotkey as `(take, effective_block)`.
+    pub fn get_pending_delegate_take(hotkey: &T::AccountId) -> Option<(u16, u64)> {
+        PendingDelegateTakes::<T>::get(hotkey)
+    }
 }
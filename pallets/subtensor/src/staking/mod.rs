@@ -4,5 +4,9 @@ pub mod become_delegate;
 pub mod decrease_take;
 pub mod helpers;
 pub mod increase_take;
+pub mod move_stake;
 pub mod remove_stake;
 pub mod set_children;
+pub mod set_reward_payout;
+pub mod transfer_stake;
+pub mod withdraw_unbonded;
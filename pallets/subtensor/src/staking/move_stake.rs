@@ -0,0 +1,173 @@
+use super::*;
+
+impl<T: Config> Pallet<T> {
+    /// ---- The implementation for the extrinsic move_stake: Moves stake between two hotkeys of the same coldkey.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     -  The signature of the caller's coldkey.
+    ///
+    /// * 'origin_hotkey' (T::AccountId):
+    ///     -  The hotkey the stake is moved away from.
+    ///
+    /// * 'destination_hotkey' (T::AccountId):
+    ///     -  The hotkey the stake is moved onto.
+    ///
+    /// * 'amount' (u64):
+    ///     -  The amount of stake to move.
+    ///
+    /// # Event:
+    /// * StakeMoved;
+    ///     -  On successfully moving the stake.
+    ///
+    /// # Raises:
+    /// * 'CannotMoveStakeToSameHotkey':
+    ///     -  Thrown if the origin and destination hotkeys are the same.
+    ///
+    /// * 'HotKeyAccountNotExists':
+    ///     -  Thrown if either hotkey does not exist.
+    ///
+    /// * 'HotKeyNotDelegateAndSignerNotOwnHotKey':
+    ///     -  Thrown if either hotkey is neither a delegate nor owned by the coldkey.
+    ///
+    /// * 'StakeToWithdrawIsZero':
+    ///     -  Thrown if the amount is zero.
+    ///
+    /// * 'NotEnoughStakeToWithdraw':
+    ///     -  Thrown if the coldkey does not have enough stake on the origin hotkey.
+    ///
+    /// * 'NomStakeBelowMinimumThreshold':
+    ///     -  Thrown if the resulting nomination on the destination is below the minimum.
+    ///
+    /// * 'StakeRateLimitExceeded':
+    ///     -  Thrown if the coldkey has hit the stake rate limit on the destination hotkey.
+    ///
+    /// * 'KeySwapInProgress':
+    ///     -  Thrown if the coldkey or either hotkey is locked by a key swap in progress.
+    ///
+    pub fn do_move_stake(
+        origin: T::RuntimeOrigin,
+        origin_hotkey: T::AccountId,
+        destination_hotkey: T::AccountId,
+        amount: u64,
+    ) -> dispatch::DispatchResult {
+        // We check the transaction is signed by the caller and retrieve the T::AccountId coldkey information.
+        let coldkey = ensure_signed(origin)?;
+        log::debug!(
+            "do_move_stake( origin:{:?} origin_hotkey:{:?}, destination_hotkey:{:?}, amount:{:?} )",
+            coldkey,
+            origin_hotkey,
+            destination_hotkey,
+            amount
+        );
+
+        // Ensure the stake actually moves somewhere.
+        ensure!(
+            origin_hotkey != destination_hotkey,
+            Error::<T>::CannotMoveStakeToSameHotkey
+        );
+
+        // Ensure none of the keys is being swapped.
+        ensure!(
+            !Self::is_key_swapping(&coldkey)
+                && !Self::is_key_swapping(&origin_hotkey)
+                && !Self::is_key_swapping(&destination_hotkey),
+            Error::<T>::KeySwapInProgress
+        );
+
+        // Ensure that both hotkey accounts exist.
+        ensure!(
+            Self::hotkey_account_exists(&origin_hotkey)
+                && Self::hotkey_account_exists(&destination_hotkey),
+            Error::<T>::HotKeyAccountNotExists
+        );
+
+        // Ensure that both hotkeys allow delegation or are owned by the calling coldkey.
+        ensure!(
+            Self::hotkey_is_delegate(&origin_hotkey)
+                || Self::coldkey_owns_hotkey(&coldkey, &origin_hotkey),
+            Error::<T>::HotKeyNotDelegateAndSignerNotOwnHotKey
+        );
+        ensure!(
+            Self::hotkey_is_delegate(&destination_hotkey)
+                || Self::coldkey_owns_hotkey(&coldkey, &destination_hotkey),
+            Error::<T>::HotKeyNotDelegateAndSignerNotOwnHotKey
+        );
+
+        // Ensure that the amount is above zero and available on the origin hotkey.
+        ensure!(amount > 0, Error::<T>::StakeToWithdrawIsZero);
+        ensure!(
+            Self::has_enough_stake(&coldkey, &origin_hotkey, amount),
+            Error::<T>::NotEnoughStakeToWithdraw
+        );
+
+        // If coldkey is not owner of the destination, it's a nomination stake.
+        if !Self::coldkey_owns_hotkey(&coldkey, &destination_hotkey) {
+            let total_stake_after_move =
+                Stake::<T>::get(&destination_hotkey, &coldkey).saturating_add(amount);
+            ensure!(
+                total_stake_after_move >= NominatorMinRequiredStake::<T>::get(),
+                Error::<T>::NomStakeBelowMinimumThreshold
+            );
+        }
+
+        // A move counts as a single stake operation on the destination.
+        let stakes_this_interval =
+            Self::get_stakes_this_interval_for_coldkey_hotkey(&coldkey, &destination_hotkey);
+        ensure!(
+            stakes_this_interval < Self::get_target_stakes_per_interval(),
+            Error::<T>::StakeRateLimitExceeded
+        );
+
+        // The moved stake keeps its emission eligibility if the destination has drained no earlier
+        // than the origin, since it cannot then be paid twice for the same blocks, carrying over
+        // the later of the two stake increases. Otherwise it is treated as freshly added stake,
+        // including when leaving a hotkey which has announced a take increase.
+        let block: u64 = Self::get_current_block_as_u64();
+        if LastHotkeyEmissionDrain::<T>::get(&destination_hotkey)
+            >= LastHotkeyEmissionDrain::<T>::get(&origin_hotkey)
+        {
+            let origin_last_increase = LastAddStakeIncrease::<T>::get(&origin_hotkey, &coldkey);
+            LastAddStakeIncrease::<T>::mutate(&destination_hotkey, &coldkey, |last| {
+                *last = (*last).max(origin_last_increase)
+            });
+        } else {
+            LastAddStakeIncrease::<T>::insert(&destination_hotkey, &coldkey, block);
+        }
+
+        // Move the stake without passing through the free balance.
+        Self::decrease_stake_on_coldkey_hotkey_account(&coldkey, &origin_hotkey, amount);
+        Self::increase_stake_on_coldkey_hotkey_account(&coldkey, &destination_hotkey, amount);
+
+        // If the remaining stake on the origin is below the minimum, we clear the nomination from storage.
+        let remaining_stake = Self::get_stake_for_coldkey_and_hotkey(&coldkey, &origin_hotkey);
+        Self::clear_small_nomination_if_required(&origin_hotkey, &coldkey, remaining_stake);
+
+        // Set last block for rate limiting
+        Self::set_last_tx_block(&coldkey, block);
+        Self::set_stakes_this_interval_for_coldkey_hotkey(
+            &coldkey,
+            &destination_hotkey,
+            stakes_this_interval.saturating_add(1),
+            block,
+        );
+
+        // Emit the move event.
+        log::debug!(
+            "StakeMoved( coldkey:{:?}, origin_hotkey:{:?}, destination_hotkey:{:?}, amount:{:?} )",
+            coldkey,
+            origin_hotkey,
+            destination_hotkey,
+            amount
+        );
+        Self::deposit_event(Event::StakeMoved {
+            coldkey,
+            origin_hotkey,
+            destination_hotkey,
+            amount,
+        });
+
+        // Done and ok.
+        Ok(())
+    }
+}
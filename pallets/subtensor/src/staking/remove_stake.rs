@@ -30,6 +30,12 @@ impl<T: Config> Pallet<T> {
     /// * 'TxRateLimitExceeded':
     ///     -  Thrown if key has hit transaction rate limit
     ///
+    /// * 'KeySwapInProgress':
+    ///     -  Thrown if the coldkey or hotkey is locked by a key swap in progress.
+    ///
+    /// * 'UnbondingQueueFull':
+    ///     -  Thrown if the stake has to unbond and the unbonding queue of the coldkey is full.
+    ///
     pub fn do_remove_stake(
         origin: T::RuntimeOrigin,
         hotkey: T::AccountId,
@@ -44,6 +50,12 @@ impl<T: Config> Pallet<T> {
             stake_to_be_removed
         );
 
+        // Ensure neither key is being swapped.
+        ensure!(
+            !Self::is_key_swapping(&coldkey) && !Self::is_key_swapping(&hotkey),
+            Error::<T>::KeySwapInProgress
+        );
+
         // Ensure that the hotkey account exists this is only possible through registration.
         ensure!(
             Self::hotkey_account_exists(&hotkey),
@@ -73,11 +85,20 @@ impl<T: Config> Pallet<T> {
             Error::<T>::UnstakeRateLimitExceeded
         );
 
+        // Ensure the removed stake can be queued if it has to unbond.
+        ensure!(
+            Self::can_unbond_stake(&coldkey),
+            Error::<T>::UnbondingQueueFull
+        );
+
+        // Stake leaving a hotkey with an announced take increase may be staked again without penalty.
+        Self::record_take_notice_unstake(&coldkey, &hotkey, stake_to_be_removed);
+
         // We remove the balance from the hotkey.
         Self::decrease_stake_on_coldkey_hotkey_account(&coldkey, &hotkey, stake_to_be_removed);
 
-        // We add the balance to the coldkey.  If the above fails we will not credit this coldkey.
-        Self::add_balance_to_coldkey_account(&coldkey, stake_to_be_removed);
+        // We add the balance to the coldkey, or queue it while it unbonds.  If the above fails we will not credit this coldkey.
+        Self::credit_removed_stake(&coldkey, &hotkey, stake_to_be_removed);
 
         // If the stake is below the minimum, we clear the nomination from storage.
         // This only applies to nominator stakes.
@@ -19,8 +19,10 @@ impl<T: Config> Pallet<T> {
     ///     - A list of children with their proportions.
     ///
     /// # Events:
-    /// * `ChildrenAdded`:
-    ///     - On successfully registering children to a hotkey.
+    /// * `SetChildren`:
+    ///     - On successfully registering children to a hotkey, if no consent is needed.
+    /// * `ChildrenProposed`:
+    ///     - On proposing children whose coldkeys must accept with `accept_child`.
     ///
     /// # Errors:
     /// * `SubNetworkDoesNotExist`:
@@ -31,6 +33,12 @@ impl<T: Config> Pallet<T> {
     ///     - The coldkey does not own the hotkey or the child is the same as the hotkey.
     /// * `HotKeyAccountNotExists`:
     ///     - The hotkey account does not exist.
+    /// * `TooManyChildren`:
+    ///     - More children than the subnet maximum.
+    /// * `ChildProportionTooLow`:
+    ///     - A child proportion is below the subnet minimum.
+    /// * `KeySwapInProgress`:
+    ///     - The hotkey or a child is locked by a key swap in progress.
     ///
     /// # Detailed Explanation of Checks:
     /// 1. **Signature Verification**: Ensures that the caller has signed the transaction, verifying the coldkey.
@@ -39,8 +47,11 @@ impl<T: Config> Pallet<T> {
     /// 4. **Ownership Verification**: Ensures that the coldkey owns the hotkey.
     /// 5. **Hotkey Account Existence Check**: Ensures that the hotkey account already exists.
     /// 6. **Child-Hotkey Distinction**: Ensures that the child is not the same as the hotkey.
-    /// 7. **Old Children Cleanup**: Removes the hotkey from the parent list of its old children.
-    /// 8. **New Children Assignment**: Assigns the new child to the hotkey and updates the parent list for the new child.
+    /// 7. **Consent**: Children owned by another coldkey must accept before anything changes.
+    ///    Children owned by the caller are accepted with the proposal. Children without an owner
+    ///    wait for the coldkey which registers them to accept.
+    /// 8. **Old Children Cleanup**: Removes the hotkey from the parent list of its old children.
+    /// 9. **New Children Assignment**: Assigns the new child to the hotkey and updates the parent list for the new child.
     ///
     pub fn do_set_children(
         origin: T::RuntimeOrigin,
@@ -95,8 +106,8 @@ impl<T: Config> Pallet<T> {
             Error::<T>::NonAssociatedColdKey
         );
 
-        // --- 4.1. Ensure that the number of children does not exceed 5.
-        ensure!(children.len() <= 5, Error::<T>::TooManyChildren);
+        // --- 4.1. Ensure no key is being swapped and the children fit the subnet limits.
+        Self::ensure_children_can_be_set(&hotkey, netuid, &children)?;
 
         // --- 5. Ensure that each child is not the hotkey.
         for (_, child_i) in &children {
@@ -118,53 +129,403 @@ impl<T: Config> Pallet<T> {
             unique_children.push(child_i.clone());
         }
 
-        // --- 6. Erase myself from old children's parents.
-        let old_children: Vec<(u64, T::AccountId)> = ChildKeys::<T>::get(hotkey.clone(), netuid);
+        // --- 6. Propose the children. Those owned by the caller are accepted with the proposal.
+        let accepted: Vec<T::AccountId> = children
+            .iter()
+            .map(|(_, child_i)| child_i.clone())
+            .filter(|child_i| Self::coldkey_owns_hotkey(&coldkey, child_i))
+            .collect();
+        // The proposal lasts at least until the next block, where expired proposals are dropped.
+        let expires_at: u64 =
+            current_block.saturating_add(ChildkeyProposalExpiry::<T>::get().max(1));
+        let proposal = ChildkeyProposal {
+            children: children.clone(),
+            accepted,
+            expires_at,
+        };
+
+        // --- 7. Set the children now if no consent is needed, or wait for it.
+        if !Self::store_childkey_proposal(&hotkey, netuid, proposal) {
+            log::trace!(
+                "ChildrenProposed( hotkey:{:?}, netuid:{:?}, children:{:?}, expires_at:{:?} )",
+                hotkey,
+                netuid,
+                children,
+                expires_at
+            );
+            Self::deposit_event(Event::ChildrenProposed {
+                hotkey,
+                netuid,
+                children,
+                expires_at,
+            });
+        }
+
+        // Ok and return.
+        Ok(())
+    }
+
+    /// ---- The implementation for the extrinsic accept_child: Accepts a hotkey as the child of a parent.
+    ///
+    /// # Arguments:
+    /// * `origin` (<T as frame_system::Config>::RuntimeOrigin):
+    ///     - The signature of the coldkey owning the child.
+    ///
+    /// * `parent` (T::AccountId):
+    ///     - The hotkey which proposed the child.
+    ///
+    /// * `child` (T::AccountId):
+    ///     - The accepting child hotkey.
+    ///
+    /// * `netuid` (u16):
+    ///     - The u16 network identifier of the proposal.
+    ///
+    /// # Events:
+    /// * `ChildAccepted`:
+    ///     - On successfully accepting the proposal.
+    /// * `SetChildren`:
+    ///     - Once every proposed child has accepted.
+    ///
+    /// # Errors:
+    /// * `NonAssociatedColdKey`:
+    ///     - The coldkey does not own the child.
+    /// * `ChildkeyProposalNotFound`:
+    ///     - The parent has no children proposed on this network.
+    /// * `ChildNotProposed`:
+    ///     - The child is not among the proposed children.
+    /// * `ChildAlreadyAccepted`:
+    ///     - The child has already accepted the proposal.
+    ///
+    /// The proposal is dropped with a `ChildkeyProposalDropped` event if, once every child has
+    /// accepted, a key is being swapped or the children no longer fit the subnet limits.
+    ///
+    pub fn do_accept_child(
+        origin: T::RuntimeOrigin,
+        parent: T::AccountId,
+        child: T::AccountId,
+        netuid: u16,
+    ) -> DispatchResult {
+        // --- 1. Check that the caller owns the child.
+        let coldkey = ensure_signed(origin)?;
+        ensure!(
+            Self::coldkey_owns_hotkey(&coldkey, &child),
+            Error::<T>::NonAssociatedColdKey
+        );
+
+        // --- 2. Check that the child is proposed and has not accepted yet.
+        let mut proposal = PendingChildKeys::<T>::get(&parent, netuid)
+            .ok_or(Error::<T>::ChildkeyProposalNotFound)?;
+        ensure!(
+            proposal
+                .children
+                .iter()
+                .any(|(_, child_i)| *child_i == child),
+            Error::<T>::ChildNotProposed
+        );
+        ensure!(
+            !proposal.accepted.contains(&child),
+            Error::<T>::ChildAlreadyAccepted
+        );
+
+        // --- 3. Record the consent, setting the children if it was the last one missing.
+        proposal.accepted.push(child.clone());
+        log::trace!(
+            "ChildAccepted( parent:{:?}, child:{:?}, netuid:{:?} )",
+            parent,
+            child,
+            netuid
+        );
+        Self::deposit_event(Event::ChildAccepted {
+            parent: parent.clone(),
+            child,
+            netuid,
+        });
+
+        // --- 4. Drop the proposal instead if its children can no longer be set, the subnet limits
+        // or the key swaps may have changed since it was proposed.
+        let all_accepted: bool = proposal
+            .children
+            .iter()
+            .all(|(_, child_i)| proposal.accepted.contains(child_i));
+        if all_accepted {
+            if let Err(error) =
+                Self::ensure_children_can_be_set(&parent, netuid, &proposal.children)
+            {
+                Self::remove_childkey_proposal(&parent, netuid);
+                log::trace!(
+                    "ChildkeyProposalDropped( hotkey:{:?}, netuid:{:?}, error:{:?} )",
+                    parent,
+                    netuid,
+                    error
+                );
+                Self::deposit_event(Event::ChildkeyProposalDropped {
+                    hotkey: parent,
+                    netuid,
+                    error,
+                });
+                return Ok(());
+            }
+        }
+        Self::store_childkey_proposal(&parent, netuid, proposal);
+
+        // Ok and return.
+        Ok(())
+    }
+
+    /// ---- The implementation for the extrinsic reject_child: Rejects a hotkey as the child of a parent.
+    ///
+    /// The whole proposal is dropped, the parent keeps its current children.
+    ///
+    /// # Arguments:
+    /// * `origin` (<T as frame_system::Config>::RuntimeOrigin):
+    ///     - The signature of the coldkey owning the child.
+    ///
+    /// * `parent` (T::AccountId):
+    ///     - The hotkey which proposed the child.
+    ///
+    /// * `child` (T::AccountId):
+    ///     - The rejecting child hotkey.
+    ///
+    /// * `netuid` (u16):
+    ///     - The u16 network identifier of the proposal.
+    ///
+    /// # Events:
+    /// * `ChildRejected`:
+    ///     - On successfully rejecting the proposal.
+    ///
+    /// # Errors:
+    /// * `NonAssociatedColdKey`:
+    ///     - The coldkey does not own the child.
+    /// * `ChildkeyProposalNotFound`:
+    ///     - The parent has no children proposed on this network.
+    /// * `ChildNotProposed`:
+    ///     - The child is not among the proposed children.
+    ///
+    pub fn do_reject_child(
+        origin: T::RuntimeOrigin,
+        parent: T::AccountId,
+        child: T::AccountId,
+        netuid: u16,
+    ) -> DispatchResult {
+        // --- 1. Check that the caller owns the child.
+        let coldkey = ensure_signed(origin)?;
+        ensure!(
+            Self::coldkey_owns_hotkey(&coldkey, &child),
+            Error::<T>::NonAssociatedColdKey
+        );
+
+        // --- 2. Check that the child is proposed.
+        let proposal = PendingChildKeys::<T>::get(&parent, netuid)
+            .ok_or(Error::<T>::ChildkeyProposalNotFound)?;
+        ensure!(
+            proposal
+                .children
+                .iter()
+                .any(|(_, child_i)| *child_i == child),
+            Error::<T>::ChildNotProposed
+        );
+
+        // --- 3. Drop the proposal.
+        Self::remove_childkey_proposal(&parent, netuid);
+        log::trace!(
+            "ChildRejected( parent:{:?}, child:{:?}, netuid:{:?} )",
+            parent,
+            child,
+            netuid
+        );
+        Self::deposit_event(Event::ChildRejected {
+            parent,
+            child,
+            netuid,
+        });
+
+        // Ok and return.
+        Ok(())
+    }
+
+    /// Ensures neither the hotkey nor its children are being swapped, and that the children fit
+    /// the maximum number of children and the minimum child proportion of the subnet.
+    fn ensure_children_can_be_set(
+        hotkey: &T::AccountId,
+        netuid: u16,
+        children: &[(u64, T::AccountId)],
+    ) -> DispatchResult {
+        ensure!(
+            !Self::is_key_swapping(hotkey)
+                && children
+                    .iter()
+                    .all(|(_, child)| !Self::is_key_swapping(child)),
+            Error::<T>::KeySwapInProgress
+        );
+        ensure!(
+            children.len() <= usize::from(Self::get_max_children(netuid)),
+            Error::<T>::TooManyChildren
+        );
+        let min_proportion = Self::get_min_child_proportion(netuid);
+        ensure!(
+            children
+                .iter()
+                .all(|(proportion, _)| *proportion >= min_proportion),
+            Error::<T>::ChildProportionTooLow
+        );
+        Ok(())
+    }
+
+    /// Sets the children of a proposal once all of them have accepted, otherwise stores it until they do.
+    ///
+    /// # Returns
+    /// * `bool` - True if the children were set.
+    fn store_childkey_proposal(
+        hotkey: &T::AccountId,
+        netuid: u16,
+        proposal: ChildkeyProposal<T::AccountId>,
+    ) -> bool {
+        let all_accepted: bool = proposal
+            .children
+            .iter()
+            .all(|(_, child_i)| proposal.accepted.contains(child_i));
+        if all_accepted {
+            Self::remove_childkey_proposal(hotkey, netuid);
+            Self::apply_children(hotkey, netuid, proposal.children);
+        } else {
+            Self::insert_childkey_proposal(hotkey, netuid, proposal);
+        }
+        all_accepted
+    }
+
+    /// Stores the children proposed by a hotkey, indexed by their expiry block and by each child.
+    ///
+    /// A proposal of the hotkey already stored on the network is replaced.
+    pub fn insert_childkey_proposal(
+        hotkey: &T::AccountId,
+        netuid: u16,
+        proposal: ChildkeyProposal<T::AccountId>,
+    ) {
+        Self::remove_childkey_proposal(hotkey, netuid);
+        PendingChildKeysByExpiry::<T>::append(proposal.expires_at, (hotkey.clone(), netuid));
+        for (_, child_i) in proposal.children.iter() {
+            PendingParentKeys::<T>::append(child_i, netuid, hotkey.clone());
+        }
+        PendingChildKeys::<T>::insert(hotkey, netuid, proposal);
+    }
+
+    /// Removes the children proposed by a hotkey along with their indexes.
+    ///
+    /// # Returns
+    /// * `Option<ChildkeyProposal>` - The removed proposal, if any.
+    pub fn remove_childkey_proposal(
+        hotkey: &T::AccountId,
+        netuid: u16,
+    ) -> Option<ChildkeyProposal<T::AccountId>> {
+        let proposal = PendingChildKeys::<T>::take(hotkey, netuid)?;
+        PendingChildKeysByExpiry::<T>::mutate_exists(proposal.expires_at, |proposals| {
+            if let Some(indexed) = proposals {
+                indexed.retain(|(parent, netuid_i)| parent != hotkey || *netuid_i != netuid);
+                if indexed.is_empty() {
+                    *proposals = None;
+                }
+            }
+        });
+        for (_, child_i) in proposal.children.iter() {
+            PendingParentKeys::<T>::mutate_exists(child_i, netuid, |parents| {
+                if let Some(indexed) = parents {
+                    indexed.retain(|parent| parent != hotkey);
+                    if indexed.is_empty() {
+                        *parents = None;
+                    }
+                }
+            });
+        }
+        Some(proposal)
+    }
 
-        // --- 6.0. Iterate over all my old children and remove myself from their parent's map.
-        for (_, old_child_i) in old_children.clone().iter() {
-            // --- 6.1. Get the old child's parents on this network.
+    /// Replaces the children of a hotkey and updates the parents of the old and new children.
+    fn apply_children(hotkey: &T::AccountId, netuid: u16, children: Vec<(u64, T::AccountId)>) {
+        // --- 1. Erase myself from old children's parents.
+        let old_children: Vec<(u64, T::AccountId)> = ChildKeys::<T>::get(hotkey, netuid);
+
+        // --- 1.0. Iterate over all my old children and remove myself from their parent's map.
+        for (_, old_child_i) in old_children.iter() {
+            // --- 1.1. Get the old child's parents on this network.
             let my_old_child_parents: Vec<(u64, T::AccountId)> =
                 ParentKeys::<T>::get(old_child_i.clone(), netuid);
 
-            // --- 6.2. Filter my hotkey from my old children's parents list.
+            // --- 1.2. Filter my hotkey from my old children's parents list.
             let filtered_parents: Vec<(u64, T::AccountId)> = my_old_child_parents
                 .into_iter()
-                .filter(|(_, parent)| *parent != hotkey)
+                .filter(|(_, parent)| parent != hotkey)
                 .collect();
 
-            // --- 6.3. Update the parent list in storage
+            // --- 1.3. Update the parent list in storage
             ParentKeys::<T>::insert(old_child_i, netuid, filtered_parents);
         }
 
-        // --- 7.1. Insert my new children + proportion list into the map.
-        ChildKeys::<T>::insert(hotkey.clone(), netuid, children.clone());
+        // --- 2.1. Insert my new children + proportion list into the map.
+        ChildKeys::<T>::insert(hotkey, netuid, children.clone());
 
-        // --- 7.2. Update the parents list for my new children.
-        for (proportion, new_child_i) in children.clone().iter() {
-            // --- 8.2.1. Get the child's parents on this network.
+        // --- 2.2. Update the parents list for my new children.
+        for (proportion, new_child_i) in children.iter() {
+            // --- 2.2.1. Get the child's parents on this network.
             let mut new_child_previous_parents: Vec<(u64, T::AccountId)> =
                 ParentKeys::<T>::get(new_child_i.clone(), netuid);
 
-            // --- 7.2.2. Append my hotkey and proportion to my new child's parents list.
+            // --- 2.2.2. Append my hotkey and proportion to my new child's parents list.
             // NOTE: There are no duplicates possible because I previously removed my self from my old children.
             new_child_previous_parents.push((*proportion, hotkey.clone()));
 
-            // --- 7.2.3. Update the parents list in storage.
+            // --- 2.2.3. Update the parents list in storage.
             ParentKeys::<T>::insert(new_child_i.clone(), netuid, new_child_previous_parents);
         }
 
-        // --- 8. Log and return.
+        // --- 3. Log the new children.
         log::trace!(
             "SetChildren( netuid:{:?}, hotkey:{:?}, children:{:?} )",
             hotkey,
             netuid,
             children.clone()
         );
-        Self::deposit_event(Event::SetChildren(hotkey.clone(), netuid, children.clone()));
+        Self::deposit_event(Event::SetChildren(hotkey.clone(), netuid, children));
+    }
 
-        // Ok and return.
-        Ok(())
+    /// Drops the children proposals which were not accepted in time.
+    pub fn remove_expired_childkey_proposals(block_number: u64) {
+        for (hotkey, netuid) in PendingChildKeysByExpiry::<T>::take(block_number) {
+            // The proposal may have been replaced or dropped since it was indexed.
+            let expired: bool = PendingChildKeys::<T>::get(&hotkey, netuid)
+                .is_some_and(|proposal| proposal.expires_at <= block_number);
+            if !expired {
+                continue;
+            }
+            Self::remove_childkey_proposal(&hotkey, netuid);
+            log::trace!(
+                "ChildkeyProposalExpired( hotkey:{:?}, netuid:{:?} )",
+                hotkey,
+                netuid
+            );
+            Self::deposit_event(Event::ChildkeyProposalExpired { hotkey, netuid });
+        }
+    }
+
+    /// Returns the children proposed by a hotkey on a network, awaiting consent.
+    pub fn get_pending_children(
+        hotkey: &T::AccountId,
+        netuid: u16,
+    ) -> Option<ChildkeyProposal<T::AccountId>> {
+        PendingChildKeys::<T>::get(hotkey, netuid)
+    }
+
+    /// Returns the proposals naming a hotkey as child, as `(parent, netuid, proposal)`.
+    pub fn get_pending_parents(
+        child: &T::AccountId,
+    ) -> Vec<(T::AccountId, u16, ChildkeyProposal<T::AccountId>)> {
+        PendingParentKeys::<T>::iter_prefix(child)
+            .flat_map(|(netuid, parents)| {
+                parents.into_iter().filter_map(move |parent| {
+                    let proposal = PendingChildKeys::<T>::get(&parent, netuid)?;
+                    Some((parent, netuid, proposal))
+                })
+            })
+            .collect()
     }
 
     /* Retrieves the list of children for a given hotkey and network.
@@ -225,7 +586,7 @@ impl<T: Config> Pallet<T> {
     /// * `NonAssociatedColdKey`:
     ///     - The coldkey does not own the hotkey.
     /// * `InvalidChildkeyTake`:
-    ///     - The provided take value is invalid (greater than the maximum allowed take).
+    ///     - The provided take value is outside the childkey take bounds of the subnet.
     /// * `TxChildkeyTakeRateLimitExceeded`:
     ///     - The rate limit for changing childkey take has been exceeded.
     pub fn do_set_childkey_take(
@@ -240,9 +601,10 @@ impl<T: Config> Pallet<T> {
             Error::<T>::NonAssociatedColdKey
         );
 
-        // Ensure the take value is valid
+        // Ensure the take value is within the bounds of the subnet
         ensure!(
-            take <= Self::get_max_childkey_take(),
+            take >= Self::get_subnet_min_childkey_take(netuid)
+                && take <= Self::get_subnet_max_childkey_take(netuid),
             Error::<T>::InvalidChildkeyTake
         );
 
@@ -290,6 +652,7 @@ impl<T: Config> Pallet<T> {
     ///
     /// This function retrieves the current childkey take value for a specified hotkey.
     /// If no specific take value has been set, it returns the default childkey take.
+    /// A take set before the subnet lowered its upper bound is capped at the new bound.
     ///
     /// # Arguments:
     /// * `hotkey` (&T::AccountId):
@@ -299,6 +662,6 @@ impl<T: Config> Pallet<T> {
     /// * `u16` - The childkey take value. This is a percentage represented as a value between 0 and 10000,
     ///           where 10000 represents 100%.
     pub fn get_childkey_take(hotkey: &T::AccountId, netuid: u16) -> u16 {
-        ChildkeyTake::<T>::get(hotkey, netuid)
+        ChildkeyTake::<T>::get(hotkey, netuid).min(Self::get_subnet_max_childkey_take(netuid))
     }
 }
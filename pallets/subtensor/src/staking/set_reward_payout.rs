@@ -0,0 +1,95 @@
+use super::*;
+use frame_support::traits::tokens::{fungible::Balanced as _, Precision};
+
+impl<T: Config> Pallet<T> {
+    /// ---- The implementation for the extrinsic set_reward_payout: Sets where the nominator emission of a coldkey on a hotkey is paid.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     -  The signature of the caller's coldkey.
+    ///
+    /// * 'hotkey' (T::AccountId):
+    ///     -  The hotkey the coldkey stakes on.
+    ///
+    /// * 'payout' (RewardPayout<T::AccountId>):
+    ///     -  Compound into the stake, pay to the free balance of the coldkey, or pay to another account.
+    ///
+    /// # Event:
+    /// * RewardPayoutSet;
+    ///     -  On successfully setting the payout.
+    ///
+    /// # Raises:
+    /// * 'NoStakeOnHotkey':
+    ///     -  Thrown if the coldkey has no stake on the hotkey.
+    ///
+    pub fn do_set_reward_payout(
+        origin: T::RuntimeOrigin,
+        hotkey: T::AccountId,
+        payout: RewardPayout<T::AccountId>,
+    ) -> dispatch::DispatchResult {
+        // We check the transaction is signed by the caller and retrieve the T::AccountId coldkey information.
+        let coldkey = ensure_signed(origin)?;
+
+        // Ensure the coldkey stakes on the hotkey.
+        ensure!(
+            Stake::<T>::get(&hotkey, &coldkey) > 0,
+            Error::<T>::NoStakeOnHotkey
+        );
+
+        // Compounding is the default, so it is not stored.
+        if payout == RewardPayout::Compound {
+            RewardPayouts::<T>::remove(&hotkey, &coldkey);
+        } else {
+            RewardPayouts::<T>::insert(&hotkey, &coldkey, payout.clone());
+        }
+
+        log::debug!(
+            "RewardPayoutSet( coldkey:{:?}, hotkey:{:?}, payout:{:?} )",
+            coldkey,
+            hotkey,
+            payout
+        );
+        Self::deposit_event(Event::RewardPayoutSet {
+            coldkey,
+            hotkey,
+            payout,
+        });
+
+        // Done and ok.
+        Ok(())
+    }
+
+    /// Pays the emission a nominator earned on a hotkey as set by its `RewardPayouts`.
+    ///
+    /// The emission is added to the stake of the nominator unless it is paid to a free balance. A
+    /// payout the free balance cannot receive, such as one below the existential deposit of a new
+    /// account, is compounded instead, as is the payout of a coldkey being swapped, whose balance
+    /// has already moved. Either way the emission is new issuance, accounted for by the caller.
+    ///
+    /// # Arguments
+    /// * `nominator` - The coldkey staking on the hotkey.
+    /// * `hotkey` - The drained hotkey.
+    /// * `amount` - The emission of the nominator.
+    pub fn pay_nominator_emission(nominator: &T::AccountId, hotkey: &T::AccountId, amount: u64) {
+        let destination = match RewardPayouts::<T>::get(hotkey, nominator) {
+            Some(RewardPayout::FreeBalance) => Some(nominator.clone()),
+            Some(RewardPayout::Account(account)) => Some(account),
+            Some(RewardPayout::Compound) | None => None,
+        };
+        let destination = destination.filter(|_| !Self::is_key_swapping(nominator));
+
+        if let Some(destination) = destination.filter(|_| amount > 0) {
+            if T::Currency::deposit(&destination, amount, Precision::Exact).is_ok() {
+                Self::deposit_event(Event::NominatorRewardPaid {
+                    coldkey: nominator.clone(),
+                    hotkey: hotkey.clone(),
+                    destination,
+                    amount,
+                });
+                return;
+            }
+        }
+
+        Self::increase_stake_on_coldkey_hotkey_account(nominator, hotkey, amount);
+    }
+}
@@ -0,0 +1,175 @@
+use super::*;
+
+impl<T: Config> Pallet<T> {
+    /// ---- The implementation for the extrinsic transfer_stake: Transfers stake on a hotkey to another coldkey.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     -  The signature of the caller's coldkey.
+    ///
+    /// * 'destination_coldkey' (T::AccountId):
+    ///     -  The coldkey receiving the stake.
+    ///
+    /// * 'hotkey' (T::AccountId):
+    ///     -  The hotkey the stake is held on.
+    ///
+    /// * 'amount' (u64):
+    ///     -  The amount of stake to transfer.
+    ///
+    /// # Event:
+    /// * StakeTransferred;
+    ///     -  On successfully transferring the stake.
+    ///
+    /// # Raises:
+    /// * 'SameColdkey':
+    ///     -  Thrown if the destination coldkey is the caller.
+    ///
+    /// * 'SwapAlreadyScheduled':
+    ///     -  Thrown if the caller has a coldkey swap scheduled.
+    ///
+    /// * 'ColdkeyIsInArbitration' / 'NewColdkeyIsInArbitration':
+    ///     -  Thrown if either coldkey has a contested swap.
+    ///
+    /// * 'HotKeyAccountNotExists':
+    ///     -  Thrown if the hotkey does not exist.
+    ///
+    /// * 'HotKeyNotDelegateAndSignerNotOwnHotKey':
+    ///     -  Thrown if the hotkey is neither a delegate nor owned by the destination coldkey.
+    ///
+    /// * 'StakeToWithdrawIsZero':
+    ///     -  Thrown if the amount is zero.
+    ///
+    /// * 'NotEnoughStakeToWithdraw':
+    ///     -  Thrown if the caller does not have enough stake on the hotkey.
+    ///
+    /// * 'NomStakeBelowMinimumThreshold':
+    ///     -  Thrown if the resulting nomination of the destination is below the minimum.
+    ///
+    /// * 'StakeRateLimitExceeded':
+    ///     -  Thrown if the caller has hit the stake rate limit on the hotkey.
+    ///
+    /// * 'KeySwapInProgress':
+    ///     -  Thrown if either coldkey or the hotkey is locked by a key swap in progress.
+    ///
+    pub fn do_transfer_stake(
+        origin: T::RuntimeOrigin,
+        destination_coldkey: T::AccountId,
+        hotkey: T::AccountId,
+        amount: u64,
+    ) -> dispatch::DispatchResult {
+        // We check the transaction is signed by the caller and retrieve the T::AccountId coldkey information.
+        let coldkey = ensure_signed(origin)?;
+        log::debug!(
+            "do_transfer_stake( origin:{:?} destination_coldkey:{:?}, hotkey:{:?}, amount:{:?} )",
+            coldkey,
+            destination_coldkey,
+            hotkey,
+            amount
+        );
+
+        // Ensure the stake actually changes owner.
+        ensure!(destination_coldkey != coldkey, Error::<T>::SameColdkey);
+
+        // Stake cannot leave a coldkey which is being swapped or is in arbitration. The signed
+        // extension filters these too, but proxied calls do not pass through it.
+        ensure!(
+            !ColdkeySwapScheduled::<T>::contains_key(&coldkey),
+            Error::<T>::SwapAlreadyScheduled
+        );
+        ensure!(
+            !ColdkeySwapDisputes::<T>::contains_key(&coldkey),
+            Error::<T>::ColdkeyIsInArbitration
+        );
+        ensure!(
+            !ColdkeySwapDisputes::<T>::contains_key(&destination_coldkey),
+            Error::<T>::NewColdkeyIsInArbitration
+        );
+        ensure!(
+            !Self::is_key_swapping(&coldkey)
+                && !Self::is_key_swapping(&destination_coldkey)
+                && !Self::is_key_swapping(&hotkey),
+            Error::<T>::KeySwapInProgress
+        );
+
+        // Ensure that the hotkey account exists.
+        ensure!(
+            Self::hotkey_account_exists(&hotkey),
+            Error::<T>::HotKeyAccountNotExists
+        );
+
+        // Ensure that the destination could have staked to the hotkey itself.
+        ensure!(
+            Self::hotkey_is_delegate(&hotkey)
+                || Self::coldkey_owns_hotkey(&destination_coldkey, &hotkey),
+            Error::<T>::HotKeyNotDelegateAndSignerNotOwnHotKey
+        );
+
+        // Ensure that the amount is above zero and available on the hotkey.
+        ensure!(amount > 0, Error::<T>::StakeToWithdrawIsZero);
+        ensure!(
+            Self::has_enough_stake(&coldkey, &hotkey, amount),
+            Error::<T>::NotEnoughStakeToWithdraw
+        );
+
+        // If the destination is not owner of the hotkey, it's a nomination stake.
+        if !Self::coldkey_owns_hotkey(&destination_coldkey, &hotkey) {
+            let total_stake_after_transfer =
+                Stake::<T>::get(&hotkey, &destination_coldkey).saturating_add(amount);
+            ensure!(
+                total_stake_after_transfer >= NominatorMinRequiredStake::<T>::get(),
+                Error::<T>::NomStakeBelowMinimumThreshold
+            );
+        }
+
+        // Ensure we don't exceed stake rate limit
+        let stakes_this_interval =
+            Self::get_stakes_this_interval_for_coldkey_hotkey(&coldkey, &hotkey);
+        ensure!(
+            stakes_this_interval < Self::get_target_stakes_per_interval(),
+            Error::<T>::StakeRateLimitExceeded
+        );
+
+        // The stake stays on the same hotkey, so the destination inherits its eligibility for the
+        // next emission drain rather than restarting it.
+        let origin_last_increase = LastAddStakeIncrease::<T>::get(&hotkey, &coldkey);
+        LastAddStakeIncrease::<T>::mutate(&hotkey, &destination_coldkey, |last| {
+            *last = (*last).max(origin_last_increase)
+        });
+
+        // Transfer the stake without passing through the free balance.
+        Self::decrease_stake_on_coldkey_hotkey_account(&coldkey, &hotkey, amount);
+        Self::increase_stake_on_coldkey_hotkey_account(&destination_coldkey, &hotkey, amount);
+
+        // If the remaining stake is below the minimum, we clear the nomination from storage.
+        let remaining_stake = Self::get_stake_for_coldkey_and_hotkey(&coldkey, &hotkey);
+        Self::clear_small_nomination_if_required(&hotkey, &coldkey, remaining_stake);
+
+        // Set last block for rate limiting
+        let block: u64 = Self::get_current_block_as_u64();
+        Self::set_last_tx_block(&coldkey, block);
+        Self::set_stakes_this_interval_for_coldkey_hotkey(
+            &coldkey,
+            &hotkey,
+            stakes_this_interval.saturating_add(1),
+            block,
+        );
+
+        // Emit the transfer event.
+        log::debug!(
+            "StakeTransferred( origin_coldkey:{:?}, destination_coldkey:{:?}, hotkey:{:?}, amount:{:?} )",
+            coldkey,
+            destination_coldkey,
+            hotkey,
+            amount
+        );
+        Self::deposit_event(Event::StakeTransferred {
+            origin_coldkey: coldkey,
+            destination_coldkey,
+            hotkey,
+            amount,
+        });
+
+        // Done and ok.
+        Ok(())
+    }
+}
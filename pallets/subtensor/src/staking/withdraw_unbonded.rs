@@ -0,0 +1,115 @@
+use super::*;
+
+impl<T: Config> Pallet<T> {
+    /// ---- The implementation for the extrinsic withdraw_unbonded: Withdraws the unbonded stake of a coldkey onto its balance.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     -  The signature of the caller's coldkey.
+    ///
+    /// # Event:
+    /// * UnbondedWithdrawn;
+    ///     -  On successfully withdrawing the unbonded stake.
+    ///
+    /// # Raises:
+    /// * 'NoUnbondedStakeToWithdraw':
+    ///     -  Thrown if none of the unbonding stake of the coldkey has reached its unlock block.
+    ///
+    pub fn do_withdraw_unbonded(origin: T::RuntimeOrigin) -> dispatch::DispatchResult {
+        // We check the transaction is signed by the caller and retrieve the T::AccountId coldkey information.
+        let coldkey = ensure_signed(origin)?;
+
+        // Split the queue into the unlocked entries and the ones still unbonding.
+        let current_block: u64 = Self::get_current_block_as_u64();
+        let (unlocked, unbonding): (Vec<(u64, u64)>, Vec<(u64, u64)>) =
+            UnbondingQueue::<T>::get(&coldkey)
+                .into_iter()
+                .partition(|(_, unlock_block)| *unlock_block <= current_block);
+        let amount: u64 = unlocked
+            .iter()
+            .fold(0u64, |total, (amount, _)| total.saturating_add(*amount));
+        ensure!(amount > 0, Error::<T>::NoUnbondedStakeToWithdraw);
+
+        // Keep the entries still unbonding.
+        if unbonding.is_empty() {
+            UnbondingQueue::<T>::remove(&coldkey);
+        } else {
+            UnbondingQueue::<T>::insert(&coldkey, unbonding);
+        }
+        TotalUnbonding::<T>::mutate(|total| *total = total.saturating_sub(amount));
+
+        // We add the balance to the coldkey.
+        Self::add_balance_to_coldkey_account(&coldkey, amount);
+
+        log::debug!(
+            "UnbondedWithdrawn( coldkey:{:?}, amount:{:?} )",
+            coldkey,
+            amount
+        );
+        Self::deposit_event(Event::UnbondedWithdrawn { coldkey, amount });
+
+        // Done and ok.
+        Ok(())
+    }
+
+    /// Returns true if removed stake of the coldkey can be credited now, or queued to unbond.
+    ///
+    /// The queue is full once it holds `MAX_UNBONDING_CHUNKS` entries, unless the stake would
+    /// unlock at the same block as one of them.
+    pub fn can_unbond_stake(coldkey: &T::AccountId) -> bool {
+        let period: u64 = Self::get_unbonding_period();
+        if period == 0 {
+            return true;
+        }
+        let unlock_block: u64 = Self::get_current_block_as_u64().saturating_add(period);
+        let queue = UnbondingQueue::<T>::get(coldkey);
+        queue.len() < MAX_UNBONDING_CHUNKS
+            || queue
+                .iter()
+                .any(|(_, chunk_unlock)| *chunk_unlock == unlock_block)
+    }
+
+    /// Credits stake removed from a hotkey to the coldkey.
+    ///
+    /// Without an unbonding period the amount is added to the balance of the coldkey. Otherwise it
+    /// is queued until `UnbondingPeriod` blocks from now, see `do_withdraw_unbonded`.
+    ///
+    /// # Arguments
+    /// * `coldkey` - The coldkey the stake was removed for.
+    /// * `hotkey` - The hotkey the stake was removed from.
+    /// * `amount` - The amount of stake removed.
+    pub fn credit_removed_stake(coldkey: &T::AccountId, hotkey: &T::AccountId, amount: u64) {
+        let period: u64 = Self::get_unbonding_period();
+        if period == 0 {
+            Self::add_balance_to_coldkey_account(coldkey, amount);
+            return;
+        }
+
+        let unlock_block: u64 = Self::get_current_block_as_u64().saturating_add(period);
+        UnbondingQueue::<T>::mutate(coldkey, |queue| {
+            Self::queue_unbonding_chunk(queue, amount, unlock_block)
+        });
+        TotalUnbonding::<T>::mutate(|total| *total = total.saturating_add(amount));
+
+        Self::deposit_event(Event::StakeUnbonding {
+            coldkey: coldkey.clone(),
+            hotkey: hotkey.clone(),
+            amount,
+            unlock_block,
+        });
+    }
+
+    /// Adds an amount to an unbonding queue, merged with the entry unlocking at the same block.
+    pub fn queue_unbonding_chunk(queue: &mut Vec<(u64, u64)>, amount: u64, unlock_block: u64) {
+        match queue
+            .iter_mut()
+            .find(|(_, chunk_unlock)| *chunk_unlock == unlock_block)
+        {
+            Some((chunk_amount, _)) => *chunk_amount = chunk_amount.saturating_add(amount),
+            None => {
+                queue.push((amount, unlock_block));
+                queue.sort_by_key(|(_, chunk_unlock)| *chunk_unlock);
+            }
+        }
+    }
+}
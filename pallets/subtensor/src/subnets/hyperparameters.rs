@@ -0,0 +1,654 @@
+use super::*;
+use frame_support::traits::Get;
+use frame_support::weights::Weight;
+use frame_system::ensure_root;
+use sp_runtime::SaturatedConversion;
+
+impl<T: Config> Pallet<T> {
+    /// Sets a subnet hyperparameter from its `u64` representation.
+    ///
+    /// # Arguments
+    ///
+    /// * `netuid` - The network identifier.
+    /// * `param` - The hyperparameter to set.
+    /// * `value` - The new value, booleans are 0 or 1.
+    pub fn set_subnet_hyperparameter(netuid: u16, param: SubnetHyperparameter, value: u64) {
+        match param {
+            SubnetHyperparameter::ServingRateLimit => Self::set_serving_rate_limit(netuid, value),
+            SubnetHyperparameter::MinDifficulty => Self::set_min_difficulty(netuid, value),
+            SubnetHyperparameter::MaxDifficulty => Self::set_max_difficulty(netuid, value),
+            SubnetHyperparameter::WeightsVersionKey => Self::set_weights_version_key(netuid, value),
+            SubnetHyperparameter::WeightsSetRateLimit => {
+                Self::set_weights_set_rate_limit(netuid, value)
+            }
+            SubnetHyperparameter::AdjustmentInterval => {
+                Self::set_adjustment_interval(netuid, value.saturated_into())
+            }
+            SubnetHyperparameter::AdjustmentAlpha => Self::set_adjustment_alpha(netuid, value),
+            SubnetHyperparameter::MaxWeightLimit => {
+                Self::set_max_weight_limit(netuid, value.saturated_into())
+            }
+            SubnetHyperparameter::ImmunityPeriod => {
+                Self::set_immunity_period(netuid, value.saturated_into())
+            }
+            SubnetHyperparameter::MinAllowedWeights => {
+                Self::set_min_allowed_weights(netuid, value.saturated_into())
+            }
+            SubnetHyperparameter::MaxAllowedUids => {
+                Self::set_max_allowed_uids(netuid, value.saturated_into())
+            }
+            SubnetHyperparameter::Kappa => Self::set_kappa(netuid, value.saturated_into()),
+            SubnetHyperparameter::Rho => Self::set_rho(netuid, value.saturated_into()),
+            SubnetHyperparameter::ActivityCutoff => {
+                Self::set_activity_cutoff(netuid, value.saturated_into())
+            }
+            SubnetHyperparameter::NetworkRegistrationAllowed => {
+                Self::set_network_registration_allowed(netuid, value != 0)
+            }
+            SubnetHyperparameter::NetworkPowRegistrationAllowed => {
+                Self::set_network_pow_registration_allowed(netuid, value != 0)
+            }
+            SubnetHyperparameter::TargetRegistrationsPerInterval => {
+                Self::set_target_registrations_per_interval(netuid, value.saturated_into())
+            }
+            SubnetHyperparameter::MinBurn => Self::set_min_burn(netuid, value),
+            SubnetHyperparameter::MaxBurn => Self::set_max_burn(netuid, value),
+            SubnetHyperparameter::Difficulty => Self::set_difficulty(netuid, value),
+            SubnetHyperparameter::MaxAllowedValidators => {
+                Self::set_max_allowed_validators(netuid, value.saturated_into())
+            }
+            SubnetHyperparameter::BondsMovingAverage => {
+                Self::set_bonds_moving_average(netuid, value)
+            }
+            SubnetHyperparameter::MaxRegistrationsPerBlock => {
+                Self::set_max_registrations_per_block(netuid, value.saturated_into())
+            }
+            SubnetHyperparameter::Tempo => Self::set_tempo(netuid, value.saturated_into()),
+            SubnetHyperparameter::CommitRevealWeightsInterval => {
+                Self::set_commit_reveal_weights_interval(netuid, value)
+            }
+            SubnetHyperparameter::CommitRevealWeightsEnabled => {
+                Self::set_commit_reveal_weights_enabled(netuid, value != 0)
+            }
+            SubnetHyperparameter::LiquidAlphaEnabled => {
+                Self::set_liquid_alpha_enabled(netuid, value != 0)
+            }
+            SubnetHyperparameter::NetworkMaxStake => Self::set_network_max_stake(netuid, value),
+            SubnetHyperparameter::MaxChildren => {
+                Self::set_max_children(netuid, value.saturated_into())
+            }
+            SubnetHyperparameter::MinChildProportion => {
+                Self::set_min_child_proportion(netuid, value)
+            }
+            SubnetHyperparameter::SetChildrenCooldown => {
+                Self::set_set_children_cooldown(netuid, value)
+            }
+            SubnetHyperparameter::MinChildkeyTake => {
+                Self::set_subnet_min_childkey_take(netuid, value.saturated_into())
+            }
+            SubnetHyperparameter::MaxChildkeyTake => {
+                Self::set_subnet_max_childkey_take(netuid, value.saturated_into())
+            }
+            SubnetHyperparameter::AlphaLow => Self::set_alpha_low(netuid, value.saturated_into()),
+            SubnetHyperparameter::AlphaHigh => Self::set_alpha_high(netuid, value.saturated_into()),
+        }
+    }
+
+    /// Returns a subnet hyperparameter as its `u64` representation.
+    pub fn get_subnet_hyperparameter(netuid: u16, param: SubnetHyperparameter) -> u64 {
+        match param {
+            SubnetHyperparameter::ServingRateLimit => Self::get_serving_rate_limit(netuid),
+            SubnetHyperparameter::MinDifficulty => Self::get_min_difficulty(netuid),
+            SubnetHyperparameter::MaxDifficulty => Self::get_max_difficulty(netuid),
+            SubnetHyperparameter::WeightsVersionKey => Self::get_weights_version_key(netuid),
+            SubnetHyperparameter::WeightsSetRateLimit => Self::get_weights_set_rate_limit(netuid),
+            SubnetHyperparameter::AdjustmentInterval => {
+                u64::from(Self::get_adjustment_interval(netuid))
+            }
+            SubnetHyperparameter::AdjustmentAlpha => Self::get_adjustment_alpha(netuid),
+            SubnetHyperparameter::MaxWeightLimit => u64::from(Self::get_max_weight_limit(netuid)),
+            SubnetHyperparameter::ImmunityPeriod => u64::from(Self::get_immunity_period(netuid)),
+            SubnetHyperparameter::MinAllowedWeights => {
+                u64::from(Self::get_min_allowed_weights(netuid))
+            }
+            SubnetHyperparameter::MaxAllowedUids => u64::from(Self::get_max_allowed_uids(netuid)),
+            SubnetHyperparameter::Kappa => u64::from(Self::get_kappa(netuid)),
+            SubnetHyperparameter::Rho => u64::from(Self::get_rho(netuid)),
+            SubnetHyperparameter::ActivityCutoff => u64::from(Self::get_activity_cutoff(netuid)),
+            SubnetHyperparameter::NetworkRegistrationAllowed => {
+                u64::from(Self::get_network_registration_allowed(netuid))
+            }
+            SubnetHyperparameter::NetworkPowRegistrationAllowed => {
+                u64::from(Self::get_network_pow_registration_allowed(netuid))
+            }
+            SubnetHyperparameter::TargetRegistrationsPerInterval => {
+                u64::from(Self::get_target_registrations_per_interval(netuid))
+            }
+            SubnetHyperparameter::MinBurn => Self::get_min_burn_as_u64(netuid),
+            SubnetHyperparameter::MaxBurn => Self::get_max_burn_as_u64(netuid),
+            SubnetHyperparameter::Difficulty => Self::get_difficulty_as_u64(netuid),
+            SubnetHyperparameter::MaxAllowedValidators => {
+                u64::from(Self::get_max_allowed_validators(netuid))
+            }
+            SubnetHyperparameter::BondsMovingAverage => Self::get_bonds_moving_average(netuid),
+            SubnetHyperparameter::MaxRegistrationsPerBlock => {
+                u64::from(Self::get_max_registrations_per_block(netuid))
+            }
+            SubnetHyperparameter::Tempo => u64::from(Self::get_tempo(netuid)),
+            SubnetHyperparameter::CommitRevealWeightsInterval => {
+                Self::get_commit_reveal_weights_interval(netuid)
+            }
+            SubnetHyperparameter::CommitRevealWeightsEnabled => {
+                u64::from(Self::get_commit_reveal_weights_enabled(netuid))
+            }
+            SubnetHyperparameter::LiquidAlphaEnabled => {
+                u64::from(Self::get_liquid_alpha_enabled(netuid))
+            }
+            SubnetHyperparameter::NetworkMaxStake => Self::get_network_max_stake(netuid),
+            SubnetHyperparameter::MaxChildren => u64::from(Self::get_max_children(netuid)),
+            SubnetHyperparameter::MinChildProportion => Self::get_min_child_proportion(netuid),
+            SubnetHyperparameter::SetChildrenCooldown => Self::get_set_children_cooldown(netuid),
+            SubnetHyperparameter::MinChildkeyTake => {
+                u64::from(Self::get_subnet_min_childkey_take(netuid))
+            }
+            SubnetHyperparameter::MaxChildkeyTake => {
+                u64::from(Self::get_subnet_max_childkey_take(netuid))
+            }
+            SubnetHyperparameter::AlphaLow => u64::from(Self::get_alpha_values(netuid).0),
+            SubnetHyperparameter::AlphaHigh => u64::from(Self::get_alpha_values(netuid).1),
+        }
+    }
+
+    /// Applies a hyperparameter change now, or records it as pending if an enactment delay is set.
+    ///
+    /// A later change of the same hyperparameter replaces the pending one.
+    ///
+    /// # Returns
+    ///
+    /// * The block at which the change applies, or `None` if it was applied immediately.
+    pub fn set_or_schedule_subnet_hyperparameter(
+        netuid: u16,
+        param: SubnetHyperparameter,
+        value: u64,
+    ) -> Option<u64> {
+        let delay = HyperparameterEnactmentDelay::<T>::get();
+        if delay == 0 {
+            Self::set_subnet_hyperparameter(netuid, param, value);
+            return None;
+        }
+
+        let effective_block = Self::get_current_block_as_u64().saturating_add(delay);
+        Self::remove_pending_hyperparameter_change(netuid, param);
+        PendingHyperparameterChanges::<T>::insert(netuid, param, (value, effective_block));
+        PendingHyperparameterChangesByBlock::<T>::append(effective_block, (netuid, param));
+        log::debug!(
+            "HyperparameterChangeScheduled( netuid:{:?}, param:{:?}, value:{:?}, effective_block:{:?} )",
+            netuid,
+            param,
+            value,
+            effective_block
+        );
+        Self::deposit_event(Event::HyperparameterChangeScheduled {
+            netuid,
+            param,
+            value,
+            effective_block,
+        });
+        Some(effective_block)
+    }
+
+    /// Cancels a pending hyperparameter change before it applies.
+    ///
+    /// # Raises
+    ///
+    /// * `NoPendingHyperparameterChange` - No change of `param` is pending for the subnet.
+    pub fn cancel_pending_hyperparameter_change(
+        netuid: u16,
+        param: SubnetHyperparameter,
+    ) -> DispatchResult {
+        ensure!(
+            Self::remove_pending_hyperparameter_change(netuid, param).is_some(),
+            Error::<T>::NoPendingHyperparameterChange
+        );
+        Self::deposit_event(Event::HyperparameterChangeCancelled { netuid, param });
+        Ok(())
+    }
+
+    /// Drops the pending change of a hyperparameter, returning it as `(value, effective_block)`.
+    pub fn remove_pending_hyperparameter_change(
+        netuid: u16,
+        param: SubnetHyperparameter,
+    ) -> Option<(u64, u64)> {
+        let pending = PendingHyperparameterChanges::<T>::take(netuid, param);
+        if let Some((_, effective_block)) = pending {
+            PendingHyperparameterChangesByBlock::<T>::mutate_exists(effective_block, |changes| {
+                if let Some(indexed) = changes {
+                    indexed.retain(|change| *change != (netuid, param));
+                    if indexed.is_empty() {
+                        *changes = None;
+                    }
+                }
+            });
+        }
+        pending
+    }
+
+    /// Drops every pending hyperparameter change of a subnet.
+    pub fn clear_pending_hyperparameter_changes(netuid: u16) {
+        let params: Vec<SubnetHyperparameter> =
+            PendingHyperparameterChanges::<T>::iter_key_prefix(netuid).collect();
+        for param in params {
+            Self::remove_pending_hyperparameter_change(netuid, param);
+        }
+    }
+
+    /// Applies the pending hyperparameter changes whose effective block is this block.
+    ///
+    /// Changes which are no longer valid when they come due, such as a UID limit below the
+    /// current number of neurons, are dropped.
+    ///
+    /// # Returns
+    ///
+    /// * The weight used, and the applied changes as `(netuid, param, old_value, new_value)`.
+    pub fn enact_pending_hyperparameter_changes(
+    ) -> (Weight, Vec<(u16, SubnetHyperparameter, u64, u64)>) {
+        let current_block = Self::get_current_block_as_u64();
+        let mut weight = T::DbWeight::get().reads_writes(1, 1);
+        let mut enacted = Vec::new();
+
+        for (netuid, param) in PendingHyperparameterChangesByBlock::<T>::take(current_block) {
+            weight.saturating_accrue(T::DbWeight::get().reads(1));
+            // The change may have been replaced or cancelled since it was indexed.
+            let Some((value, effective_block)) =
+                PendingHyperparameterChanges::<T>::get(netuid, param)
+            else {
+                continue;
+            };
+            if effective_block > current_block {
+                continue;
+            }
+            PendingHyperparameterChanges::<T>::remove(netuid, param);
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+
+            if !Self::hyperparameter_is_valid(netuid, param, value) {
+                log::warn!(
+                    "Dropped pending hyperparameter change( netuid:{:?}, param:{:?}, value:{:?} )",
+                    netuid,
+                    param,
+                    value
+                );
+                continue;
+            }
+
+            let old_value = Self::get_subnet_hyperparameter(netuid, param);
+            Self::set_subnet_hyperparameter(netuid, param, value);
+            weight.saturating_accrue(T::DbWeight::get().reads(1));
+            Self::deposit_event(Event::HyperparameterChangeEnacted {
+                netuid,
+                param,
+                value,
+            });
+            enacted.push((netuid, param, old_value, value));
+        }
+
+        (weight, enacted)
+    }
+
+    /// Checks the constraints a hyperparameter value has on the subnet state, for values which
+    /// were not validated against the current state of the subnet.
+    fn hyperparameter_is_valid(netuid: u16, param: SubnetHyperparameter, value: u64) -> bool {
+        if !Self::if_subnet_exist(netuid) || !Self::is_within_childkey_limits(param, value) {
+            return false;
+        }
+        match param {
+            SubnetHyperparameter::MaxAllowedUids => {
+                u64::from(Self::get_subnetwork_n(netuid)) < value
+            }
+            SubnetHyperparameter::MaxAllowedValidators => {
+                value <= u64::from(Self::get_max_allowed_uids(netuid))
+            }
+            SubnetHyperparameter::MinChildkeyTake => {
+                value <= u64::from(Self::get_subnet_max_childkey_take(netuid))
+            }
+            SubnetHyperparameter::MaxChildkeyTake => {
+                u64::from(Self::get_subnet_min_childkey_take(netuid)) <= value
+            }
+            SubnetHyperparameter::MinBurn => value <= Self::get_max_burn_as_u64(netuid),
+            SubnetHyperparameter::MaxBurn => Self::get_min_burn_as_u64(netuid) <= value,
+            SubnetHyperparameter::MinDifficulty => value <= Self::get_max_difficulty(netuid),
+            SubnetHyperparameter::MaxDifficulty => Self::get_min_difficulty(netuid) <= value,
+            SubnetHyperparameter::AlphaLow => u16::try_from(value).is_ok_and(|alpha_low| {
+                Self::ensure_valid_alpha_values(netuid, alpha_low, Self::get_alpha_values(netuid).1)
+                    .is_ok()
+            }),
+            SubnetHyperparameter::AlphaHigh => u16::try_from(value).is_ok_and(|alpha_high| {
+                Self::ensure_valid_alpha_values(
+                    netuid,
+                    Self::get_alpha_values(netuid).0,
+                    alpha_high,
+                )
+                .is_ok()
+            }),
+            _ => true,
+        }
+    }
+
+    /// Returns false if a value is outside the limits every subnet keeps to for its children: at
+    /// most `MAX_CHILDREN_LIMIT` children, a cooldown of at least `MIN_SET_CHILDREN_COOLDOWN`
+    /// blocks, and childkey take bounds within the global bounds.
+    pub fn is_within_childkey_limits(param: SubnetHyperparameter, value: u64) -> bool {
+        match param {
+            SubnetHyperparameter::MaxChildren => value <= u64::from(MAX_CHILDREN_LIMIT),
+            SubnetHyperparameter::SetChildrenCooldown => value >= MIN_SET_CHILDREN_COOLDOWN,
+            SubnetHyperparameter::MinChildkeyTake | SubnetHyperparameter::MaxChildkeyTake => {
+                u64::from(Self::get_min_childkey_take()) <= value
+                    && value <= u64::from(Self::get_max_childkey_take())
+            }
+            _ => true,
+        }
+    }
+
+    /// Returns true if only root may change a subnet hyperparameter, false if the subnet owner
+    /// may also change it within its bounds.
+    pub fn is_root_only_subnet_hyperparameter(param: SubnetHyperparameter) -> bool {
+        match param {
+            SubnetHyperparameter::AdjustmentInterval
+            | SubnetHyperparameter::MaxAllowedUids
+            | SubnetHyperparameter::TargetRegistrationsPerInterval
+            | SubnetHyperparameter::MaxAllowedValidators
+            | SubnetHyperparameter::MaxRegistrationsPerBlock
+            | SubnetHyperparameter::Tempo
+            | SubnetHyperparameter::NetworkMaxStake => true,
+            SubnetHyperparameter::ServingRateLimit
+            | SubnetHyperparameter::MinDifficulty
+            | SubnetHyperparameter::MaxDifficulty
+            | SubnetHyperparameter::WeightsVersionKey
+            | SubnetHyperparameter::WeightsSetRateLimit
+            | SubnetHyperparameter::AdjustmentAlpha
+            | SubnetHyperparameter::MaxWeightLimit
+            | SubnetHyperparameter::ImmunityPeriod
+            | SubnetHyperparameter::MinAllowedWeights
+            | SubnetHyperparameter::Kappa
+            | SubnetHyperparameter::Rho
+            | SubnetHyperparameter::ActivityCutoff
+            | SubnetHyperparameter::NetworkRegistrationAllowed
+            | SubnetHyperparameter::NetworkPowRegistrationAllowed
+            | SubnetHyperparameter::MinBurn
+            | SubnetHyperparameter::MaxBurn
+            | SubnetHyperparameter::Difficulty
+            | SubnetHyperparameter::BondsMovingAverage
+            | SubnetHyperparameter::CommitRevealWeightsInterval
+            | SubnetHyperparameter::CommitRevealWeightsEnabled
+            | SubnetHyperparameter::LiquidAlphaEnabled
+            | SubnetHyperparameter::MaxChildren
+            | SubnetHyperparameter::MinChildProportion
+            | SubnetHyperparameter::SetChildrenCooldown
+            | SubnetHyperparameter::MinChildkeyTake
+            | SubnetHyperparameter::MaxChildkeyTake
+            | SubnetHyperparameter::AlphaLow
+            | SubnetHyperparameter::AlphaHigh => false,
+        }
+    }
+
+    /// Ensures the origin may change a subnet hyperparameter, root for the root-only
+    /// hyperparameters and the subnet owner or root for the others.
+    ///
+    /// # Raises
+    ///
+    /// * `BadOrigin` - The origin may not change the hyperparameter.
+    pub fn ensure_subnet_hyperparameter_origin(
+        o: T::RuntimeOrigin,
+        netuid: u16,
+        param: SubnetHyperparameter,
+    ) -> DispatchResult {
+        if Self::is_root_only_subnet_hyperparameter(param) {
+            ensure_root(o)?;
+            Ok(())
+        } else {
+            Self::ensure_subnet_owner_or_root(o, netuid)
+        }
+    }
+
+    /// Returns the pending hyperparameter changes of a subnet as `(param, value, effective_block)`.
+    pub fn get_pending_hyperparameter_changes(
+        netuid: u16,
+    ) -> Vec<(SubnetHyperparameter, u64, u64)> {
+        PendingHyperparameterChanges::<T>::iter_prefix(netuid)
+            .map(|(param, (value, effective_block))| (param, value, effective_block))
+            .collect()
+    }
+
+    /// Ensures the origin is the subnet owner or root, and that a subnet owner keeps the value
+    /// within the bounds root has set for the hyperparameter.
+    ///
+    /// # Raises
+    ///
+    /// * `BadOrigin` - The origin is neither root nor the subnet owner.
+    /// * `HyperparameterOutOfBounds` - The subnet owner set a value outside the bounds.
+    pub fn ensure_subnet_owner_or_root_within_bounds(
+        o: T::RuntimeOrigin,
+        netuid: u16,
+        param: SubnetHyperparameter,
+        value: u64,
+    ) -> DispatchResult {
+        let is_owner = ensure_signed(o.clone()).is_ok();
+        Self::ensure_subnet_owner_or_root(o, netuid)?;
+        if is_owner {
+            let (min, max) = Self::get_hyperparameter_bounds(param);
+            ensure!(
+                min <= value && value <= max,
+                Error::<T>::HyperparameterOutOfBounds
+            );
+        }
+        Ok(())
+    }
+
+    /// Returns the range a subnet owner may set a hyperparameter to as `(min, max)`.
+    pub fn get_hyperparameter_bounds(param: SubnetHyperparameter) -> (u64, u64) {
+        HyperparameterBounds::<T>::get(param).unwrap_or((0, u64::MAX))
+    }
+
+    /// Returns every bounded hyperparameter as `(param, min, max)`. Hyperparameters which are not
+    /// listed are unbounded.
+    pub fn get_all_hyperparameter_bounds() -> Vec<(SubnetHyperparameter, u64, u64)> {
+        HyperparameterBounds::<T>::iter()
+            .map(|(param, (min, max))| (param, min, max))
+            .collect()
+    }
+
+    /// Returns the bounds matching the limits the hyperparameters are checked against, and the
+    /// default ranges of rho, kappa and the activity cutoff, as `(param, min, max)`.
+    pub fn get_default_hyperparameter_bounds() -> Vec<(SubnetHyperparameter, u64, u64)> {
+        vec![
+            (
+                SubnetHyperparameter::AlphaLow,
+                1,
+                u64::from(MIN_ALPHA_HIGH.saturating_sub(1)),
+            ),
+            (
+                SubnetHyperparameter::AlphaHigh,
+                u64::from(MIN_ALPHA_HIGH),
+                u64::from(u16::MAX),
+            ),
+            (
+                SubnetHyperparameter::MaxChildren,
+                0,
+                u64::from(MAX_CHILDREN_LIMIT),
+            ),
+            (
+                SubnetHyperparameter::SetChildrenCooldown,
+                MIN_SET_CHILDREN_COOLDOWN,
+                u64::MAX,
+            ),
+            (
+                SubnetHyperparameter::Rho,
+                u64::from(DEFAULT_RHO_BOUNDS.0),
+                u64::from(DEFAULT_RHO_BOUNDS.1),
+            ),
+            (
+                SubnetHyperparameter::Kappa,
+                u64::from(DEFAULT_MIN_KAPPA),
+                u64::from(u16::MAX),
+            ),
+            (
+                SubnetHyperparameter::ActivityCutoff,
+                u64::from(DEFAULT_MIN_ACTIVITY_CUTOFF),
+                u64::from(u16::MAX),
+            ),
+        ]
+    }
+
+    /// Bounds the hyperparameters which have no bounds yet by their default bounds.
+    ///
+    /// # Returns
+    ///
+    /// * The number of hyperparameters bounded.
+    pub fn seed_default_hyperparameter_bounds() -> u64 {
+        let mut seeded: u64 = 0;
+        for (param, min, max) in Self::get_default_hyperparameter_bounds() {
+            if !HyperparameterBounds::<T>::contains_key(param) {
+                HyperparameterBounds::<T>::insert(param, (min, max));
+                seeded = seeded.saturating_add(1);
+            }
+        }
+        seeded
+    }
+
+    /// Set the range a subnet owner may set a hyperparameter to
+    ///
+    /// # Arguments
+    ///
+    /// * `param` - The hyperparameter to bound.
+    /// * `min` - The lowest value a subnet owner may set.
+    /// * `max` - The highest value a subnet owner may set.
+    ///
+    /// # Effects
+    ///
+    /// * Update the HyperparameterBounds storage, the full `u64` range removes the bounds.
+    /// * Emits a HyperparameterBoundsSet event.
+    pub fn set_hyperparameter_bounds(
+        param: SubnetHyperparameter,
+        min: u64,
+        max: u64,
+    ) -> DispatchResult {
+        ensure!(min <= max, Error::<T>::InvalidHyperparameterBounds);
+        if min == 0 && max == u64::MAX {
+            HyperparameterBounds::<T>::remove(param);
+        } else {
+            HyperparameterBounds::<T>::insert(param, (min, max));
+        }
+        Self::deposit_event(Event::HyperparameterBoundsSet { param, min, max });
+        Ok(())
+    }
+
+    /// Returns true if the template has a name of at most 256 bytes and sets each of at most 32
+    /// hyperparameters once.
+    pub fn is_valid_hyperparameter_template(template: &HyperparameterTemplate) -> bool {
+        let has_duplicates = template.params.iter().enumerate().any(|(i, (param, _))| {
+            template
+                .params
+                .iter()
+                .skip(i.saturating_add(1))
+                .any(|(other, _)| other == param)
+        });
+
+        !template.name.is_empty()
+            && template.name.len() <= 256
+            && template.params.len() <= 32
+            && !has_duplicates
+    }
+
+    /// Set a hyperparameter template which new subnets can be registered with
+    ///
+    /// # Arguments
+    ///
+    /// * `template_id` - The id of the template, an existing template is replaced.
+    /// * `template` - The name and hyperparameters of the template.
+    ///
+    /// # Effects
+    ///
+    /// * Update the HyperparameterTemplates storage.
+    /// * Emits a HyperparameterTemplateSet event.
+    ///
+    /// # Raises
+    ///
+    /// * `InvalidHyperparameterTemplate` - The template is not valid.
+    pub fn set_hyperparameter_template(
+        template_id: u16,
+        template: HyperparameterTemplate,
+    ) -> DispatchResult {
+        ensure!(
+            Self::is_valid_hyperparameter_template(&template),
+            Error::<T>::InvalidHyperparameterTemplate
+        );
+        HyperparameterTemplates::<T>::insert(template_id, template);
+        Self::deposit_event(Event::HyperparameterTemplateSet(template_id));
+        Ok(())
+    }
+
+    /// Removes a hyperparameter template. Subnets registered with it keep their hyperparameters.
+    ///
+    /// # Raises
+    ///
+    /// * `HyperparameterTemplateNotFound` - No template exists with the id.
+    pub fn remove_hyperparameter_template(template_id: u16) -> DispatchResult {
+        ensure!(
+            HyperparameterTemplates::<T>::take(template_id).is_some(),
+            Error::<T>::HyperparameterTemplateNotFound
+        );
+        Self::deposit_event(Event::HyperparameterTemplateRemoved(template_id));
+        Ok(())
+    }
+
+    /// Applies the hyperparameters of a template to a subnet, bypassing the enactment delay.
+    ///
+    /// Hyperparameters are set in the order of the template. Values which are not valid for the
+    /// subnet at that point, such as more validators than UIDs, are skipped.
+    ///
+    /// # Raises
+    ///
+    /// * `HyperparameterTemplateNotFound` - No template exists with the id.
+    pub fn apply_hyperparameter_template(netuid: u16, template_id: u16) -> DispatchResult {
+        let template = HyperparameterTemplates::<T>::get(template_id)
+            .ok_or(Error::<T>::HyperparameterTemplateNotFound)?;
+
+        for (param, value) in template.params {
+            if Self::hyperparameter_is_valid(netuid, param, value) {
+                Self::set_subnet_hyperparameter(netuid, param, value);
+            } else {
+                log::warn!(
+                    "Skipped template hyperparameter( netuid:{:?}, param:{:?}, value:{:?} )",
+                    netuid,
+                    param,
+                    value
+                );
+            }
+        }
+
+        Self::deposit_event(Event::HyperparameterTemplateApplied {
+            netuid,
+            template_id,
+        });
+        Ok(())
+    }
+
+    /// Returns every hyperparameter template as `(template_id, template)`.
+    pub fn get_hyperparameter_templates() -> Vec<(u16, HyperparameterTemplate)> {
+        HyperparameterTemplates::<T>::iter().collect()
+    }
+
+    /// Set the delay before hyperparameter changes apply
+    ///
+    /// # Arguments
+    ///
+    /// * `delay` - The blocks between a change and its enactment, 0 applies changes immediately.
+    ///
+    /// # Effects
+    ///
+    /// * Update the HyperparameterEnactmentDelay storage.
+    /// * Emits a HyperparameterEnactmentDelaySet event.
+    pub fn set_hyperparameter_enactment_delay(delay: u64) {
+        HyperparameterEnactmentDelay::<T>::set(delay);
+        Self::deposit_event(Event::HyperparameterEnactmentDelaySet(delay));
+    }
+}
@@ -1,4 +1,6 @@
 use super::*;
+pub mod hyperparameters;
+pub mod ownership;
 pub mod registration;
 pub mod serving;
 pub mod uids;
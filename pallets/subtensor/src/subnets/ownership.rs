@@ -0,0 +1,251 @@
+use super::*;
+use frame_support::traits::Get;
+use frame_support::weights::Weight;
+use frame_system::{ensure_signed_or_root, pallet_prelude::BlockNumberFor};
+use sp_runtime::Saturating;
+
+impl<T: Config> Pallet<T> {
+    /// ---- The implementation for the extrinsic propose_subnet_owner_transfer.
+    ///
+    /// The current owner of a subnet nominates a new owner coldkey. The transfer only takes effect
+    /// once the new owner accepts it, and no earlier than `SubnetOwnerTransferScheduleDuration`
+    /// blocks after the proposal.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     - The signature of the current subnet owner coldkey.
+    ///
+    /// * 'netuid' (u16):
+    ///     - The u16 network identifier.
+    ///
+    /// * 'new_owner' (T::AccountId):
+    ///     - The coldkey which will become the owner of the subnet.
+    ///
+    /// # Event:
+    /// * SubnetOwnerTransferProposed;
+    ///     - On successfully recording the pending transfer.
+    ///
+    /// # Raises:
+    /// * 'SubNetworkDoesNotExist':
+    ///     - The subnet does not exist.
+    ///
+    /// * 'NotSubnetOwner':
+    ///     - The caller does not own the subnet.
+    ///
+    /// * 'NewSubnetOwnerIsCurrentOwner':
+    ///     - The proposed owner already owns the subnet.
+    ///
+    /// * 'SubnetOwnerTransferAlreadyPending':
+    ///     - A transfer is already pending for this subnet.
+    ///
+    pub fn do_propose_subnet_owner_transfer(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+        new_owner: T::AccountId,
+    ) -> DispatchResult {
+        // --- 1. Ensure the caller is signed.
+        let coldkey = ensure_signed(origin)?;
+
+        // --- 2. Ensure the subnet exists and the caller owns it.
+        ensure!(
+            netuid != Self::get_root_netuid() && Self::if_subnet_exist(netuid),
+            Error::<T>::SubNetworkDoesNotExist
+        );
+        ensure!(
+            SubnetOwner::<T>::get(netuid) == coldkey,
+            Error::<T>::NotSubnetOwner
+        );
+
+        // --- 3. Ensure the transfer actually changes the owner.
+        ensure!(
+            new_owner != coldkey,
+            Error::<T>::NewSubnetOwnerIsCurrentOwner
+        );
+
+        // --- 4. Only one transfer can be pending at a time.
+        ensure!(
+            !PendingSubnetOwnerTransfer::<T>::contains_key(netuid),
+            Error::<T>::SubnetOwnerTransferAlreadyPending
+        );
+
+        // --- 5. Record the pending transfer.
+        let current_block: BlockNumberFor<T> = <frame_system::Pallet<T>>::block_number();
+        let executable_block: BlockNumberFor<T> =
+            current_block.saturating_add(SubnetOwnerTransferScheduleDuration::<T>::get());
+        PendingSubnetOwnerTransfer::<T>::insert(netuid, (new_owner.clone(), executable_block));
+
+        // --- 6. Emit the event and return.
+        log::debug!(
+            "SubnetOwnerTransferProposed( netuid:{:?}, current_owner:{:?}, new_owner:{:?}, executable_block:{:?} )",
+            netuid,
+            coldkey,
+            new_owner,
+            executable_block
+        );
+        Self::deposit_event(Event::SubnetOwnerTransferProposed {
+            netuid,
+            current_owner: coldkey,
+            new_owner,
+            executable_block,
+        });
+        Ok(())
+    }
+
+    /// ---- The implementation for the extrinsic accept_subnet_owner_transfer.
+    ///
+    /// The proposed owner takes over the subnet. Everything keyed by the netuid, such as the locked
+    /// balance refunded on dissolution, the subnet identity and the owner cut of emission, follows
+    /// the `SubnetOwner` entry and therefore moves to the new owner.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     - The signature of the proposed new owner coldkey.
+    ///
+    /// * 'netuid' (u16):
+    ///     - The u16 network identifier.
+    ///
+    /// # Event:
+    /// * SubnetOwnerTransferred;
+    ///     - On successfully transferring the subnet.
+    ///
+    /// # Raises:
+    /// * 'NoPendingSubnetOwnerTransfer':
+    ///     - There is no transfer pending for this subnet.
+    ///
+    /// * 'NotProposedSubnetOwner':
+    ///     - The caller is not the proposed new owner.
+    ///
+    /// * 'SubnetOwnerTransferNotReady':
+    ///     - The transfer delay has not elapsed yet.
+    ///
+    pub fn do_accept_subnet_owner_transfer(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+    ) -> DispatchResult {
+        // --- 1. Ensure the caller is signed.
+        let coldkey = ensure_signed(origin)?;
+
+        // --- 2. Ensure the caller is the proposed owner and the delay has elapsed.
+        let (new_owner, executable_block) = PendingSubnetOwnerTransfer::<T>::get(netuid)
+            .ok_or(Error::<T>::NoPendingSubnetOwnerTransfer)?;
+        ensure!(new_owner == coldkey, Error::<T>::NotProposedSubnetOwner);
+        ensure!(
+            <frame_system::Pallet<T>>::block_number() >= executable_block,
+            Error::<T>::SubnetOwnerTransferNotReady
+        );
+
+        // --- 3. Transfer the ownership.
+        let old_owner: T::AccountId = SubnetOwner::<T>::get(netuid);
+        SubnetOwner::<T>::insert(netuid, new_owner.clone());
+        PendingSubnetOwnerTransfer::<T>::remove(netuid);
+
+        // --- 4. Emit the event and return.
+        log::debug!(
+            "SubnetOwnerTransferred( netuid:{:?}, old_owner:{:?}, new_owner:{:?} )",
+            netuid,
+            old_owner,
+            new_owner
+        );
+        Self::deposit_event(Event::SubnetOwnerTransferred {
+            netuid,
+            old_owner,
+            new_owner,
+        });
+        Ok(())
+    }
+
+    /// ---- The implementation for the extrinsic cancel_subnet_owner_transfer.
+    ///
+    /// A pending transfer can be cancelled by the current owner, by root, or declined by the
+    /// proposed new owner, at any time before it is accepted.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     - The current owner, the proposed new owner, or root.
+    ///
+    /// * 'netuid' (u16):
+    ///     - The u16 network identifier.
+    ///
+    /// # Event:
+    /// * SubnetOwnerTransferCancelled;
+    ///     - On successfully removing the pending transfer.
+    ///
+    /// # Raises:
+    /// * 'NoPendingSubnetOwnerTransfer':
+    ///     - There is no transfer pending for this subnet.
+    ///
+    /// * 'BadOrigin':
+    ///     - The caller is neither root, the owner nor the proposed owner.
+    ///
+    pub fn do_cancel_subnet_owner_transfer(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+    ) -> DispatchResult {
+        // --- 1. Ensure there is something to cancel.
+        let (new_owner, _) = PendingSubnetOwnerTransfer::<T>::get(netuid)
+            .ok_or(Error::<T>::NoPendingSubnetOwnerTransfer)?;
+
+        // --- 2. Ensure the caller is allowed to cancel.
+        if let Some(who) = ensure_signed_or_root(origin)? {
+            ensure!(
+                who == SubnetOwner::<T>::get(netuid) || who == new_owner,
+                DispatchError::BadOrigin
+            );
+        }
+
+        // --- 3. Remove the pending transfer.
+        PendingSubnetOwnerTransfer::<T>::remove(netuid);
+
+        // --- 4. Emit the event and return.
+        log::debug!(
+            "SubnetOwnerTransferCancelled( netuid:{:?}, new_owner:{:?} )",
+            netuid,
+            new_owner
+        );
+        Self::deposit_event(Event::SubnetOwnerTransferCancelled { netuid, new_owner });
+        Ok(())
+    }
+
+    /// Returns the pending ownership transfer of a subnet, if any, as
+    /// `(new_owner, executable_block)`.
+    pub fn get_pending_subnet_owner_transfer(
+        netuid: u16,
+    ) -> Option<(T::AccountId, BlockNumberFor<T>)> {
+        PendingSubnetOwnerTransfer::<T>::get(netuid)
+    }
+
+    /// Set the duration for subnet owner transfers
+    ///
+    /// # Arguments
+    ///
+    /// * `duration` - The blocks between a transfer proposal and its earliest acceptance.
+    ///
+    /// # Effects
+    ///
+    /// * Update the SubnetOwnerTransferScheduleDuration storage.
+    /// * Emits a SubnetOwnerTransferScheduleDurationSet event.
+    pub fn set_subnet_owner_transfer_schedule_duration(duration: BlockNumberFor<T>) {
+        SubnetOwnerTransferScheduleDuration::<T>::set(duration);
+        Self::deposit_event(Event::SubnetOwnerTransferScheduleDurationSet(duration));
+    }
+
+    /// Redirects pending subnet ownership transfers addressed to a coldkey that is being swapped.
+    pub fn swap_pending_subnet_owner_transfers(
+        old_coldkey: &T::AccountId,
+        new_coldkey: &T::AccountId,
+        weight: &mut Weight,
+    ) {
+        let pending: Vec<(u16, (T::AccountId, BlockNumberFor<T>))> =
+            PendingSubnetOwnerTransfer::<T>::iter().collect();
+        weight.saturating_accrue(T::DbWeight::get().reads(pending.len() as u64));
+        for (netuid, (new_owner, executable_block)) in pending {
+            if new_owner == *old_coldkey {
+                PendingSubnetOwnerTransfer::<T>::insert(
+                    netuid,
+                    (new_coldkey.clone(), executable_block),
+                );
+                weight.saturating_accrue(T::DbWeight::get().writes(1));
+            }
+        }
+    }
+}
@@ -1,3 +1,5 @@
 use super::*;
+pub mod pending_swap;
+pub mod recover_coldkey;
 pub mod swap_coldkey;
 pub mod swap_hotkey;
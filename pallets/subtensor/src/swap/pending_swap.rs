@@ -0,0 +1,244 @@
+use super::*;
+use frame_support::weights::Weight;
+use sp_core::Get;
+
+impl<T: Config> Pallet<T> {
+    /// Returns true if the key is locked by a key swap in progress.
+    ///
+    /// Both the swapped key and the new key are locked until the swap completes. While locked,
+    /// they cannot stake, unstake, set children or be swapped again.
+    pub fn is_key_swapping(key: &T::AccountId) -> bool {
+        KeySwapLocks::<T>::contains_key(key)
+    }
+
+    /// Continues a key swap started by an extrinsic.
+    ///
+    /// The swap moves entries within the weight of the extrinsic. If entries are left, the keys
+    /// are locked and the swap is recorded in `PendingKeySwaps` for `on_idle` to continue.
+    ///
+    /// # Arguments
+    /// * `old_key` - The AccountId of the swapped key.
+    /// * `swap` - The swap returned by `begin_hotkey_swap` or `begin_swap_coldkey`.
+    /// * `weight` - A mutable reference to the Weight, updated as operations are performed.
+    pub fn continue_key_swap_in_call(
+        old_key: &T::AccountId,
+        mut swap: KeySwapProgress<T::AccountId>,
+        weight: &mut Weight,
+    ) {
+        let completed =
+            Self::continue_key_swap(old_key, &mut swap, Self::get_key_swap_call_budget(), weight);
+        if !completed {
+            KeySwapLocks::<T>::insert(old_key, old_key.clone());
+            KeySwapLocks::<T>::insert(&swap.new_key, old_key.clone());
+            weight.saturating_accrue(T::DbWeight::get().writes(2));
+        }
+        Self::record_key_swap_progress(old_key, swap, completed, weight);
+    }
+
+    /// Continues the key swaps in progress until `max_weight` is used.
+    ///
+    /// Called in `on_idle` with the weight left in the block. Returns the weight used.
+    pub fn continue_pending_key_swaps(max_weight: Weight) -> Weight {
+        // Collect only the swaps there is weight left to read, the others wait for a later block.
+        let mut weight = T::DbWeight::get().reads(1);
+        let mut old_keys: Vec<T::AccountId> = Vec::new();
+        for old_key in PendingKeySwaps::<T>::iter_keys() {
+            weight.saturating_accrue(T::DbWeight::get().reads(2));
+            if !weight.all_lt(max_weight) {
+                break;
+            }
+            old_keys.push(old_key);
+        }
+
+        for old_key in old_keys {
+            if !weight.all_lt(max_weight) {
+                break;
+            }
+            let Some(mut swap) = PendingKeySwaps::<T>::get(&old_key) else {
+                continue;
+            };
+
+            let entries_moved = swap.entries_moved;
+            let completed = Self::continue_key_swap(
+                &old_key,
+                &mut swap,
+                max_weight.saturating_sub(weight),
+                &mut weight,
+            );
+            if completed || swap.entries_moved > entries_moved {
+                Self::record_key_swap_progress(&old_key, swap, completed, &mut weight);
+            }
+        }
+
+        weight
+    }
+
+    /// Moves the entries left in a key swap until `max_weight` is used or none are left.
+    ///
+    /// A hotkey swap moves the registrations of the hotkey one subnet at a time, then its stake
+    /// rows. A coldkey swap moves its stake rows. The phase of `swap` is the cursor the next call
+    /// resumes from.
+    ///
+    /// # Arguments
+    /// * `old_key` - The AccountId of the swapped key.
+    /// * `swap` - The swap to continue, updated with the entries moved.
+    /// * `max_weight` - The weight the call may use.
+    /// * `weight` - A mutable reference to the Weight, updated as operations are performed.
+    ///
+    /// # Returns
+    /// * `bool` - True once the swap is complete.
+    pub fn continue_key_swap(
+        old_key: &T::AccountId,
+        swap: &mut KeySwapProgress<T::AccountId>,
+        max_weight: Weight,
+        weight: &mut Weight,
+    ) -> bool {
+        let mut used = Weight::zero();
+        let completed = loop {
+            match swap.phase {
+                KeySwapPhase::HotkeySubnets(from_netuid) => {
+                    let netuid = Self::get_all_subnet_netuids()
+                        .into_iter()
+                        .filter(|netuid| *netuid >= from_netuid)
+                        .min();
+                    used.saturating_accrue(
+                        T::DbWeight::get()
+                            .reads(u64::from(TotalNetworks::<T>::get()).saturating_add(1)),
+                    );
+                    let Some(netuid) = netuid else {
+                        swap.phase = KeySwapPhase::HotkeyStake;
+                        continue;
+                    };
+                    if !used
+                        .saturating_add(Self::get_key_swap_subnet_weight())
+                        .all_lte(max_weight)
+                    {
+                        break false;
+                    }
+
+                    Self::perform_hotkey_swap_on_subnet(old_key, &swap.new_key, netuid, &mut used);
+                    swap.entries_moved = swap.entries_moved.saturating_add(1);
+                    swap.phase = match netuid.checked_add(1) {
+                        Some(next_netuid) => KeySwapPhase::HotkeySubnets(next_netuid),
+                        None => KeySwapPhase::HotkeyStake,
+                    };
+                }
+                KeySwapPhase::HotkeyStake | KeySwapPhase::ColdkeyStake => {
+                    let limit = Self::get_key_swap_rows_within(max_weight.saturating_sub(used));
+                    if limit == 0 {
+                        break false;
+                    }
+
+                    let moved = if swap.phase == KeySwapPhase::HotkeyStake {
+                        Self::swap_hotkey_stake_rows(old_key, &swap.new_key, limit, &mut used)
+                    } else {
+                        Self::swap_coldkey_stake_rows(old_key, &swap.new_key, limit, &mut used)
+                    };
+                    swap.entries_moved = swap.entries_moved.saturating_add(moved);
+                    break moved < limit;
+                }
+            }
+        };
+        if completed {
+            Self::settle_key_swap(old_key, swap, &mut used);
+        }
+        weight.saturating_accrue(used);
+        completed
+    }
+
+    /// Moves what was credited to the old key while its swap was in progress to the new key.
+    ///
+    /// Drains are skipped for a swapping hotkey, so the emission it is given builds up until the
+    /// swap completes. A swapping coldkey keeps compounding its nominator emission, which is added
+    /// to its total stake while rows are still left under it.
+    fn settle_key_swap(
+        old_key: &T::AccountId,
+        swap: &KeySwapProgress<T::AccountId>,
+        weight: &mut Weight,
+    ) {
+        match swap.phase {
+            KeySwapPhase::HotkeySubnets(_) | KeySwapPhase::HotkeyStake => {
+                let pending_emission = PendingdHotkeyEmission::<T>::take(old_key);
+                if pending_emission > 0 {
+                    PendingdHotkeyEmission::<T>::mutate(&swap.new_key, |emission| {
+                        *emission = emission.saturating_add(pending_emission)
+                    });
+                }
+            }
+            KeySwapPhase::ColdkeyStake => {
+                let stranded_stake = TotalColdkeyStake::<T>::take(old_key);
+                if stranded_stake > 0 {
+                    TotalColdkeyStake::<T>::mutate(&swap.new_key, |stake| {
+                        *stake = stake.saturating_add(stranded_stake)
+                    });
+                }
+            }
+        }
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
+    }
+
+    /// Records the progress of a key swap, and emits `KeySwapProgressed`.
+    ///
+    /// A completed swap is removed from `PendingKeySwaps` and its keys are unlocked. If it was
+    /// pending, `KeySwapCompleted` is emitted instead.
+    fn record_key_swap_progress(
+        old_key: &T::AccountId,
+        swap: KeySwapProgress<T::AccountId>,
+        completed: bool,
+        weight: &mut Weight,
+    ) {
+        if !completed {
+            Self::deposit_event(Event::KeySwapProgressed {
+                old_key: old_key.clone(),
+                new_key: swap.new_key.clone(),
+                entries_moved: swap.entries_moved,
+            });
+            PendingKeySwaps::<T>::insert(old_key, swap);
+            weight.saturating_accrue(T::DbWeight::get().writes(1));
+            return;
+        }
+
+        if PendingKeySwaps::<T>::take(old_key).is_some() {
+            KeySwapLocks::<T>::remove(old_key);
+            KeySwapLocks::<T>::remove(&swap.new_key);
+            Self::deposit_event(Event::KeySwapCompleted {
+                old_key: old_key.clone(),
+                new_key: swap.new_key,
+                entries_moved: swap.entries_moved,
+            });
+            weight.saturating_accrue(T::DbWeight::get().writes(2));
+        }
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+    }
+
+    /// The weight a swap extrinsic may use moving entries, the remaining entries are moved in
+    /// `on_idle`.
+    pub fn get_key_swap_call_budget() -> Weight {
+        T::DbWeight::get().reads_writes(128, 256)
+    }
+
+    /// The largest weight moving the registration of a hotkey on one subnet uses, with its
+    /// children and parents.
+    fn get_key_swap_subnet_weight() -> Weight {
+        // The children of the hotkey, and those of its proposal moved in both indexes.
+        let children = u64::from(MAX_CHILDREN_LIMIT).saturating_mul(3);
+        T::DbWeight::get().reads_writes(
+            22u64.saturating_add(children),
+            31u64.saturating_add(children),
+        )
+    }
+
+    /// The number of stake rows which can be moved within `max_weight`.
+    fn get_key_swap_rows_within(max_weight: Weight) -> u32 {
+        let row_weight = T::DbWeight::get().reads_writes(4, 5);
+        let by_ref_time = max_weight
+            .ref_time()
+            .checked_div(row_weight.ref_time())
+            .unwrap_or(u64::MAX);
+        let by_proof_size = max_weight
+            .proof_size()
+            .checked_div(row_weight.proof_size())
+            .unwrap_or(u64::MAX);
+        u32::try_from(by_ref_time.min(by_proof_size)).unwrap_or(u32::MAX)
+    }
+}
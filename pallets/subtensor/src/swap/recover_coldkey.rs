@@ -0,0 +1,285 @@
+use super::*;
+use frame_support::traits::schedule::v3::{Named as ScheduleNamed, TaskName};
+use frame_support::traits::schedule::DispatchTime;
+use frame_support::traits::StorePreimage;
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_io::hashing::blake2_256;
+use sp_runtime::Saturating;
+
+impl<T: Config> Pallet<T> {
+    /// Sets the guardians who can jointly recover a coldkey.
+    ///
+    /// # Arguments
+    ///
+    /// * `origin` - The signature of the coldkey.
+    /// * `guardians` - The guardians of the coldkey. Empty removes the guardians.
+    /// * `threshold` - The number of guardians who must vouch for a recovery.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - A recovery of the coldkey is in progress.
+    /// - There are more than `MAX_RECOVERY_GUARDIANS` guardians, a guardian is repeated or is
+    ///   the coldkey itself.
+    /// - The threshold is zero or above the number of guardians.
+    ///
+    /// # Events
+    ///
+    /// Emits a `RecoveryGuardiansSet` event when successful.
+    pub fn do_set_recovery_guardians(
+        origin: T::RuntimeOrigin,
+        guardians: Vec<T::AccountId>,
+        threshold: u32,
+    ) -> DispatchResult {
+        // 1. Ensure the caller is the coldkey and no recovery is in progress
+        let coldkey = ensure_signed(origin)?;
+        ensure!(
+            !ColdkeyRecoveries::<T>::contains_key(&coldkey),
+            Error::<T>::ColdkeyRecoveryInProgress
+        );
+
+        // 2. Remove the guardians, or ensure they are valid and store them
+        if guardians.is_empty() {
+            ColdkeyRecoveryGuardians::<T>::remove(&coldkey);
+        } else {
+            let mut unique = guardians.clone();
+            unique.sort();
+            unique.dedup();
+            ensure!(
+                guardians.len() <= MAX_RECOVERY_GUARDIANS
+                    && unique.len() == guardians.len()
+                    && !guardians.contains(&coldkey)
+                    && threshold > 0
+                    && threshold as usize <= guardians.len(),
+                Error::<T>::InvalidRecoveryGuardians
+            );
+            ColdkeyRecoveryGuardians::<T>::insert(
+                &coldkey,
+                RecoveryGuardians {
+                    guardians: guardians.clone(),
+                    threshold,
+                },
+            );
+        }
+
+        // 3. Emit the RecoveryGuardiansSet event
+        Self::deposit_event(Event::RecoveryGuardiansSet {
+            coldkey,
+            guardians,
+            threshold,
+        });
+
+        Ok(())
+    }
+
+    /// Vouches for the recovery of a coldkey as one of its guardians.
+    ///
+    /// Vouches are counted per destination, and a guardian vouching for another destination
+    /// moves its vouch. Once the threshold of the guardians has vouched for the same coldkey,
+    /// the swap to it is scheduled `ColdkeySwapScheduleDuration` blocks ahead, during which the
+    /// coldkey can veto it with `veto_coldkey_recovery`.
+    ///
+    /// # Arguments
+    ///
+    /// * `origin` - The signature of the guardian.
+    /// * `coldkey` - The coldkey to recover.
+    /// * `new_coldkey` - The coldkey the recovery swaps to.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The signer is not a guardian of the coldkey.
+    /// - The new coldkey is the coldkey itself.
+    /// - A contested swap of the coldkey is in arbitration.
+    /// - The recovery in progress is already scheduled.
+    /// - The guardian has already vouched for the new coldkey.
+    /// - The scheduler fails to schedule the swap.
+    ///
+    /// # Events
+    ///
+    /// Emits a `ColdkeyRecoveryVouched` event when successful, followed by
+    /// `ColdkeyRecoveryScheduled` once the threshold is reached.
+    pub fn do_vouch_coldkey_recovery(
+        origin: T::RuntimeOrigin,
+        coldkey: T::AccountId,
+        new_coldkey: T::AccountId,
+    ) -> DispatchResult {
+        // 1. Ensure the caller is a guardian of the coldkey, which is not in arbitration
+        let guardian = ensure_signed(origin)?;
+        let guardians = ColdkeyRecoveryGuardians::<T>::get(&coldkey)
+            .filter(|guardians| guardians.guardians.contains(&guardian))
+            .ok_or(Error::<T>::NotRecoveryGuardian)?;
+        ensure!(new_coldkey != coldkey, Error::<T>::SameColdkey);
+        ensure!(
+            !ColdkeySwapDisputes::<T>::contains_key(&coldkey),
+            Error::<T>::ColdkeyIsInArbitration
+        );
+
+        // 2. Continue the recovery in progress, or start a new one. A scheduled recovery whose
+        // task already ran and failed leaves only the record behind, and is started over.
+        let task_name = Self::coldkey_recovery_task_name(&coldkey);
+        let mut recovery = match ColdkeyRecoveries::<T>::get(&coldkey) {
+            Some(recovery)
+                if recovery.scheduled.is_none()
+                    || T::Scheduler::next_dispatch_time(task_name).is_ok() =>
+            {
+                ensure!(
+                    recovery.scheduled.is_none(),
+                    Error::<T>::ColdkeyRecoveryInProgress
+                );
+                recovery
+            }
+            _ => ColdkeyRecovery {
+                vouches: Vec::new(),
+                scheduled: None,
+            },
+        };
+
+        // 3. Record the vouch of the guardian, replacing a vouch for another coldkey
+        ensure!(
+            !recovery
+                .vouches
+                .contains(&(guardian.clone(), new_coldkey.clone())),
+            Error::<T>::ColdkeyRecoveryAlreadyVouched
+        );
+        recovery
+            .vouches
+            .retain(|(vouched_by, _)| *vouched_by != guardian);
+        recovery
+            .vouches
+            .push((guardian.clone(), new_coldkey.clone()));
+        let vouches: usize = recovery
+            .vouches
+            .iter()
+            .filter(|(_, destination)| *destination == new_coldkey)
+            .count();
+
+        Self::deposit_event(Event::ColdkeyRecoveryVouched {
+            coldkey: coldkey.clone(),
+            guardian,
+            new_coldkey: new_coldkey.clone(),
+            vouches: u32::try_from(vouches).unwrap_or(u32::MAX),
+        });
+
+        // 4. Schedule the swap once enough guardians vouched for the new coldkey
+        if u32::try_from(vouches).is_ok_and(|vouches| vouches >= guardians.threshold) {
+            let current_block: BlockNumberFor<T> = <frame_system::Pallet<T>>::block_number();
+            let when: BlockNumberFor<T> =
+                current_block.saturating_add(ColdkeySwapScheduleDuration::<T>::get());
+
+            let call = Call::<T>::recover_coldkey {
+                coldkey: coldkey.clone(),
+                new_coldkey: new_coldkey.clone(),
+            };
+            let bound_call = T::Preimages::bound(LocalCallOf::<T>::from(call))
+                .map_err(|_| Error::<T>::FailedToSchedule)?;
+            T::Scheduler::schedule_named(
+                task_name,
+                DispatchTime::At(when),
+                None,
+                63,
+                frame_system::RawOrigin::Root.into(),
+                bound_call,
+            )
+            .map_err(|_| Error::<T>::FailedToSchedule)?;
+
+            recovery.scheduled = Some((new_coldkey.clone(), when));
+            Self::deposit_event(Event::ColdkeyRecoveryScheduled {
+                coldkey: coldkey.clone(),
+                new_coldkey,
+                execution_block: when,
+            });
+        }
+        ColdkeyRecoveries::<T>::insert(&coldkey, recovery);
+
+        Ok(())
+    }
+
+    /// Vetoes the recovery of a coldkey, cancelling the swap if it is scheduled.
+    ///
+    /// # Arguments
+    ///
+    /// * `origin` - The signature of the coldkey.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - No recovery of the coldkey is in progress.
+    /// - The scheduler fails to cancel the pending task.
+    ///
+    /// # Events
+    ///
+    /// Emits a `ColdkeyRecoveryVetoed` event when successful.
+    pub fn do_veto_coldkey_recovery(origin: T::RuntimeOrigin) -> DispatchResult {
+        // 1. Ensure the caller is the coldkey and take its recovery
+        let coldkey = ensure_signed(origin)?;
+        let recovery =
+            ColdkeyRecoveries::<T>::take(&coldkey).ok_or(Error::<T>::ColdkeyRecoveryNotFound)?;
+
+        // 2. Cancel the task if it is still in the scheduler
+        let task_name = Self::coldkey_recovery_task_name(&coldkey);
+        if T::Scheduler::next_dispatch_time(task_name).is_ok() {
+            T::Scheduler::cancel_named(task_name)
+                .map_err(|_| Error::<T>::FailedToCancelSchedule)?;
+        }
+
+        // 3. Emit the ColdkeyRecoveryVetoed event
+        Self::deposit_event(Event::ColdkeyRecoveryVetoed {
+            coldkey,
+            new_coldkey: recovery.scheduled.map(|(new_coldkey, _)| new_coldkey),
+        });
+
+        Ok(())
+    }
+
+    /// Performs the coldkey swap of a recovery scheduled by `vouch_coldkey_recovery`.
+    ///
+    /// The recovery must still be recorded, so a vetoed recovery, or one replaced by another
+    /// swap of the coldkey, does nothing when its task runs.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The recovery is not scheduled for the new coldkey, or its execution block is not reached.
+    /// - A contested swap of the coldkey is in arbitration.
+    /// - The coldkey swap fails.
+    ///
+    /// # Events
+    ///
+    /// Emits a `ColdkeyRecovered` event when successful, preceded by `ColdkeySwapped`.
+    pub fn do_recover_coldkey(
+        coldkey: &T::AccountId,
+        new_coldkey: &T::AccountId,
+    ) -> DispatchResultWithPostInfo {
+        // 1. Ensure the recovery is due for the new coldkey
+        let current_block: BlockNumberFor<T> = <frame_system::Pallet<T>>::block_number();
+        ensure!(
+            ColdkeyRecoveries::<T>::get(coldkey).is_some_and(|recovery| {
+                recovery.scheduled.is_some_and(|(destination, block)| {
+                    destination == *new_coldkey && block <= current_block
+                })
+            }),
+            Error::<T>::ColdkeyRecoveryNotFound
+        );
+        ensure!(
+            !ColdkeySwapDisputes::<T>::contains_key(coldkey),
+            Error::<T>::ColdkeyIsInArbitration
+        );
+
+        // 2. Swap the coldkey, which clears the recovery
+        let post_info = Self::do_swap_coldkey(coldkey, new_coldkey)?;
+
+        // 3. Emit the ColdkeyRecovered event
+        Self::deposit_event(Event::ColdkeyRecovered {
+            old_coldkey: coldkey.clone(),
+            new_coldkey: new_coldkey.clone(),
+        });
+
+        Ok(post_info)
+    }
+
+    /// Returns the scheduler task name of the recovery of `coldkey`.
+    pub fn coldkey_recovery_task_name(coldkey: &T::AccountId) -> TaskName {
+        (b"subtensor/recover_coldkey", coldkey).using_encoded(blake2_256)
+    }
+}
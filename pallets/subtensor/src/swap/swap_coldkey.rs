@@ -1,6 +1,10 @@
 use super::*;
+use frame_support::traits::schedule::v3::{Named as ScheduleNamed, TaskName};
+use frame_support::traits::EnsureOrigin;
 use frame_support::weights::Weight;
+use frame_system::{ensure_root, ensure_signed_or_root};
 use sp_core::Get;
+use sp_io::hashing::blake2_256;
 
 impl<T: Config> Pallet<T> {
     /// Swaps the coldkey associated with a set of hotkeys from an old coldkey to a new coldkey.
@@ -21,10 +25,13 @@ impl<T: Config> Pallet<T> {
     /// - The old coldkey (caller) is in arbitration.
     /// - The new coldkey is already associated with other hotkeys or is a hotkey itself.
     /// - There's not enough balance to pay for the swap.
+    /// - Either coldkey is locked by a key swap in progress.
+    /// - The scheduler fails to cancel a swap or recovery task left for the old coldkey.
     ///
     /// # Events
     ///
-    /// Emits a `ColdkeySwapped` event when successful.
+    /// Emits a `ColdkeySwapped` event when successful, and `KeySwapProgressed` if stake rows are
+    /// left for `on_idle` to move.
     ///
     /// # Weight
     ///
@@ -41,6 +48,11 @@ impl<T: Config> Pallet<T> {
             Error::<T>::ColdKeyAlreadyAssociated
         );
         weight = weight.saturating_add(T::DbWeight::get().reads(1));
+        ensure!(
+            !Self::is_key_swapping(old_coldkey) && !Self::is_key_swapping(new_coldkey),
+            Error::<T>::KeySwapInProgress
+        );
+        weight = weight.saturating_add(T::DbWeight::get().reads(2));
 
         // 4. Ensure the new coldkey is not a hotkey
         ensure!(
@@ -49,10 +61,13 @@ impl<T: Config> Pallet<T> {
         );
         weight = weight.saturating_add(T::DbWeight::get().reads(1));
 
-        // 5. Swap the identity if the old coldkey has one
+        // 5. Swap the identity and recovery guardians if the old coldkey has them
         if let Some(identity) = Identities::<T>::take(old_coldkey) {
             Identities::<T>::insert(new_coldkey, identity);
         }
+        if let Some(guardians) = ColdkeyRecoveryGuardians::<T>::take(old_coldkey) {
+            ColdkeyRecoveryGuardians::<T>::insert(new_coldkey, guardians);
+        }
 
         // 6. Calculate the swap cost and ensure sufficient balance
         let swap_cost = Self::get_key_swap_cost();
@@ -68,15 +83,30 @@ impl<T: Config> Pallet<T> {
         // 8. Update the weight for the balance operations
         weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
 
-        // 9. Perform the actual coldkey swap
-        let _ = Self::perform_swap_coldkey(old_coldkey, new_coldkey, &mut weight);
+        // 9. Perform the actual coldkey swap, the stake rows left over are moved in `on_idle`
+        let swap = Self::begin_swap_coldkey(old_coldkey, new_coldkey, &mut weight)?;
+        Self::continue_key_swap_in_call(old_coldkey, swap, &mut weight);
 
         // 10. Update the last transaction block for the new coldkey
         Self::set_last_tx_block(new_coldkey, Self::get_current_block_as_u64());
         weight.saturating_accrue(T::DbWeight::get().writes(1));
 
-        // 11. Remove the coldkey swap scheduled record
+        // 11. Remove the coldkey swap scheduled record, any dispute and any recovery of the old
+        // coldkey, cancelling the tasks left in the scheduler
+        for task_name in [
+            Self::coldkey_swap_task_name(old_coldkey),
+            Self::coldkey_recovery_task_name(old_coldkey),
+        ] {
+            if T::Scheduler::next_dispatch_time(task_name).is_ok() {
+                T::Scheduler::cancel_named(task_name)
+                    .map_err(|_| Error::<T>::FailedToCancelSchedule)?;
+            }
+        }
         ColdkeySwapScheduled::<T>::remove(old_coldkey);
+        ScheduledColdkeySwaps::<T>::remove(old_coldkey);
+        ColdkeySwapDisputes::<T>::remove(old_coldkey);
+        ColdkeyRecoveries::<T>::remove(old_coldkey);
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 6));
 
         // 12. Emit the ColdkeySwapped event
         Self::deposit_event(Event::ColdkeySwapped {
@@ -88,8 +118,208 @@ impl<T: Config> Pallet<T> {
         Ok(Some(weight).into())
     }
 
+    /// Cancels a coldkey swap scheduled with `schedule_swap_coldkey`.
+    ///
+    /// # Arguments
+    ///
+    /// * `origin` - Root, or the signature of the coldkey which scheduled the swap.
+    /// * `coldkey` - The coldkey whose scheduled swap is cancelled.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The signer is not the coldkey which scheduled the swap.
+    /// - No named swap is recorded for the coldkey.
+    /// - The scheduler fails to cancel the pending task.
+    ///
+    /// # Events
+    ///
+    /// Emits a `ColdkeySwapCancelled` event when successful.
+    pub fn do_cancel_scheduled_swap_coldkey(
+        origin: T::RuntimeOrigin,
+        coldkey: T::AccountId,
+    ) -> DispatchResult {
+        // 1. Ensure the caller is root or the coldkey itself
+        if let Some(who) = ensure_signed_or_root(origin)? {
+            ensure!(who == coldkey, DispatchError::BadOrigin);
+        }
+
+        // 2. Ensure the swap is not contested and is recorded for the coldkey
+        ensure!(
+            !ColdkeySwapDisputes::<T>::contains_key(&coldkey),
+            Error::<T>::ColdkeyIsInArbitration
+        );
+        let (new_coldkey, _) =
+            ScheduledColdkeySwaps::<T>::get(&coldkey).ok_or(Error::<T>::SwapNotScheduled)?;
+
+        // 3. Cancel the task if it is still in the scheduler. A task which already ran and
+        // failed leaves only the records behind, which are cleared below.
+        let task_name = Self::coldkey_swap_task_name(&coldkey);
+        if T::Scheduler::next_dispatch_time(task_name).is_ok() {
+            T::Scheduler::cancel_named(task_name)
+                .map_err(|_| Error::<T>::FailedToCancelSchedule)?;
+        }
+
+        // 4. Remove the scheduled records
+        ColdkeySwapScheduled::<T>::remove(&coldkey);
+        ScheduledColdkeySwaps::<T>::remove(&coldkey);
+
+        // 5. Emit the ColdkeySwapCancelled event
+        Self::deposit_event(Event::ColdkeySwapCancelled {
+            old_coldkey: coldkey,
+            new_coldkey,
+        });
+
+        Ok(())
+    }
+
+    /// Handles a swap request for a coldkey which already has a swap scheduled.
+    ///
+    /// A request to a different destination freezes the scheduled swap: the scheduler task is
+    /// cancelled and every requested destination is recorded in `ColdkeySwapDisputes` until
+    /// governance resolves the dispute. While in arbitration the coldkey keeps its
+    /// `ColdkeySwapScheduled` flag, so transfers stay locked, and staking is rejected by the
+    /// signed extension.
+    ///
+    /// Each contesting request burns the key swap cost from the coldkey right away, so that
+    /// filling a dispute is not free. The swap chosen when the dispute is resolved burns the cost
+    /// again when it runs, as any scheduled swap does.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The destination was already requested.
+    /// - The dispute already records `MAX_COLDKEY_SWAP_DESTINATIONS` destinations.
+    /// - There's not enough balance to pay for the swap.
+    /// - The scheduler fails to cancel the pending task.
+    ///
+    /// # Events
+    ///
+    /// Emits a `ColdkeySwapContested` event when successful.
+    pub fn do_contest_swap_coldkey(
+        old_coldkey: &T::AccountId,
+        new_coldkey: &T::AccountId,
+    ) -> DispatchResult {
+        let destinations = match ColdkeySwapDisputes::<T>::get(old_coldkey) {
+            // 1. Already in arbitration, record the additional destination.
+            Some(mut destinations) => {
+                ensure!(
+                    !destinations.contains(new_coldkey),
+                    Error::<T>::SwapAlreadyScheduled
+                );
+                ensure!(
+                    destinations.len() < MAX_COLDKEY_SWAP_DESTINATIONS,
+                    Error::<T>::MaxColdkeyDestinationsReached
+                );
+                destinations.push(new_coldkey.clone());
+                destinations
+            }
+            // 2. First conflicting request, freeze the scheduled swap.
+            None => {
+                let (scheduled_coldkey, _) = ScheduledColdkeySwaps::<T>::get(old_coldkey)
+                    .ok_or(Error::<T>::SwapAlreadyScheduled)?;
+                ensure!(
+                    scheduled_coldkey != *new_coldkey,
+                    Error::<T>::SwapAlreadyScheduled
+                );
+
+                let task_name = Self::coldkey_swap_task_name(old_coldkey);
+                if T::Scheduler::next_dispatch_time(task_name).is_ok() {
+                    T::Scheduler::cancel_named(task_name)
+                        .map_err(|_| Error::<T>::FailedToCancelSchedule)?;
+                }
+                ScheduledColdkeySwaps::<T>::remove(old_coldkey);
+
+                vec![scheduled_coldkey, new_coldkey.clone()]
+            }
+        };
+
+        // 3. Burn the swap cost from the coldkey for the request.
+        let swap_cost = Self::get_key_swap_cost();
+        ensure!(
+            Self::can_remove_balance_from_coldkey_account(old_coldkey, swap_cost),
+            Error::<T>::NotEnoughBalanceToPaySwapColdKey
+        );
+        let actual_burn_amount = Self::remove_balance_from_coldkey_account(old_coldkey, swap_cost)?;
+        Self::burn_tokens(actual_burn_amount);
+        ColdkeySwapDisputes::<T>::insert(old_coldkey, destinations);
+
+        // 4. Emit the ColdkeySwapContested event
+        Self::deposit_event(Event::ColdkeySwapContested {
+            old_coldkey: old_coldkey.clone(),
+            new_coldkey: new_coldkey.clone(),
+        });
+
+        Ok(())
+    }
+
+    /// Resolves a contested coldkey swap.
+    ///
+    /// # Arguments
+    ///
+    /// * `origin` - Root or the council origin.
+    /// * `old_coldkey` - The coldkey in arbitration.
+    /// * `new_coldkey` - The destination chosen by governance, one of the contested destinations.
+    ///   `None` rejects every request, clears the scheduled flag and releases the coldkey.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The origin is neither root nor the council.
+    /// - The coldkey is not in arbitration.
+    /// - The chosen destination is not one of the contested destinations.
+    /// - The coldkey swap to the chosen destination fails.
+    ///
+    /// # Events
+    ///
+    /// Emits a `ColdkeySwapDisputeResolved` event when successful, preceded by `ColdkeySwapped`
+    /// when a destination is chosen.
+    pub fn do_resolve_coldkey_swap_dispute(
+        origin: T::RuntimeOrigin,
+        old_coldkey: T::AccountId,
+        new_coldkey: Option<T::AccountId>,
+    ) -> DispatchResultWithPostInfo {
+        // 1. Ensure the caller is root or the council
+        ensure_root(origin.clone())
+            .or_else(|_| T::CouncilOrigin::ensure_origin(origin).map(|_| ()))?;
+
+        // 2. Ensure the coldkey is in arbitration for the chosen destination and release it
+        let destinations = ColdkeySwapDisputes::<T>::take(&old_coldkey)
+            .ok_or(Error::<T>::ColdkeyNotInArbitration)?;
+        if let Some(new_coldkey) = &new_coldkey {
+            ensure!(
+                destinations.contains(new_coldkey),
+                Error::<T>::NotContestedColdkeySwapDestination
+            );
+        }
+
+        // 3. Swap to the chosen destination, or drop the scheduled flag
+        let post_info = match &new_coldkey {
+            Some(new_coldkey) => Self::do_swap_coldkey(&old_coldkey, new_coldkey)?,
+            None => {
+                ColdkeySwapScheduled::<T>::remove(&old_coldkey);
+                ().into()
+            }
+        };
+
+        // 4. Emit the ColdkeySwapDisputeResolved event
+        Self::deposit_event(Event::ColdkeySwapDisputeResolved {
+            old_coldkey,
+            new_coldkey,
+        });
+
+        Ok(post_info)
+    }
+
+    /// Returns the scheduler task name of the coldkey swap scheduled for `coldkey`.
+    pub fn coldkey_swap_task_name(coldkey: &T::AccountId) -> TaskName {
+        (b"subtensor/swap_coldkey", coldkey).using_encoded(blake2_256)
+    }
+
     /// Performs the actual coldkey swap operation, transferring all associated data and balances from the old coldkey to the new coldkey.
     ///
+    /// The whole swap is performed at once, see `begin_swap_coldkey` and `continue_key_swap`.
+    ///
     /// # Arguments
     ///
     /// * `old_coldkey` - The account ID of the old coldkey.
@@ -100,6 +330,32 @@ impl<T: Config> Pallet<T> {
     ///
     /// Returns a `DispatchResult` indicating success or failure of the operation.
     ///
+    /// # Notes
+    ///
+    /// This function is a critical part of the coldkey swap process and should be called only after all necessary checks and validations have been performed.
+    pub fn perform_swap_coldkey(
+        old_coldkey: &T::AccountId,
+        new_coldkey: &T::AccountId,
+        weight: &mut Weight,
+    ) -> DispatchResult {
+        let mut swap = Self::begin_swap_coldkey(old_coldkey, new_coldkey, weight)?;
+        Self::continue_key_swap(old_coldkey, &mut swap, Weight::MAX, weight);
+        Ok(())
+    }
+
+    /// Begins the coldkey swap operation, transferring the data and balances of the old coldkey
+    /// which do not grow with the hotkeys it stakes to.
+    ///
+    /// # Arguments
+    ///
+    /// * `old_coldkey` - The account ID of the old coldkey.
+    /// * `new_coldkey` - The account ID of the new coldkey.
+    /// * `weight` - A mutable reference to the current transaction weight.
+    ///
+    /// # Returns
+    ///
+    /// Returns the swap left to continue, or an error if any operation failed.
+    ///
     /// # Steps
     ///
     /// 1. Swap TotalHotkeyColdkeyStakesThisInterval:
@@ -108,32 +364,26 @@ impl<T: Config> Pallet<T> {
     /// 2. Swap subnet ownership:
     ///    - For each subnet, if the old coldkey is the owner, transfer ownership to the new coldkey.
     ///
-    /// 3. Swap Stakes:
-    ///    - For each hotkey staking for the old coldkey, transfer its stake to the new coldkey.
-    ///
-    /// 4. Swap total coldkey stake:
+    /// 3. Swap total coldkey stake:
     ///    - Transfer the total stake from the old coldkey to the new coldkey.
     ///
-    /// 5. Swap StakingHotkeys:
-    ///    - Transfer the list of staking hotkeys from the old coldkey to the new coldkey.
-    ///
-    /// 6. Swap hotkey owners:
+    /// 4. Swap hotkey owners:
     ///    - For each hotkey owned by the old coldkey, transfer ownership to the new coldkey.
     ///    - Update the list of owned hotkeys for both old and new coldkeys.
     ///
-    /// 7. Transfer remaining balance:
+    /// 5. Transfer remaining balance:
     ///    - Transfer any remaining balance from the old coldkey to the new coldkey.
     ///
-    /// Throughout the process, the function updates the transaction weight to reflect the operations performed.
-    ///
-    /// # Notes
+    /// 6. Transfer unbonding stake:
+    ///    - Merge the unbonding queue of the old coldkey into the queue of the new coldkey.
     ///
-    /// This function is a critical part of the coldkey swap process and should be called only after all necessary checks and validations have been performed.
-    pub fn perform_swap_coldkey(
+    /// The stake rows and staking hotkeys of the old coldkey are moved by `continue_key_swap`,
+    /// see `swap_coldkey_stake_rows`.
+    pub fn begin_swap_coldkey(
         old_coldkey: &T::AccountId,
         new_coldkey: &T::AccountId,
         weight: &mut Weight,
-    ) -> DispatchResult {
+    ) -> Result<KeySwapProgress<T::AccountId>, DispatchError> {
         // 1. Swap TotalHotkeyColdkeyStakesThisInterval
         // TotalHotkeyColdkeyStakesThisInterval: MAP ( hotkey, coldkey ) --> ( stake, block ) | Stake of the hotkey for the coldkey.
         for hotkey in OwnedHotkeys::<T>::get(old_coldkey).iter() {
@@ -153,23 +403,10 @@ impl<T: Config> Pallet<T> {
             }
             weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
         }
+        // Pending ownership transfers addressed to the old coldkey follow it.
+        Self::swap_pending_subnet_owner_transfers(old_coldkey, new_coldkey, weight);
 
-        // 3. Swap Stake.
-        // Stake: MAP ( hotkey, coldkey ) --> u64 | Stake of the hotkey for the coldkey.
-        for hotkey in StakingHotkeys::<T>::get(old_coldkey) {
-            // Get the stake on the old (hot,coldkey) account.
-            let old_stake: u64 = Stake::<T>::get(&hotkey, old_coldkey);
-            // Get the stake on the new (hot,coldkey) account.
-            let new_stake: u64 = Stake::<T>::get(&hotkey, new_coldkey);
-            // Add the stake to new account.
-            Stake::<T>::insert(&hotkey, new_coldkey, new_stake.saturating_add(old_stake));
-            // Remove the value from the old account.
-            Stake::<T>::remove(&hotkey, old_coldkey);
-            // Add the weight for the read and write.
-            weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
-        }
-
-        // 4. Swap total coldkey stake.
+        // 3. Swap total coldkey stake.
         // TotalColdkeyStake: MAP ( coldkey ) --> u64 | Total stake of the coldkey.
         let old_coldkey_stake: u64 = TotalColdkeyStake::<T>::get(old_coldkey);
         // Get the stake of the new coldkey.
@@ -183,21 +420,7 @@ impl<T: Config> Pallet<T> {
         );
         weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
 
-        // 5. Swap StakingHotkeys.
-        // StakingHotkeys: MAP ( coldkey ) --> Vec<hotkeys> | Hotkeys staking for the coldkey.
-        let old_staking_hotkeys: Vec<T::AccountId> = StakingHotkeys::<T>::get(old_coldkey);
-        let mut new_staking_hotkeys: Vec<T::AccountId> = StakingHotkeys::<T>::get(new_coldkey);
-        for hotkey in old_staking_hotkeys {
-            // If the hotkey is not already in the new coldkey, add it.
-            if !new_staking_hotkeys.contains(&hotkey) {
-                new_staking_hotkeys.push(hotkey);
-            }
-        }
-        StakingHotkeys::<T>::remove(old_coldkey);
-        StakingHotkeys::<T>::insert(new_coldkey, new_staking_hotkeys);
-        weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
-
-        // 6. Swap hotkey owners.
+        // 4. Swap hotkey owners.
         // Owner: MAP ( hotkey ) --> coldkey | Owner of the hotkey.
         // OwnedHotkeys: MAP ( coldkey ) --> Vec<hotkeys> | Hotkeys owned by the coldkey.
         let old_owned_hotkeys: Vec<T::AccountId> = OwnedHotkeys::<T>::get(old_coldkey);
@@ -216,7 +439,7 @@ impl<T: Config> Pallet<T> {
         OwnedHotkeys::<T>::insert(new_coldkey, new_owned_hotkeys);
         weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
 
-        // 7. Transfer remaining balance.
+        // 5. Transfer remaining balance.
         // Balance: MAP ( coldkey ) --> u64 | Balance of the coldkey.
         // Transfer any remaining balance from old_coldkey to new_coldkey
         let remaining_balance = Self::get_coldkey_balance(old_coldkey);
@@ -226,7 +449,84 @@ impl<T: Config> Pallet<T> {
         }
         weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
 
-        // Return ok.
-        Ok(())
+        // 6. Transfer unbonding stake.
+        // UnbondingQueue: MAP ( coldkey ) --> Vec<(amount, unlock_block)> | Removed stake waiting to unbond.
+        let old_unbonding = UnbondingQueue::<T>::take(old_coldkey);
+        if !old_unbonding.is_empty() {
+            UnbondingQueue::<T>::mutate(new_coldkey, |queue| {
+                for (amount, unlock_block) in old_unbonding {
+                    Self::queue_unbonding_chunk(queue, amount, unlock_block);
+                }
+            });
+        }
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
+
+        // The stake rows are left to continue.
+        Ok(KeySwapProgress {
+            new_key: new_coldkey.clone(),
+            phase: KeySwapPhase::ColdkeyStake,
+            entries_moved: 0,
+        })
+    }
+
+    /// Transfers the stake rows of up to `limit` hotkeys staked to by a coldkey to a new coldkey.
+    ///
+    /// Moved hotkeys are removed from the staking hotkeys of the old coldkey, so the ones left
+    /// are still to move.
+    ///
+    /// # Arguments
+    ///
+    /// * `old_coldkey` - The account ID of the old coldkey.
+    /// * `new_coldkey` - The account ID of the new coldkey.
+    /// * `limit` - The maximum number of rows to move.
+    /// * `weight` - A mutable reference to the current transaction weight.
+    ///
+    /// # Returns
+    ///
+    /// The number of rows moved.
+    pub fn swap_coldkey_stake_rows(
+        old_coldkey: &T::AccountId,
+        new_coldkey: &T::AccountId,
+        limit: u32,
+        weight: &mut Weight,
+    ) -> u32 {
+        // StakingHotkeys: MAP ( coldkey ) --> Vec<hotkeys> | Hotkeys staking for the coldkey.
+        let mut old_staking_hotkeys: Vec<T::AccountId> = StakingHotkeys::<T>::get(old_coldkey);
+        let mut new_staking_hotkeys: Vec<T::AccountId> = StakingHotkeys::<T>::get(new_coldkey);
+        weight.saturating_accrue(T::DbWeight::get().reads(2));
+
+        let count = old_staking_hotkeys.len().min(limit as usize);
+        for hotkey in old_staking_hotkeys.drain(..count) {
+            // Stake: MAP ( hotkey, coldkey ) --> u64 | Stake of the hotkey for the coldkey.
+            // Get the stake on the old (hot,coldkey) account.
+            let old_stake: u64 = Stake::<T>::get(&hotkey, old_coldkey);
+            // Get the stake on the new (hot,coldkey) account.
+            let new_stake: u64 = Stake::<T>::get(&hotkey, new_coldkey);
+            // Add the stake to new account.
+            Stake::<T>::insert(&hotkey, new_coldkey, new_stake.saturating_add(old_stake));
+            // Remove the value from the old account.
+            Stake::<T>::remove(&hotkey, old_coldkey);
+            // RewardPayouts: MAP ( hotkey, coldkey ) --> payout | Where the nominator emission is paid.
+            if let Some(payout) = RewardPayouts::<T>::take(&hotkey, old_coldkey) {
+                RewardPayouts::<T>::insert(&hotkey, new_coldkey, payout);
+                weight.saturating_accrue(T::DbWeight::get().writes(2));
+            }
+            // If the hotkey is not already in the new coldkey, add it.
+            if !new_staking_hotkeys.contains(&hotkey) {
+                new_staking_hotkeys.push(hotkey);
+            }
+            // Add the weight for the read and write.
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(3, 2));
+        }
+
+        if old_staking_hotkeys.is_empty() {
+            StakingHotkeys::<T>::remove(old_coldkey);
+        } else {
+            StakingHotkeys::<T>::insert(old_coldkey, old_staking_hotkeys);
+        }
+        StakingHotkeys::<T>::insert(new_coldkey, new_staking_hotkeys);
+        weight.saturating_accrue(T::DbWeight::get().writes(2));
+
+        count as u32
     }
 }
@@ -22,6 +22,7 @@ impl<T: Config> Pallet<T> {
     /// * `NewHotKeyIsSameWithOld` - If the new hotkey is the same as the old hotkey.
     /// * `HotKeyAlreadyRegisteredInSubNet` - If the new hotkey is already registered in the subnet.
     /// * `NotEnoughBalanceToPaySwapHotKey` - If there is not enough balance to pay for the swap.
+    /// * `KeySwapInProgress` - If either hotkey is locked by a key swap in progress.
     pub fn do_swap_hotkey(
         origin: T::RuntimeOrigin,
         old_hotkey: &T::AccountId,
@@ -41,6 +42,10 @@ impl<T: Config> Pallet<T> {
             !Self::is_hotkey_registered_on_any_network(new_hotkey),
             Error::<T>::HotKeyAlreadyRegisteredInSubNet
         );
+        ensure!(
+            !Self::is_key_swapping(old_hotkey) && !Self::is_key_swapping(new_hotkey),
+            Error::<T>::KeySwapInProgress
+        );
 
         // 5. Update the weight for the checks above
         weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 0));
@@ -81,8 +86,9 @@ impl<T: Config> Pallet<T> {
         // 13. Burn the tokens
         Self::burn_tokens(actual_burn_amount);
 
-        // 14. Perform the hotkey swap
-        let _ = Self::perform_hotkey_swap(old_hotkey, new_hotkey, &coldkey, &mut weight);
+        // 14. Perform the hotkey swap, the entries left over are moved in `on_idle`
+        let swap = Self::begin_hotkey_swap(old_hotkey, new_hotkey, &coldkey, &mut weight)?;
+        Self::continue_key_swap_in_call(old_hotkey, swap, &mut weight);
 
         // 15. Update the last transaction block for the coldkey
         Self::set_last_tx_block(&coldkey, block);
@@ -99,27 +105,151 @@ impl<T: Config> Pallet<T> {
         Ok(Some(weight).into())
     }
 
-    /// Performs the hotkey swap operation, transferring all associated data and state from the old hotkey to the new hotkey.
+    /// Swaps the hotkey of a coldkey account on a single subnet.
     ///
-    /// This function executes a series of steps to ensure a complete transfer of all relevant information:
-    /// 1. Swaps the owner of the hotkey.
-    /// 2. Updates the list of owned hotkeys for the coldkey.
-    /// 3. Transfers the total hotkey stake.
-    /// 4. Moves all stake-related data for the interval.
-    /// 5. Updates the last transaction block for the new hotkey.
-    /// 6. Transfers the delegate take information.
-    /// 7. Swaps Senate membership if applicable.
-    /// 8. Updates delegate information.
-    /// 9. For each subnet:
-    ///    - Updates network membership status.
-    ///    - Transfers UID and key information.
-    ///    - Moves Prometheus data.
-    ///    - Updates axon information.
-    ///    - Transfers weight commits.
-    ///    - Updates loaded emission data.
-    /// 10. Transfers all stake information, including updating staking hotkeys for each coldkey.
+    /// The registration of the old hotkey on the subnet is moved to the new hotkey, which the
+    /// coldkey owns afterwards. The old hotkey keeps its registrations on other subnets, and its
+    /// stake unless `move_stake` is set.
+    ///
+    /// # Arguments
+    ///
+    /// * `origin` - The origin of the transaction, and also the coldkey account.
+    /// * `old_hotkey` - The hotkey registered on the subnet.
+    /// * `new_hotkey` - The hotkey to take its place on the subnet.
+    /// * `netuid` - The subnet to swap the hotkey on.
+    /// * `move_stake` - Whether the stake, delegate take and senate seat move to the new hotkey.
+    ///
+    /// # Returns
+    ///
+    /// * `DispatchResultWithPostInfo` - The result of the dispatch.
     ///
-    /// Throughout the process, the function accumulates the computational weight of operations performed.
+    /// # Errors
+    ///
+    /// * `NewHotKeyIsSameWithOld` - If the new hotkey is the same as the old hotkey.
+    /// * `SubNetworkDoesNotExist` - If the subnet does not exist.
+    /// * `NonAssociatedColdKey` - If the coldkey does not own the old hotkey, or the new hotkey is
+    ///   owned by another coldkey.
+    /// * `HotKeyNotRegisteredInSubNet` - If the old hotkey is not registered on the subnet.
+    /// * `HotKeyAlreadyRegisteredInSubNet` - If the new hotkey is already registered on the subnet.
+    /// * `NewHotKeyHasChildkeys` - If the new hotkey has children, parents or proposed children on
+    ///   the subnet.
+    /// * `NewHotKeyHasStake` - If the stake is moved and the new hotkey already has stake.
+    /// * `HotKeySetTxRateLimitExceeded` - If the transaction rate limit is exceeded.
+    /// * `NotEnoughBalanceToPaySwapHotKey` - If there is not enough balance to pay for the swap.
+    /// * `KeySwapInProgress` - If either hotkey is locked by a key swap in progress.
+    pub fn do_swap_hotkey_on_subnet(
+        origin: T::RuntimeOrigin,
+        old_hotkey: &T::AccountId,
+        new_hotkey: &T::AccountId,
+        netuid: u16,
+        move_stake: bool,
+    ) -> DispatchResultWithPostInfo {
+        // 1. Ensure the origin is signed and get the coldkey
+        let coldkey = ensure_signed(origin)?;
+
+        // 2. Initialize the weight for this operation
+        let mut weight = T::DbWeight::get().reads(2);
+
+        // 3. Ensure the new hotkey is different from the old one, and neither is being swapped
+        ensure!(old_hotkey != new_hotkey, Error::<T>::NewHotKeyIsSameWithOld);
+        ensure!(
+            !Self::is_key_swapping(old_hotkey) && !Self::is_key_swapping(new_hotkey),
+            Error::<T>::KeySwapInProgress
+        );
+
+        // 4. Ensure the subnet exists
+        ensure!(
+            Self::if_subnet_exist(netuid),
+            Error::<T>::SubNetworkDoesNotExist
+        );
+
+        // 5. Ensure the coldkey owns the old hotkey, and the new hotkey if it exists
+        ensure!(
+            Self::coldkey_owns_hotkey(&coldkey, old_hotkey),
+            Error::<T>::NonAssociatedColdKey
+        );
+        ensure!(
+            !Self::hotkey_account_exists(new_hotkey)
+                || Self::coldkey_owns_hotkey(&coldkey, new_hotkey),
+            Error::<T>::NonAssociatedColdKey
+        );
+
+        // 6. Ensure only the old hotkey is registered on the subnet
+        ensure!(
+            IsNetworkMember::<T>::get(old_hotkey, netuid),
+            Error::<T>::HotKeyNotRegisteredInSubNet
+        );
+        ensure!(
+            !IsNetworkMember::<T>::get(new_hotkey, netuid),
+            Error::<T>::HotKeyAlreadyRegisteredInSubNet
+        );
+        weight.saturating_accrue(T::DbWeight::get().reads(5));
+
+        // 7. Ensure the new hotkey has no childkeys on the subnet to be merged with the old ones,
+        // nor stake if the stake is moved
+        ensure!(
+            ChildKeys::<T>::get(new_hotkey, netuid).is_empty()
+                && ParentKeys::<T>::get(new_hotkey, netuid).is_empty()
+                && !PendingChildKeys::<T>::contains_key(new_hotkey, netuid)
+                && !PendingParentKeys::<T>::contains_key(new_hotkey, netuid),
+            Error::<T>::NewHotKeyHasChildkeys
+        );
+        ensure!(
+            !move_stake || TotalHotkeyStake::<T>::get(new_hotkey) == 0,
+            Error::<T>::NewHotKeyHasStake
+        );
+        weight.saturating_accrue(T::DbWeight::get().reads(5));
+
+        // 8. Ensure the transaction rate limit is not exceeded
+        let block: u64 = Self::get_current_block_as_u64();
+        ensure!(
+            !Self::exceeds_tx_rate_limit(Self::get_last_tx_block(&coldkey), block),
+            Error::<T>::HotKeySetTxRateLimitExceeded
+        );
+
+        // 9. Ensure the coldkey can pay for the swap, and burn the cost
+        let swap_cost = Self::get_key_swap_cost();
+        ensure!(
+            Self::can_remove_balance_from_coldkey_account(&coldkey, swap_cost),
+            Error::<T>::NotEnoughBalanceToPaySwapHotKey
+        );
+        let actual_burn_amount = Self::remove_balance_from_coldkey_account(&coldkey, swap_cost)?;
+        Self::burn_tokens(actual_burn_amount);
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+
+        // 10. The coldkey owns the new hotkey alongside the old one
+        Self::create_account_if_non_existent(&coldkey, new_hotkey);
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(3, 4));
+
+        // 11. Swap the hotkey on the subnet
+        Self::perform_hotkey_swap_on_subnet(old_hotkey, new_hotkey, netuid, &mut weight);
+
+        // 12. Move the stake if asked, the senate seat follows the stake
+        if move_stake {
+            Self::swap_hotkey_stake(old_hotkey, new_hotkey, &mut weight);
+            Self::swap_senate_member(old_hotkey, new_hotkey, &mut weight)?;
+        }
+
+        // 13. Update the last transaction block for the coldkey
+        Self::set_last_tx_block(&coldkey, block);
+        weight.saturating_accrue(T::DbWeight::get().writes(1));
+
+        // 14. Emit an event for the hotkey swap
+        Self::deposit_event(Event::HotkeySwappedOnSubnet {
+            coldkey,
+            old_hotkey: old_hotkey.clone(),
+            new_hotkey: new_hotkey.clone(),
+            netuid,
+            move_stake,
+        });
+
+        // 15. Return the weight of the operation
+        Ok(Some(weight).into())
+    }
+
+    /// Performs the hotkey swap operation, transferring all associated data and state from the old hotkey to the new hotkey.
+    ///
+    /// The whole swap is performed at once, see `begin_hotkey_swap` and `continue_key_swap`.
     ///
     /// # Arguments
     /// * `old_hotkey` - The AccountId of the current hotkey to be replaced.
@@ -139,6 +269,39 @@ impl<T: Config> Pallet<T> {
         coldkey: &T::AccountId,
         weight: &mut Weight,
     ) -> DispatchResult {
+        let mut swap = Self::begin_hotkey_swap(old_hotkey, new_hotkey, coldkey, weight)?;
+        Self::continue_key_swap(old_hotkey, &mut swap, Weight::MAX, weight);
+        Ok(())
+    }
+
+    /// Begins the hotkey swap operation, transferring the state of the old hotkey which does not
+    /// grow with its subnets and nominators.
+    ///
+    /// This function executes the following steps:
+    /// 1. Swaps the owner of the hotkey.
+    /// 2. Updates the list of owned hotkeys for the coldkey.
+    /// 3. Transfers the total stake, delegate take and drain history, see `swap_hotkey_stake_totals`.
+    /// 4. Updates the last transaction block for the new hotkey.
+    /// 5. Updates the last delegate take transaction block for the new hotkey.
+    /// 6. Swaps Senate membership if applicable.
+    ///
+    /// The registrations on each subnet and the stake rows are moved by `continue_key_swap`,
+    /// starting from the returned swap.
+    ///
+    /// # Arguments
+    /// * `old_hotkey` - The AccountId of the current hotkey to be replaced.
+    /// * `new_hotkey` - The AccountId of the new hotkey to replace the old one.
+    /// * `coldkey` - The AccountId of the coldkey that owns both hotkeys.
+    /// * `weight` - A mutable reference to the Weight, updated as operations are performed.
+    ///
+    /// # Returns
+    /// * `Result<KeySwapProgress, DispatchError>` - The swap left to continue, or an error if any operation failed.
+    pub fn begin_hotkey_swap(
+        old_hotkey: &T::AccountId,
+        new_hotkey: &T::AccountId,
+        coldkey: &T::AccountId,
+        weight: &mut Weight,
+    ) -> Result<KeySwapProgress<T::AccountId>, DispatchError> {
         // 1. Swap owner.
         // Owner( hotkey ) -> coldkey -- the coldkey that owns the hotkey.
         Owner::<T>::remove(old_hotkey);
@@ -157,7 +320,67 @@ impl<T: Config> Pallet<T> {
         OwnedHotkeys::<T>::insert(coldkey, hotkeys);
         weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
 
-        // 3. Swap total hotkey stake.
+        // 3. Swap the total stake, delegate take and drain history.
+        Self::swap_hotkey_stake_totals(old_hotkey, new_hotkey, weight);
+
+        // 4. Swap LastTxBlock
+        // LastTxBlock( hotkey ) --> u64 -- the last transaction block for the hotkey.
+        LastTxBlock::<T>::remove(old_hotkey);
+        LastTxBlock::<T>::insert(new_hotkey, Self::get_current_block_as_u64());
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(0, 2));
+
+        // 5. Swap LastTxBlockDelegateTake
+        // LastTxBlockDelegateTake( hotkey ) --> u64 -- the last transaction block for the hotkey delegate take.
+        LastTxBlockDelegateTake::<T>::remove(old_hotkey);
+        LastTxBlockDelegateTake::<T>::insert(new_hotkey, Self::get_current_block_as_u64());
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+
+        // 6. Swap Senate members.
+        // Senate( hotkey ) --> ?
+        if T::SenateMembers::is_member(old_hotkey) {
+            T::SenateMembers::swap_member(old_hotkey, new_hotkey).map_err(|e| e.error)?;
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+        }
+
+        // The subnet specific info and stake rows are left to continue.
+        Ok(KeySwapProgress {
+            new_key: new_hotkey.clone(),
+            phase: KeySwapPhase::HotkeySubnets(0),
+            entries_moved: 0,
+        })
+    }
+
+    /// Transfers the stake of a hotkey to a new hotkey, along with its delegate take and drain history.
+    ///
+    /// The stake is added to any stake the new hotkey already has.
+    ///
+    /// # Arguments
+    /// * `old_hotkey` - The AccountId of the hotkey the stake is moved from.
+    /// * `new_hotkey` - The AccountId of the hotkey the stake is moved to.
+    /// * `weight` - A mutable reference to the Weight, updated as operations are performed.
+    pub fn swap_hotkey_stake(
+        old_hotkey: &T::AccountId,
+        new_hotkey: &T::AccountId,
+        weight: &mut Weight,
+    ) {
+        Self::swap_hotkey_stake_totals(old_hotkey, new_hotkey, weight);
+        Self::swap_hotkey_stake_rows(old_hotkey, new_hotkey, u32::MAX, weight);
+    }
+
+    /// Transfers the total stake of a hotkey to a new hotkey, along with its delegate take and drain history.
+    ///
+    /// The stake rows of the nominators are moved by `swap_hotkey_stake_rows`.
+    ///
+    /// # Arguments
+    /// * `old_hotkey` - The AccountId of the hotkey the stake is moved from.
+    /// * `new_hotkey` - The AccountId of the hotkey the stake is moved to.
+    /// * `weight` - A mutable reference to the Weight, updated as operations are performed.
+    pub fn swap_hotkey_stake_totals(
+        old_hotkey: &T::AccountId,
+        new_hotkey: &T::AccountId,
+        weight: &mut Weight,
+    ) {
+        // 1. Swap total hotkey stake.
         // TotalHotkeyStake( hotkey ) -> stake -- the total stake that the hotkey has across all delegates.
         let old_total_hotkey_stake = TotalHotkeyStake::<T>::get(old_hotkey); // Get the old total hotkey stake.
         let new_total_hotkey_stake = TotalHotkeyStake::<T>::get(new_hotkey); // Get the new total hotkey stake.
@@ -168,7 +391,7 @@ impl<T: Config> Pallet<T> {
         ); // Insert the new total hotkey stake via the addition.
         weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
 
-        // 4. Swap total hotkey stakes.
+        // 2. Swap total hotkey stakes.
         // TotalHotkeyColdkeyStakesThisInterval( hotkey ) --> (u64: stakes, u64: block_number)
         let stake_tuples: Vec<(T::AccountId, (u64, u64))> =
             TotalHotkeyColdkeyStakesThisInterval::<T>::iter_prefix(old_hotkey).collect();
@@ -179,26 +402,7 @@ impl<T: Config> Pallet<T> {
             weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
         }
 
-        // 5. Swap LastTxBlock
-        // LastTxBlock( hotkey ) --> u64 -- the last transaction block for the hotkey.
-        LastTxBlock::<T>::remove(old_hotkey);
-        LastTxBlock::<T>::insert(new_hotkey, Self::get_current_block_as_u64());
-        weight.saturating_accrue(T::DbWeight::get().reads_writes(0, 2));
-
-        // 6. Swap LastTxBlockDelegateTake
-        // LastTxBlockDelegateTake( hotkey ) --> u64 -- the last transaction block for the hotkey delegate take.
-        LastTxBlockDelegateTake::<T>::remove(old_hotkey);
-        LastTxBlockDelegateTake::<T>::insert(new_hotkey, Self::get_current_block_as_u64());
-        weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
-
-        // 7. Swap Senate members.
-        // Senate( hotkey ) --> ?
-        if T::SenateMembers::is_member(old_hotkey) {
-            T::SenateMembers::swap_member(old_hotkey, new_hotkey).map_err(|e| e.error)?;
-            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
-        }
-
-        // 8. Swap delegates.
+        // 3. Swap delegates.
         // Delegates( hotkey ) -> take value -- the hotkey delegate take value.
         if Delegates::<T>::contains_key(old_hotkey) {
             let old_delegate_take = Delegates::<T>::get(old_hotkey);
@@ -206,145 +410,252 @@ impl<T: Config> Pallet<T> {
             Delegates::<T>::insert(new_hotkey, old_delegate_take);
             weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
         }
-        // 9. Swap all subnet specific info.
-        let all_netuids: Vec<u16> = Self::get_all_subnet_netuids();
-        for netuid in all_netuids {
-            // 9.1 Remove the previous hotkey and insert the new hotkey from membership.
-            // IsNetworkMember( hotkey, netuid ) -> bool -- is the hotkey a subnet member.
-            let is_network_member: bool = IsNetworkMember::<T>::get(old_hotkey, netuid);
-            IsNetworkMember::<T>::remove(old_hotkey, netuid);
-            IsNetworkMember::<T>::insert(new_hotkey, netuid, is_network_member);
-            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
-
-            // 9.2 Swap Uids + Keys.
-            // Keys( netuid, hotkey ) -> uid -- the uid the hotkey has in the network if it is a member.
-            // Uids( netuid, hotkey ) -> uid -- the uids that the hotkey has.
-            if is_network_member {
-                // 9.2.1 Swap the UIDS
-                if let Ok(old_uid) = Uids::<T>::try_get(netuid, old_hotkey) {
-                    Uids::<T>::remove(netuid, old_hotkey);
-                    Uids::<T>::insert(netuid, new_hotkey, old_uid);
-                    weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
-
-                    // 9.2.2 Swap the keys.
-                    Keys::<T>::insert(netuid, old_uid, new_hotkey.clone());
-                    weight.saturating_accrue(T::DbWeight::get().reads_writes(0, 1));
-                }
-            }
-
-            // 9.3 Swap Prometheus.
-            // Prometheus( netuid, hotkey ) -> prometheus -- the prometheus data that a hotkey has in the network.
-            if is_network_member {
-                if let Ok(old_prometheus_info) = Prometheus::<T>::try_get(netuid, old_hotkey) {
-                    Prometheus::<T>::remove(netuid, old_hotkey);
-                    Prometheus::<T>::insert(netuid, new_hotkey, old_prometheus_info);
-                    weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
-                }
-            }
-
-            // 9.4. Swap axons.
-            // Axons( netuid, hotkey ) -> axon -- the axon that the hotkey has.
-            if is_network_member {
-                if let Ok(old_axon_info) = Axons::<T>::try_get(netuid, old_hotkey) {
-                    Axons::<T>::remove(netuid, old_hotkey);
-                    Axons::<T>::insert(netuid, new_hotkey, old_axon_info);
-                    weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
-                }
-            }
-
-            // 9.5 Swap WeightCommits
-            // WeightCommits( hotkey ) --> Vec<u64> -- the weight commits for the hotkey.
-            if is_network_member {
-                if let Ok(old_weight_commits) = WeightCommits::<T>::try_get(netuid, old_hotkey) {
-                    WeightCommits::<T>::remove(netuid, old_hotkey);
-                    WeightCommits::<T>::insert(netuid, new_hotkey, old_weight_commits);
-                    weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
-                }
-            }
-
-            // 9.6. Swap the subnet loaded emission.
-            // LoadedEmission( netuid ) --> Vec<(hotkey, u64)> -- the loaded emission for the subnet.
-            if is_network_member {
-                if let Some(mut old_loaded_emission) = LoadedEmission::<T>::get(netuid) {
-                    for emission in old_loaded_emission.iter_mut() {
-                        if emission.0 == *old_hotkey {
-                            emission.0 = new_hotkey.clone();
-                        }
-                    }
-                    LoadedEmission::<T>::remove(netuid);
-                    LoadedEmission::<T>::insert(netuid, old_loaded_emission);
-                    weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
-                }
-            }
+        // PendingDelegateTakes( hotkey ) -> (take, effective_block) -- the announced take increase.
+        if let Some((take, effective_block)) = Self::cancel_pending_delegate_take(old_hotkey) {
+            Self::schedule_pending_delegate_take(new_hotkey, take, effective_block);
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(3, 4));
         }
+        weight.saturating_accrue(T::DbWeight::get().reads(1));
+        // HotkeyDrainHistory( hotkey ) -> Vec<HotkeyDrainRecord> -- the daily totals of the hotkey drains.
+        let drain_history = HotkeyDrainHistory::<T>::take(old_hotkey);
+        if !drain_history.is_empty() {
+            HotkeyDrainHistory::<T>::insert(new_hotkey, drain_history);
+        }
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+    }
 
-        // 10. Swap Stake.
+    /// Transfers up to `limit` stake rows of a hotkey to a new hotkey.
+    ///
+    /// Moved rows are removed from the old hotkey, so the rows left are the ones still to move.
+    ///
+    /// # Arguments
+    /// * `old_hotkey` - The AccountId of the hotkey the stake is moved from.
+    /// * `new_hotkey` - The AccountId of the hotkey the stake is moved to.
+    /// * `limit` - The maximum number of rows to move.
+    /// * `weight` - A mutable reference to the Weight, updated as operations are performed.
+    ///
+    /// # Returns
+    /// * `u32` - The number of rows moved.
+    pub fn swap_hotkey_stake_rows(
+        old_hotkey: &T::AccountId,
+        new_hotkey: &T::AccountId,
+        limit: u32,
+        weight: &mut Weight,
+    ) -> u32 {
         // Stake( hotkey, coldkey ) -> stake -- the stake that the hotkey controls on behalf of the coldkey.
-        let stakes: Vec<(T::AccountId, u64)> = Stake::<T>::iter_prefix(old_hotkey).collect();
-        // Clear the entire old prefix here.
-        let _ = Stake::<T>::clear_prefix(old_hotkey, stakes.len() as u32, None);
-        // Iterate over all the staking rows and insert them into the new hotkey.
-        for (coldkey, old_stake_amount) in stakes {
+        let stakes: Vec<(T::AccountId, u64)> = Stake::<T>::iter_prefix(old_hotkey)
+            .take(limit as usize)
+            .collect();
+        // Iterate over the staking rows and insert them into the new hotkey.
+        for (coldkey, old_stake_amount) in stakes.iter() {
             weight.saturating_accrue(T::DbWeight::get().reads(1));
 
             // Swap Stake value
             // Stake( hotkey, coldkey ) -> stake -- the stake that the hotkey controls on behalf of the coldkey.
             // Get the new stake value.
-            let new_stake_value: u64 = Stake::<T>::get(new_hotkey, &coldkey);
-            // Insert the new stake value.
+            let new_stake_value: u64 = Stake::<T>::get(new_hotkey, coldkey);
+            // Remove the old row and insert the new stake value.
+            Stake::<T>::remove(old_hotkey, coldkey);
             Stake::<T>::insert(
                 new_hotkey,
-                &coldkey,
-                new_stake_value.saturating_add(old_stake_amount),
+                coldkey,
+                new_stake_value.saturating_add(*old_stake_amount),
             );
-            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+
+            // Swap RewardPayouts.
+            // RewardPayouts( hotkey, coldkey ) -> payout -- where the nominator emission of the coldkey is paid.
+            if let Some(payout) = RewardPayouts::<T>::take(old_hotkey, coldkey) {
+                RewardPayouts::<T>::insert(new_hotkey, coldkey, payout);
+                weight.saturating_accrue(T::DbWeight::get().writes(2));
+            }
+            weight.saturating_accrue(T::DbWeight::get().reads(1));
 
             // Swap StakingHotkeys.
             // StakingHotkeys( coldkey ) --> Vec<hotkey> -- the hotkeys that the coldkey stakes.
-            let mut staking_hotkeys = StakingHotkeys::<T>::get(&coldkey);
+            let mut staking_hotkeys = StakingHotkeys::<T>::get(coldkey);
             staking_hotkeys.retain(|hk| *hk != *old_hotkey && *hk != *new_hotkey);
             staking_hotkeys.push(new_hotkey.clone());
             StakingHotkeys::<T>::insert(coldkey.clone(), staking_hotkeys);
             weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
         }
+        stakes.len() as u32
+    }
+
+    /// Transfers the registration of a hotkey on one subnet to a new hotkey.
+    ///
+    /// This moves the network membership, UID, Prometheus and axon information, weight commits and
+    /// loaded emission of the hotkey, and replaces it in the childkeys, parent keys and childkey
+    /// proposals of the subnet.
+    ///
+    /// # Arguments
+    /// * `old_hotkey` - The AccountId of the hotkey registered on the subnet.
+    /// * `new_hotkey` - The AccountId of the hotkey to take its place.
+    /// * `netuid` - The subnet to swap the hotkey on.
+    /// * `weight` - A mutable reference to the Weight, updated as operations are performed.
+    pub fn perform_hotkey_swap_on_subnet(
+        old_hotkey: &T::AccountId,
+        new_hotkey: &T::AccountId,
+        netuid: u16,
+        weight: &mut Weight,
+    ) {
+        // 1. Remove the previous hotkey and insert the new hotkey from membership.
+        // IsNetworkMember( hotkey, netuid ) -> bool -- is the hotkey a subnet member.
+        let is_network_member: bool = IsNetworkMember::<T>::get(old_hotkey, netuid);
+        IsNetworkMember::<T>::remove(old_hotkey, netuid);
+        IsNetworkMember::<T>::insert(new_hotkey, netuid, is_network_member);
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+
+        // 2. Swap Uids + Keys.
+        // Keys( netuid, hotkey ) -> uid -- the uid the hotkey has in the network if it is a member.
+        // Uids( netuid, hotkey ) -> uid -- the uids that the hotkey has.
+        if is_network_member {
+            // 2.1 Swap the UIDS
+            if let Ok(old_uid) = Uids::<T>::try_get(netuid, old_hotkey) {
+                Uids::<T>::remove(netuid, old_hotkey);
+                Uids::<T>::insert(netuid, new_hotkey, old_uid);
+                weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+
+                // 2.2 Swap the keys.
+                Keys::<T>::insert(netuid, old_uid, new_hotkey.clone());
+                weight.saturating_accrue(T::DbWeight::get().reads_writes(0, 1));
+            }
+        }
+
+        // 3. Swap Prometheus.
+        // Prometheus( netuid, hotkey ) -> prometheus -- the prometheus data that a hotkey has in the network.
+        if is_network_member {
+            if let Ok(old_prometheus_info) = Prometheus::<T>::try_get(netuid, old_hotkey) {
+                Prometheus::<T>::remove(netuid, old_hotkey);
+                Prometheus::<T>::insert(netuid, new_hotkey, old_prometheus_info);
+                weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+            }
+        }
+
+        // 4. Swap axons.
+        // Axons( netuid, hotkey ) -> axon -- the axon that the hotkey has.
+        if is_network_member {
+            if let Ok(old_axon_info) = Axons::<T>::try_get(netuid, old_hotkey) {
+                Axons::<T>::remove(netuid, old_hotkey);
+                Axons::<T>::insert(netuid, new_hotkey, old_axon_info);
+                weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+            }
+        }
 
-        // 11. Swap ChildKeys.
+        // 5. Swap WeightCommits
+        // WeightCommits( hotkey ) --> Vec<u64> -- the weight commits for the hotkey.
+        if is_network_member {
+            if let Ok(old_weight_commits) = WeightCommits::<T>::try_get(netuid, old_hotkey) {
+                WeightCommits::<T>::remove(netuid, old_hotkey);
+                WeightCommits::<T>::insert(netuid, new_hotkey, old_weight_commits);
+                weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+            }
+        }
+
+        // 6. Swap the subnet loaded emission.
+        // LoadedEmission( netuid ) --> Vec<(hotkey, u64)> -- the loaded emission for the subnet.
+        if is_network_member {
+            if let Some(mut old_loaded_emission) = LoadedEmission::<T>::get(netuid) {
+                for emission in old_loaded_emission.iter_mut() {
+                    if emission.0 == *old_hotkey {
+                        emission.0 = new_hotkey.clone();
+                    }
+                }
+                LoadedEmission::<T>::remove(netuid);
+                LoadedEmission::<T>::insert(netuid, old_loaded_emission);
+                weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+            }
+        }
+
+        // 7. Swap ChildKeys.
         // ChildKeys( parent, netuid ) --> Vec<(proportion,child)> -- the child keys of the parent.
-        for netuid in Self::get_all_subnet_netuids() {
-            // Get the children of the old hotkey for this subnet
-            let my_children: Vec<(u64, T::AccountId)> = ChildKeys::<T>::get(old_hotkey, netuid);
-            // Remove the old hotkey's child entries
-            ChildKeys::<T>::remove(old_hotkey, netuid);
-            // Insert the same child entries for the new hotkey
-            ChildKeys::<T>::insert(new_hotkey, netuid, my_children);
+        let my_children: Vec<(u64, T::AccountId)> = ChildKeys::<T>::get(old_hotkey, netuid);
+        ChildKeys::<T>::remove(old_hotkey, netuid);
+        ChildKeys::<T>::insert(new_hotkey, netuid, my_children.clone());
+        for (_, child_key_i) in my_children {
+            // For each child, update their parents list
+            let mut child_parents: Vec<(u64, T::AccountId)> =
+                ParentKeys::<T>::get(child_key_i.clone(), netuid);
+            for parent in child_parents.iter_mut() {
+                // If the parent is the old hotkey, replace it with the new hotkey
+                if parent.1 == *old_hotkey {
+                    parent.1 = new_hotkey.clone();
+                }
+            }
+            ParentKeys::<T>::insert(child_key_i, netuid, child_parents);
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
         }
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
 
-        // 12. Swap ParentKeys.
+        // 8. Swap ParentKeys.
         // ParentKeys( child, netuid ) --> Vec<(proportion,parent)> -- the parent keys of the child.
-        for netuid in Self::get_all_subnet_netuids() {
-            // Get the parents of the old hotkey for this subnet
-            let parents: Vec<(u64, T::AccountId)> = ParentKeys::<T>::get(old_hotkey, netuid);
-            // Remove the old hotkey's parent entries
-            ParentKeys::<T>::remove(old_hotkey, netuid);
-            // Insert the same parent entries for the new hotkey
-            ParentKeys::<T>::insert(new_hotkey, netuid, parents.clone());
-            for (_, parent_key_i) in parents {
-                // For each parent, update their children list
-                let mut parent_children: Vec<(u64, T::AccountId)> =
-                    ChildKeys::<T>::get(parent_key_i.clone(), netuid);
-                for child in parent_children.iter_mut() {
-                    // If the child is the old hotkey, replace it with the new hotkey
-                    if child.1 == *old_hotkey {
-                        child.1 = new_hotkey.clone();
-                    }
+        let parents: Vec<(u64, T::AccountId)> = ParentKeys::<T>::get(old_hotkey, netuid);
+        ParentKeys::<T>::remove(old_hotkey, netuid);
+        ParentKeys::<T>::insert(new_hotkey, netuid, parents.clone());
+        for (_, parent_key_i) in parents {
+            // For each parent, update their children list
+            let mut parent_children: Vec<(u64, T::AccountId)> =
+                ChildKeys::<T>::get(parent_key_i.clone(), netuid);
+            for child in parent_children.iter_mut() {
+                // If the child is the old hotkey, replace it with the new hotkey
+                if child.1 == *old_hotkey {
+                    child.1 = new_hotkey.clone();
                 }
-                // Update the parent's children list
-                ChildKeys::<T>::insert(parent_key_i, netuid, parent_children);
             }
+            ChildKeys::<T>::insert(parent_key_i, netuid, parent_children);
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+        }
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+
+        // 9. Swap PendingChildKeys.
+        // PendingChildKeys( parent, netuid ) --> ChildkeyProposal -- the children awaiting consent.
+        if let Some(proposal) = Self::remove_childkey_proposal(old_hotkey, netuid) {
+            // The proposal is moved along with its expiry entry and the entry of each child.
+            let children = (proposal.children.len() as u64).saturating_mul(2);
+            Self::insert_childkey_proposal(new_hotkey, netuid, proposal);
+            weight.saturating_accrue(
+                T::DbWeight::get()
+                    .reads_writes(children.saturating_add(3), children.saturating_add(4)),
+            );
         }
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
 
-        // Return successful after swapping all the relevant terms.
-        Ok(())
+        // 10. Swap the hotkey in the proposals naming it as a child.
+        // PendingParentKeys( child, netuid ) --> Vec<parent> -- the parents proposing the child.
+        let pending_parents: Vec<T::AccountId> = PendingParentKeys::<T>::get(old_hotkey, netuid);
+        weight.saturating_accrue(T::DbWeight::get().reads(1));
+        for parent_i in pending_parents {
+            let Some(mut proposal) = Self::remove_childkey_proposal(&parent_i, netuid) else {
+                continue;
+            };
+            let children = (proposal.children.len() as u64).saturating_mul(2);
+            weight.saturating_accrue(
+                T::DbWeight::get()
+                    .reads_writes(children.saturating_add(3), children.saturating_add(4)),
+            );
+
+            // A proposal already naming the new hotkey would name it twice, so it is dropped.
+            if proposal
+                .children
+                .iter()
+                .any(|(_, child_i)| child_i == new_hotkey)
+            {
+                continue;
+            }
+
+            // The coldkey owning both hotkeys keeps its consent.
+            for child in proposal.children.iter_mut() {
+                if child.1 == *old_hotkey {
+                    child.1 = new_hotkey.clone();
+                }
+            }
+            for child in proposal.accepted.iter_mut() {
+                if *child == *old_hotkey {
+                    *child = new_hotkey.clone();
+                }
+            }
+            Self::insert_childkey_proposal(&parent_i, netuid, proposal);
+        }
     }
 
     pub fn swap_senate_member(
@@ -681,6 +681,14 @@ impl<T: Config> Pallet<T> {
         AlphaValues::<T>::get(netuid)
     }
 
+    pub fn set_alpha_low(netuid: u16, alpha_low: u16) {
+        AlphaValues::<T>::mutate(netuid, |(low, _)| *low = alpha_low);
+    }
+
+    pub fn set_alpha_high(netuid: u16, alpha_high: u16) {
+        AlphaValues::<T>::mutate(netuid, |(_, high)| *high = alpha_high);
+    }
+
     pub fn get_alpha_values_32(netuid: u16) -> (I32F32, I32F32) {
         let (alpha_low, alpha_high): (u16, u16) = AlphaValues::<T>::get(netuid);
         let converted_low = I32F32::from_num(alpha_low).saturating_div(I32F32::from_num(u16::MAX));
@@ -715,6 +723,62 @@ impl<T: Config> Pallet<T> {
         Self::deposit_event(Event::HotkeyEmissionTempoSet(emission_tempo));
     }
 
+    /// Gets the number of blocks between automatic senate rebalances.
+    ///
+    /// # Returns
+    /// * `u64` - The current senate adjustment interval, 0 when disabled.
+    pub fn get_senate_adjustment_interval() -> u64 {
+        SenateAdjustmentInterval::<T>::get()
+    }
+
+    /// Sets the number of blocks between automatic senate rebalances.
+    ///
+    /// # Arguments
+    /// * `interval` - The new senate adjustment interval, 0 disables the rebalance.
+    pub fn set_senate_adjustment_interval(interval: u64) {
+        SenateAdjustmentInterval::<T>::set(interval);
+        Self::deposit_event(Event::SenateAdjustmentIntervalSet(interval));
+    }
+
+    pub fn get_delegate_take_notice_period() -> u64 {
+        DelegateTakeNoticePeriod::<T>::get()
+    }
+
+    /// Set the delegate take notice period
+    ///
+    /// # Arguments
+    /// * `period` - The blocks between a take increase and its enactment, 0 applies them immediately.
+    pub fn set_delegate_take_notice_period(period: u64) {
+        DelegateTakeNoticePeriod::<T>::set(period);
+        Self::deposit_event(Event::DelegateTakeNoticePeriodSet(period));
+    }
+
+    pub fn get_unbonding_period() -> u64 {
+        UnbondingPeriod::<T>::get()
+    }
+
+    /// Set the unbonding period
+    ///
+    /// # Arguments
+    /// * `period` - The blocks before removed stake can be withdrawn, 0 returns it immediately.
+    pub fn set_unbonding_period(period: u64) {
+        UnbondingPeriod::<T>::set(period);
+        Self::deposit_event(Event::UnbondingPeriodSet(period));
+    }
+
+    pub fn get_senate_stake_weighted_voting() -> bool {
+        SenateStakeWeightedVoting::<T>::get()
+    }
+
+    /// Set whether senate motions are tallied by the stake of the members
+    ///
+    /// # Arguments
+    /// * `enabled` - Whether motions proposed from now on are tallied by stake.
+    pub fn set_senate_stake_weighted_voting(enabled: bool) {
+        SenateStakeWeightedVoting::<T>::set(enabled);
+        Self::deposit_event(Event::SenateStakeWeightedVotingSet(enabled));
+    }
+
     pub fn get_pending_hotkey_emission(hotkey: &T::AccountId) -> u64 {
         PendingdHotkeyEmission::<T>::get(hotkey)
     }
@@ -751,6 +815,57 @@ impl<T: Config> Pallet<T> {
         Self::deposit_event(Event::NetworkMaxStakeSet(netuid, max_stake));
     }
 
+    /// Returns the maximum number of children of a hotkey on a subnet, at most
+    /// `MAX_CHILDREN_LIMIT`.
+    pub fn get_max_children(netuid: u16) -> u16 {
+        MaxChildren::<T>::get(netuid).min(MAX_CHILDREN_LIMIT)
+    }
+    pub fn set_max_children(netuid: u16, max_children: u16) {
+        MaxChildren::<T>::insert(netuid, max_children);
+        Self::deposit_event(Event::MaxChildrenSet(netuid, max_children));
+    }
+
+    /// Returns the minimum proportion of each child on a subnet.
+    pub fn get_min_child_proportion(netuid: u16) -> u64 {
+        MinChildProportion::<T>::get(netuid)
+    }
+    pub fn set_min_child_proportion(netuid: u16, proportion: u64) {
+        MinChildProportion::<T>::insert(netuid, proportion);
+        Self::deposit_event(Event::MinChildProportionSet(netuid, proportion));
+    }
+
+    /// Returns the blocks between setting the children of a hotkey on a subnet, at least
+    /// `MIN_SET_CHILDREN_COOLDOWN`.
+    pub fn get_set_children_cooldown(netuid: u16) -> u64 {
+        SetChildrenCooldown::<T>::get(netuid).max(MIN_SET_CHILDREN_COOLDOWN)
+    }
+    pub fn set_set_children_cooldown(netuid: u16, cooldown: u64) {
+        SetChildrenCooldown::<T>::insert(netuid, cooldown);
+        Self::deposit_event(Event::SetChildrenCooldownSet(netuid, cooldown));
+    }
+
+    /// Returns the lower bound of the childkey take on a subnet, which follows the global
+    /// bound until the subnet sets its own and is never below it.
+    pub fn get_subnet_min_childkey_take(netuid: u16) -> u16 {
+        let global_min = Self::get_min_childkey_take();
+        SubnetMinChildkeyTake::<T>::get(netuid).map_or(global_min, |take| take.max(global_min))
+    }
+    pub fn set_subnet_min_childkey_take(netuid: u16, take: u16) {
+        SubnetMinChildkeyTake::<T>::insert(netuid, take);
+        Self::deposit_event(Event::SubnetMinChildkeyTakeSet(netuid, take));
+    }
+
+    /// Returns the upper bound of the childkey take on a subnet, which follows the global
+    /// bound until the subnet sets its own and is never above it.
+    pub fn get_subnet_max_childkey_take(netuid: u16) -> u16 {
+        let global_max = Self::get_max_childkey_take();
+        SubnetMaxChildkeyTake::<T>::get(netuid).map_or(global_max, |take| take.min(global_max))
+    }
+    pub fn set_subnet_max_childkey_take(netuid: u16, take: u16) {
+        SubnetMaxChildkeyTake::<T>::insert(netuid, take);
+        Self::deposit_event(Event::SubnetMaxChildkeyTakeSet(netuid, take));
+    }
+
     /// Set the duration for coldkey swap
     ///
     /// # Arguments
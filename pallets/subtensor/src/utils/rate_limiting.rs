@@ -43,6 +43,14 @@ impl<T: Config> Pallet<T> {
         }
     }
 
+    /// Get the rate limit for a specific transaction type on a subnet
+    pub fn get_rate_limit_on_subnet(tx_type: &TransactionType, netuid: u16) -> u64 {
+        match tx_type {
+            TransactionType::SetChildren => Self::get_set_children_cooldown(netuid),
+            _ => Self::get_rate_limit(tx_type),
+        }
+    }
+
     /// Check if a transaction should be rate limited on a specific subnet
     pub fn passes_rate_limit_on_subnet(
         tx_type: &TransactionType,
@@ -50,7 +58,7 @@ impl<T: Config> Pallet<T> {
         netuid: u16,
     ) -> bool {
         let block: u64 = Self::get_current_block_as_u64();
-        let limit: u64 = Self::get_rate_limit(tx_type);
+        let limit: u64 = Self::get_rate_limit_on_subnet(tx_type, netuid);
         let last_block: u64 = Self::get_last_transaction_block(hotkey, netuid, tx_type);
 
         // Allow the first transaction (when last_block is 0) or if the rate limit has passed
@@ -1,12 +1,13 @@
 use super::*;
 
 impl<T: Config> Pallet<T> {
-    /// Checks if the accounting invariants for [`TotalStake`], [`TotalSubnetLocked`], and [`TotalIssuance`] are correct.
+    /// Checks if the accounting invariants for [`TotalStake`], [`TotalUnbonding`], [`TotalSubnetLocked`], and [`TotalIssuance`] are correct.
     ///
     /// This function verifies that:
     /// 1. The sum of all stakes matches the [`TotalStake`].
-    /// 2. The [`TotalSubnetLocked`] is correctly calculated.
-    /// 3. The [`TotalIssuance`] equals the sum of currency issuance, total stake, and total subnet locked.
+    /// 2. The sum of all unbonding queues matches the [`TotalUnbonding`].
+    /// 3. The [`TotalSubnetLocked`] is correctly calculated.
+    /// 4. The [`TotalIssuance`] equals the sum of currency issuance, total stake, total unbonding, and total subnet locked.
     ///
     /// # Returns
     ///
@@ -27,6 +28,20 @@ impl<T: Config> Pallet<T> {
             "TotalStake does not match total staked",
         );
 
+        // Calculate the total unbonding amount
+        let mut total_unbonding: u64 = 0;
+        for (_coldkey, queue) in UnbondingQueue::<T>::iter() {
+            for (amount, _unlock_block) in queue {
+                total_unbonding = total_unbonding.saturating_add(amount);
+            }
+        }
+
+        // Verify that the calculated total unbonding matches the stored TotalUnbonding
+        ensure!(
+            total_unbonding == TotalUnbonding::<T>::get(),
+            "TotalUnbonding does not match total unbonding",
+        );
+
         // Get the total subnet locked amount
         let total_subnet_locked: u64 = Self::get_total_subnet_locked();
 
@@ -36,6 +51,7 @@ impl<T: Config> Pallet<T> {
         // Calculate the expected total issuance
         let expected_total_issuance: u64 = currency_issuance
             .saturating_add(total_staked)
+            .saturating_add(total_unbonding)
             .saturating_add(total_subnet_locked);
 
         // Verify that the calculated total issuance matches the stored TotalIssuance
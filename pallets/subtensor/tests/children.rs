@@ -1,5 +1,6 @@
 #![allow(clippy::indexing_slicing)]
 use crate::mock::*;
+use codec::Encode;
 use frame_support::{assert_err, assert_noop, assert_ok};
 mod mock;
 use pallet_subtensor::{utils::rate_limiting::TransactionType, *};
@@ -19,6 +20,8 @@ fn test_do_set_child_singular_success() {
         // Add network and register hotkey
         add_network(netuid, 13, 0);
         register_ok_neuron(netuid, hotkey, coldkey, 0);
+        // The children belong to the coldkey of the hotkey, so they need no consent
+        SubtensorModule::create_account_if_non_existent(&coldkey, &child);
 
         // Set child
         assert_ok!(SubtensorModule::do_set_children(
@@ -163,6 +166,9 @@ fn test_do_set_child_singular_old_children_cleanup() {
         // Add network and register hotkey
         add_network(netuid, 13, 0);
         register_ok_neuron(netuid, hotkey, coldkey, 0);
+        // The children belong to the coldkey of the hotkey, so they need no consent
+        SubtensorModule::create_account_if_non_existent(&coldkey, &old_child);
+        SubtensorModule::create_account_if_non_existent(&coldkey, &new_child);
 
         // Set old child
         assert_ok!(SubtensorModule::do_set_children(
@@ -209,6 +215,8 @@ fn test_do_set_child_singular_new_children_assignment() {
         // Add network and register hotkey
         add_network(netuid, 13, 0);
         register_ok_neuron(netuid, hotkey, coldkey, 0);
+        // The children belong to the coldkey of the hotkey, so they need no consent
+        SubtensorModule::create_account_if_non_existent(&coldkey, &child);
 
         // Set child
         assert_ok!(SubtensorModule::do_set_children(
@@ -246,6 +254,8 @@ fn test_do_set_child_singular_proportion_edge_cases() {
         // Add network and register hotkey
         add_network(netuid, 13, 0);
         register_ok_neuron(netuid, hotkey, coldkey, 0);
+        // The children belong to the coldkey of the hotkey, so they need no consent
+        SubtensorModule::create_account_if_non_existent(&coldkey, &child);
 
         // Set child with minimum proportion
         let min_proportion: u64 = 0;
@@ -297,6 +307,9 @@ fn test_do_set_child_singular_multiple_children() {
         // Add network and register hotkey
         add_network(netuid, 13, 0);
         register_ok_neuron(netuid, hotkey, coldkey, 0);
+        // The children belong to the coldkey of the hotkey, so they need no consent
+        SubtensorModule::create_account_if_non_existent(&coldkey, &child1);
+        SubtensorModule::create_account_if_non_existent(&coldkey, &child2);
 
         // Set first child
         assert_ok!(SubtensorModule::do_set_children(
@@ -417,6 +430,12 @@ fn test_get_stake_for_hotkey_on_subnet() {
             netuid,
             vec![(u64::MAX, child)]
         ));
+        assert_ok!(SubtensorModule::do_accept_child(
+            RuntimeOrigin::signed(coldkey2),
+            parent,
+            child,
+            netuid
+        ));
 
         let parent_stake = SubtensorModule::get_stake_for_hotkey_on_subnet(&parent, netuid);
         let child_stake = SubtensorModule::get_stake_for_hotkey_on_subnet(&child, netuid);
@@ -454,6 +473,8 @@ fn test_do_revoke_child_singular_success() {
         // Add network and register hotkey
         add_network(netuid, 13, 0);
         register_ok_neuron(netuid, hotkey, coldkey, 0);
+        // The children belong to the coldkey of the hotkey, so they need no consent
+        SubtensorModule::create_account_if_non_existent(&coldkey, &child);
 
         // Set child
         assert_ok!(SubtensorModule::do_set_children(
@@ -590,6 +611,9 @@ fn test_do_set_children_multiple_success() {
         // Add network and register hotkey
         add_network(netuid, 13, 0);
         register_ok_neuron(netuid, hotkey, coldkey, 0);
+        // The children belong to the coldkey of the hotkey, so they need no consent
+        SubtensorModule::create_account_if_non_existent(&coldkey, &child1);
+        SubtensorModule::create_account_if_non_existent(&coldkey, &child2);
 
         // Set multiple children
         assert_ok!(SubtensorModule::do_set_children(
@@ -755,6 +779,10 @@ fn test_do_set_children_multiple_old_children_cleanup() {
         // Add network and register hotkey
         add_network(netuid, 13, 0);
         register_ok_neuron(netuid, hotkey, coldkey, 0);
+        // The children belong to the coldkey of the hotkey, so they need no consent
+        SubtensorModule::create_account_if_non_existent(&coldkey, &old_child);
+        SubtensorModule::create_account_if_non_existent(&coldkey, &new_child1);
+        SubtensorModule::create_account_if_non_existent(&coldkey, &new_child2);
 
         // Set old child
         assert_ok!(SubtensorModule::do_set_children(
@@ -803,6 +831,9 @@ fn test_do_set_children_multiple_proportion_edge_cases() {
         // Add network and register hotkey
         add_network(netuid, 13, 0);
         register_ok_neuron(netuid, hotkey, coldkey, 0);
+        // The children belong to the coldkey of the hotkey, so they need no consent
+        SubtensorModule::create_account_if_non_existent(&coldkey, &child1);
+        SubtensorModule::create_account_if_non_existent(&coldkey, &child2);
 
         // Set children with minimum and maximum proportions
         let min_proportion: u64 = 0;
@@ -845,6 +876,10 @@ fn test_do_set_children_multiple_overwrite_existing() {
         // Add network and register hotkey
         add_network(netuid, 13, 0);
         register_ok_neuron(netuid, hotkey, coldkey, 0);
+        // The children belong to the coldkey of the hotkey, so they need no consent
+        SubtensorModule::create_account_if_non_existent(&coldkey, &child1);
+        SubtensorModule::create_account_if_non_existent(&coldkey, &child2);
+        SubtensorModule::create_account_if_non_existent(&coldkey, &child3);
 
         // Set initial children
         assert_ok!(SubtensorModule::do_set_children(
@@ -1190,6 +1225,9 @@ fn test_do_revoke_children_multiple_success() {
         // Add network and register hotkey
         add_network(netuid, 13, 0);
         register_ok_neuron(netuid, hotkey, coldkey, 0);
+        // The children belong to the coldkey of the hotkey, so they need no consent
+        SubtensorModule::create_account_if_non_existent(&coldkey, &child1);
+        SubtensorModule::create_account_if_non_existent(&coldkey, &child2);
 
         // Set multiple children
         assert_ok!(SubtensorModule::do_set_children(
@@ -1300,6 +1338,10 @@ fn test_do_revoke_children_multiple_partial_revocation() {
         // Add network and register hotkey
         add_network(netuid, 13, 0);
         register_ok_neuron(netuid, hotkey, coldkey, 0);
+        // The children belong to the coldkey of the hotkey, so they need no consent
+        SubtensorModule::create_account_if_non_existent(&coldkey, &child1);
+        SubtensorModule::create_account_if_non_existent(&coldkey, &child2);
+        SubtensorModule::create_account_if_non_existent(&coldkey, &child3);
 
         // Set multiple children
         assert_ok!(SubtensorModule::do_set_children(
@@ -1355,6 +1397,8 @@ fn test_do_revoke_children_multiple_non_existent_children() {
         // Add network and register hotkey
         add_network(netuid, 13, 0);
         register_ok_neuron(netuid, hotkey, coldkey, 0);
+        // The children belong to the coldkey of the hotkey, so they need no consent
+        SubtensorModule::create_account_if_non_existent(&coldkey, &child1);
 
         // Set one child
         assert_ok!(SubtensorModule::do_set_children(
@@ -1437,6 +1481,10 @@ fn test_do_revoke_children_multiple_complex_scenario() {
         // Add network and register hotkey
         add_network(netuid, 13, 0);
         register_ok_neuron(netuid, hotkey, coldkey, 0);
+        // The children belong to the coldkey of the hotkey, so they need no consent
+        SubtensorModule::create_account_if_non_existent(&coldkey, &child1);
+        SubtensorModule::create_account_if_non_existent(&coldkey, &child2);
+        SubtensorModule::create_account_if_non_existent(&coldkey, &child3);
 
         // Set multiple children
         assert_ok!(SubtensorModule::do_set_children(
@@ -1876,6 +1924,12 @@ fn test_childkey_single_parent_emission() {
             netuid,
             vec![(u64::MAX, child)]
         ));
+        assert_ok!(SubtensorModule::do_accept_child(
+            RuntimeOrigin::signed(coldkey_child),
+            parent,
+            child,
+            netuid
+        ));
         step_block(7200 + 1);
         // Set weights on the child using the weight_setter account
         let origin = RuntimeOrigin::signed(weight_setter);
@@ -2017,12 +2071,24 @@ fn test_childkey_multiple_parents_emission() {
             netuid,
             vec![(100_000, child)]
         ));
+        assert_ok!(SubtensorModule::do_accept_child(
+            RuntimeOrigin::signed(coldkey_child),
+            parent1,
+            child,
+            netuid
+        ));
         assert_ok!(SubtensorModule::do_set_children(
             RuntimeOrigin::signed(coldkey_parent2),
             parent2,
             netuid,
             vec![(75_000, child)]
         ));
+        assert_ok!(SubtensorModule::do_accept_child(
+            RuntimeOrigin::signed(coldkey_child),
+            parent2,
+            child,
+            netuid
+        ));
 
         // Set weights
         let uids: Vec<u16> = vec![0, 1, 2];
@@ -2186,6 +2252,12 @@ fn test_parent_child_chain_emission() {
             netuid,
             vec![(u64::MAX / 2, hotkey_b)]
         ));
+        assert_ok!(SubtensorModule::do_accept_child(
+            RuntimeOrigin::signed(coldkey_b),
+            hotkey_a,
+            hotkey_b,
+            netuid
+        ));
         // B -> C (50% of B's stake)
         assert_ok!(SubtensorModule::do_set_children(
             RuntimeOrigin::signed(coldkey_b),
@@ -2193,6 +2265,12 @@ fn test_parent_child_chain_emission() {
             netuid,
             vec![(u64::MAX / 2, hotkey_c)]
         ));
+        assert_ok!(SubtensorModule::do_accept_child(
+            RuntimeOrigin::signed(coldkey_c),
+            hotkey_b,
+            hotkey_c,
+            netuid
+        ));
 
         step_block(2);
 
@@ -2338,6 +2416,12 @@ fn test_dynamic_parent_child_relationships() {
             netuid,
             vec![(u64::MAX / 2, child1)]
         ));
+        assert_ok!(SubtensorModule::do_accept_child(
+            RuntimeOrigin::signed(coldkey_child1),
+            parent,
+            child1,
+            netuid
+        ));
 
         step_block(2);
 
@@ -2380,6 +2464,18 @@ fn test_dynamic_parent_child_relationships() {
             netuid,
             vec![(u64::MAX / 4, child1), (u64::MAX / 3, child2)]
         ));
+        assert_ok!(SubtensorModule::do_accept_child(
+            RuntimeOrigin::signed(coldkey_child1),
+            parent,
+            child1,
+            netuid
+        ));
+        assert_ok!(SubtensorModule::do_accept_child(
+            RuntimeOrigin::signed(coldkey_child2),
+            parent,
+            child2,
+            netuid
+        ));
 
         // Run second epoch
         let hotkey_emission: Vec<(U256, u64, u64)> = SubtensorModule::epoch(netuid, hardcoded_emission);
@@ -2820,6 +2916,18 @@ fn test_get_stake_for_hotkey_on_subnet_complex_hierarchy() {
             netuid,
             vec![(u64::MAX / 2, child1), (u64::MAX / 2, child2)]
         ));
+        assert_ok!(SubtensorModule::do_accept_child(
+            RuntimeOrigin::signed(coldkey_child1),
+            parent,
+            child1,
+            netuid
+        ));
+        assert_ok!(SubtensorModule::do_accept_child(
+            RuntimeOrigin::signed(coldkey_child2),
+            parent,
+            child2,
+            netuid
+        ));
 
         log::info!("After setting parent's children:");
         log::info!(
@@ -2857,6 +2965,12 @@ fn test_get_stake_for_hotkey_on_subnet_complex_hierarchy() {
             netuid,
             vec![(u64::MAX, grandchild)]
         ));
+        assert_ok!(SubtensorModule::do_accept_child(
+            RuntimeOrigin::signed(coldkey_grandchild),
+            child1,
+            grandchild,
+            netuid
+        ));
 
         log::info!("After setting child1's children:");
         log::info!(
@@ -3129,6 +3243,12 @@ fn test_rank_trust_incentive_calculation_with_parent_child() {
             netuid,
             vec![(u64::MAX, child_hotkey)]
         ));
+        assert_ok!(SubtensorModule::do_accept_child(
+            RuntimeOrigin::signed(child_coldkey),
+            parent_hotkey,
+            child_hotkey,
+            netuid
+        ));
 
         // Child now sets weights as a validator
         assert_ok!(SubtensorModule::set_weights(
@@ -3237,3 +3357,585 @@ fn test_rank_trust_incentive_calculation_with_parent_child() {
 
     });
 }
+
+// Test that a child owned by another coldkey must accept before it is set
+// SKIP_WASM_BUILD=1 RUST_LOG=info cargo test --test children -- test_set_children_requires_child_consent --exact --nocapture
+#[test]
+fn test_set_children_requires_child_consent() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let parent = U256::from(1);
+        let child = U256::from(2);
+        let coldkey_parent = U256::from(3);
+        let coldkey_child = U256::from(4);
+        let proportion: u64 = 1000;
+
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, parent, coldkey_parent, 0);
+        register_ok_neuron(netuid, child, coldkey_child, 1);
+
+        // The parent proposes the child, nothing changes yet
+        assert_ok!(SubtensorModule::do_set_children(
+            RuntimeOrigin::signed(coldkey_parent),
+            parent,
+            netuid,
+            vec![(proportion, child)]
+        ));
+        assert!(SubtensorModule::get_children(&parent, netuid).is_empty());
+        assert!(SubtensorModule::get_parents(&child, netuid).is_empty());
+        let proposal = SubtensorModule::get_pending_children(&parent, netuid).unwrap();
+        assert_eq!(proposal.children, vec![(proportion, child)]);
+        assert!(proposal.accepted.is_empty());
+        assert_eq!(
+            SubtensorModule::get_pending_parents(&child),
+            vec![(parent, netuid, proposal.clone())]
+        );
+        System::assert_last_event(
+            Event::ChildrenProposed {
+                hotkey: parent,
+                netuid,
+                children: vec![(proportion, child)],
+                expires_at: proposal.expires_at,
+            }
+            .into(),
+        );
+
+        // Only the coldkey of the child can accept
+        assert_noop!(
+            SubtensorModule::do_accept_child(
+                RuntimeOrigin::signed(coldkey_parent),
+                parent,
+                child,
+                netuid
+            ),
+            Error::<Test>::NonAssociatedColdKey
+        );
+        assert_noop!(
+            SubtensorModule::do_accept_child(
+                RuntimeOrigin::signed(coldkey_child),
+                child,
+                parent,
+                netuid
+            ),
+            Error::<Test>::NonAssociatedColdKey
+        );
+
+        // The child accepts and is set
+        assert_ok!(SubtensorModule::do_accept_child(
+            RuntimeOrigin::signed(coldkey_child),
+            parent,
+            child,
+            netuid
+        ));
+        assert_eq!(
+            SubtensorModule::get_children(&parent, netuid),
+            vec![(proportion, child)]
+        );
+        assert_eq!(
+            SubtensorModule::get_parents(&child, netuid),
+            vec![(proportion, parent)]
+        );
+        assert!(SubtensorModule::get_pending_children(&parent, netuid).is_none());
+        assert_noop!(
+            SubtensorModule::do_accept_child(
+                RuntimeOrigin::signed(coldkey_child),
+                parent,
+                child,
+                netuid
+            ),
+            Error::<Test>::ChildkeyProposalNotFound
+        );
+    });
+}
+
+// Test that a child without an owner waits for the coldkey registering it to accept
+// SKIP_WASM_BUILD=1 RUST_LOG=info cargo test --test children -- test_set_children_unowned_child_needs_consent --exact --nocapture
+#[test]
+fn test_set_children_unowned_child_needs_consent() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let parent = U256::from(1);
+        let child = U256::from(2);
+        let coldkey_parent = U256::from(3);
+        let coldkey_child = U256::from(4);
+        let proportion: u64 = 1000;
+
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, parent, coldkey_parent, 0);
+
+        // Nobody owns the child yet, so it is not accepted with the proposal
+        assert_ok!(SubtensorModule::do_set_children(
+            RuntimeOrigin::signed(coldkey_parent),
+            parent,
+            netuid,
+            vec![(proportion, child)]
+        ));
+        assert!(SubtensorModule::get_children(&parent, netuid).is_empty());
+        assert!(SubtensorModule::get_pending_children(&parent, netuid)
+            .unwrap()
+            .accepted
+            .is_empty());
+
+        // The coldkey registering the child decides
+        register_ok_neuron(netuid, child, coldkey_child, 1);
+        assert_ok!(SubtensorModule::do_accept_child(
+            RuntimeOrigin::signed(coldkey_child),
+            parent,
+            child,
+            netuid
+        ));
+        assert_eq!(
+            SubtensorModule::get_children(&parent, netuid),
+            vec![(proportion, child)]
+        );
+    });
+}
+
+// Test that a rejected or expired proposal leaves the children unchanged
+// SKIP_WASM_BUILD=1 RUST_LOG=info cargo test --test children -- test_set_children_rejected_or_expired --exact --nocapture
+#[test]
+fn test_set_children_rejected_or_expired() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let parent = U256::from(1);
+        let own_child = U256::from(2);
+        let child = U256::from(3);
+        let coldkey_parent = U256::from(4);
+        let coldkey_child = U256::from(5);
+
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, parent, coldkey_parent, 0);
+        register_ok_neuron(netuid, own_child, coldkey_parent, 1);
+        register_ok_neuron(netuid, child, coldkey_child, 2);
+
+        // A child of the same coldkey is set without consent
+        assert_ok!(SubtensorModule::do_set_children(
+            RuntimeOrigin::signed(coldkey_parent),
+            parent,
+            netuid,
+            vec![(1000, own_child)]
+        ));
+        assert_eq!(
+            SubtensorModule::get_children(&parent, netuid),
+            vec![(1000, own_child)]
+        );
+
+        // Rejecting drops the whole proposal
+        assert_ok!(SubtensorModule::do_set_children(
+            RuntimeOrigin::signed(coldkey_parent),
+            parent,
+            netuid,
+            vec![(1000, own_child), (1000, child)]
+        ));
+        assert_eq!(
+            SubtensorModule::get_pending_children(&parent, netuid)
+                .unwrap()
+                .accepted,
+            vec![own_child]
+        );
+        assert_noop!(
+            SubtensorModule::do_reject_child(
+                RuntimeOrigin::signed(coldkey_child),
+                parent,
+                U256::from(6),
+                netuid
+            ),
+            Error::<Test>::NonAssociatedColdKey
+        );
+        assert_ok!(SubtensorModule::do_reject_child(
+            RuntimeOrigin::signed(coldkey_child),
+            parent,
+            child,
+            netuid
+        ));
+        assert!(SubtensorModule::get_pending_children(&parent, netuid).is_none());
+        assert_eq!(
+            SubtensorModule::get_children(&parent, netuid),
+            vec![(1000, own_child)]
+        );
+
+        // A proposal not accepted in time is dropped
+        ChildkeyProposalExpiry::<Test>::put(10);
+        assert_ok!(SubtensorModule::do_set_children(
+            RuntimeOrigin::signed(coldkey_parent),
+            parent,
+            netuid,
+            vec![(1000, child)]
+        ));
+        step_block(9);
+        assert!(SubtensorModule::get_pending_children(&parent, netuid).is_some());
+        step_block(1);
+        assert!(SubtensorModule::get_pending_children(&parent, netuid).is_none());
+        assert!(SubtensorModule::get_pending_parents(&child).is_empty());
+        assert!(!PendingParentKeys::<Test>::contains_key(child, netuid));
+        assert_eq!(PendingChildKeysByExpiry::<Test>::iter().count(), 0);
+        assert_eq!(
+            SubtensorModule::get_children(&parent, netuid),
+            vec![(1000, own_child)]
+        );
+    });
+}
+
+// Test that an accepted proposal which no longer fits the subnet limits or hits a key swap is dropped
+// SKIP_WASM_BUILD=1 RUST_LOG=info cargo test --test children -- test_accept_child_drops_proposal_that_cannot_be_set --exact --nocapture
+#[test]
+fn test_accept_child_drops_proposal_that_cannot_be_set() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let parent = U256::from(1);
+        let child = U256::from(2);
+        let coldkey_parent = U256::from(3);
+        let coldkey_child = U256::from(4);
+        let proportion: u64 = 1000;
+
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, parent, coldkey_parent, 0);
+        register_ok_neuron(netuid, child, coldkey_child, 1);
+
+        // The minimum proportion is raised while the proposal waits for the child
+        assert_ok!(SubtensorModule::do_set_children(
+            RuntimeOrigin::signed(coldkey_parent),
+            parent,
+            netuid,
+            vec![(proportion, child)]
+        ));
+        SubtensorModule::set_min_child_proportion(netuid, proportion + 1);
+        assert_ok!(SubtensorModule::do_accept_child(
+            RuntimeOrigin::signed(coldkey_child),
+            parent,
+            child,
+            netuid
+        ));
+        System::assert_last_event(
+            Event::ChildkeyProposalDropped {
+                hotkey: parent,
+                netuid,
+                error: Error::<Test>::ChildProportionTooLow.into(),
+            }
+            .into(),
+        );
+        assert!(SubtensorModule::get_children(&parent, netuid).is_empty());
+        assert!(SubtensorModule::get_pending_children(&parent, netuid).is_none());
+        assert!(SubtensorModule::get_pending_parents(&child).is_empty());
+
+        // The parent is being swapped when the child accepts
+        SubtensorModule::set_min_child_proportion(netuid, 0);
+        step_block(SubtensorModule::get_set_children_cooldown(netuid) as u16 + 1);
+        assert_ok!(SubtensorModule::do_set_children(
+            RuntimeOrigin::signed(coldkey_parent),
+            parent,
+            netuid,
+            vec![(proportion, child)]
+        ));
+        KeySwapLocks::<Test>::insert(parent, U256::from(5));
+        assert_ok!(SubtensorModule::do_accept_child(
+            RuntimeOrigin::signed(coldkey_child),
+            parent,
+            child,
+            netuid
+        ));
+        System::assert_last_event(
+            Event::ChildkeyProposalDropped {
+                hotkey: parent,
+                netuid,
+                error: Error::<Test>::KeySwapInProgress.into(),
+            }
+            .into(),
+        );
+        assert!(SubtensorModule::get_children(&parent, netuid).is_empty());
+        assert!(SubtensorModule::get_pending_children(&parent, netuid).is_none());
+    });
+}
+// Test the childkey info reported for a hotkey
+// SKIP_WASM_BUILD=1 RUST_LOG=info cargo test --test children -- test_get_childkey_info --exact --nocapture
+#[test]
+fn test_get_childkey_info() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let parent = U256::from(1);
+        let child = U256::from(2);
+        let coldkey = U256::from(3);
+
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, parent, coldkey, 0);
+        register_ok_neuron(netuid, child, coldkey, 1);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &parent, 1000);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &child, 1000);
+        assert_ok!(SubtensorModule::do_set_children(
+            RuntimeOrigin::signed(coldkey),
+            parent,
+            netuid,
+            vec![(u64::MAX / 2, child)]
+        ));
+
+        // The stake lent to the child is what the child's effective stake inherits
+        let lent: u64 = SubtensorModule::get_childkey_stake_proportion(1000, u64::MAX / 2);
+        assert_eq!(
+            SubtensorModule::get_stake_for_hotkey_on_subnet(&parent, netuid),
+            1000 - lent
+        );
+        assert_eq!(
+            SubtensorModule::get_stake_for_hotkey_on_subnet(&child, netuid),
+            1000 + lent
+        );
+
+        assert!(SubtensorModule::get_childkey_info(parent.encode(), netuid).is_some());
+        assert!(SubtensorModule::get_childkey_info(child.encode(), netuid).is_some());
+        assert!(SubtensorModule::get_childkey_info(child.encode(), netuid + 1).is_none());
+        assert!(SubtensorModule::get_childkey_info(vec![0; 31], netuid).is_none());
+    });
+}
+
+// Test the record of the emission passed from children to parents
+// SKIP_WASM_BUILD=1 RUST_LOG=info cargo test --test children -- test_childkey_emission_is_recorded --exact --nocapture
+#[test]
+fn test_childkey_emission_is_recorded() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let parent = U256::from(1);
+        let child = U256::from(2);
+        let coldkey = U256::from(3);
+
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, parent, coldkey, 0);
+        register_ok_neuron(netuid, child, coldkey, 1);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &parent, 1000);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &child, 1000);
+        assert_ok!(SubtensorModule::do_set_children(
+            RuntimeOrigin::signed(coldkey),
+            parent,
+            netuid,
+            vec![(u64::MAX, child)]
+        ));
+
+        // The emission passed to the parent is returned
+        let parent_emission = SubtensorModule::accumulate_hotkey_emission(&child, netuid, 1000, 0);
+        assert_eq!(parent_emission.len(), 1);
+        assert_eq!(parent_emission[0].0, parent);
+        assert!(parent_emission[0].1 > 0);
+        assert_eq!(
+            PendingdHotkeyEmission::<Test>::get(parent),
+            parent_emission[0].1
+        );
+
+        // The epoch is recorded and summarized
+        let amount = parent_emission[0].1;
+        SubtensorModule::record_childkey_emission(netuid, 10, vec![(child, parent, amount)]);
+        assert_eq!(
+            SubtensorModule::get_childkey_emission_history(netuid),
+            vec![ChildkeyEmissionRecord {
+                block: 10,
+                transfers: vec![(child, parent, amount)],
+            }]
+        );
+        System::assert_last_event(
+            Event::ChildkeyEmissionRecorded {
+                netuid,
+                transfers: 1,
+                total_emission: amount,
+            }
+            .into(),
+        );
+
+        // Epochs without transfers are not recorded
+        SubtensorModule::record_childkey_emission(netuid, 11, vec![]);
+        assert_eq!(ChildkeyEmissionHistory::<Test>::get(netuid).len(), 1);
+
+        // Only the last epochs are kept
+        for block in 0..CHILDKEY_EMISSION_HISTORY_EPOCHS as u64 {
+            SubtensorModule::record_childkey_emission(
+                netuid,
+                100 + block,
+                vec![(child, parent, 1)],
+            );
+        }
+        let history = ChildkeyEmissionHistory::<Test>::get(netuid);
+        assert_eq!(history.len(), CHILDKEY_EMISSION_HISTORY_EPOCHS);
+        assert_eq!(history[0].block, 100);
+    });
+}
+
+// Test the childkey limits and rules set per subnet
+// SKIP_WASM_BUILD=1 RUST_LOG=info cargo test --test children -- test_childkey_limits_per_subnet --exact --nocapture
+#[test]
+fn test_childkey_limits_per_subnet() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let hotkey = U256::from(2);
+        let netuid: u16 = 1;
+        let other_netuid: u16 = 2;
+        let children: Vec<(u64, U256)> = (10..16).map(|i| (1000, U256::from(i))).collect();
+
+        add_network(netuid, 13, 0);
+        add_network(other_netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey, coldkey, 0);
+        for (_, child) in &children {
+            SubtensorModule::create_account_if_non_existent(&coldkey, child);
+        }
+
+        // The defaults keep the former global rules
+        assert_eq!(SubtensorModule::get_max_children(netuid), 5);
+        assert_eq!(SubtensorModule::get_min_child_proportion(netuid), 0);
+        assert_eq!(
+            SubtensorModule::get_set_children_cooldown(netuid),
+            u64::from(DefaultTempo::<Test>::get()) * 2
+        );
+        assert_noop!(
+            SubtensorModule::do_set_children(
+                RuntimeOrigin::signed(coldkey),
+                hotkey,
+                netuid,
+                children.clone()
+            ),
+            Error::<Test>::TooManyChildren
+        );
+
+        // A subnet can allow more children
+        SubtensorModule::set_subnet_hyperparameter(netuid, SubnetHyperparameter::MaxChildren, 6);
+        assert_ok!(SubtensorModule::do_set_children(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            netuid,
+            children.clone()
+        ));
+        assert_eq!(SubtensorModule::get_children(&hotkey, netuid), children);
+        assert_eq!(SubtensorModule::get_max_children(other_netuid), 5);
+
+        // No subnet can go past the hard limits
+        MaxChildren::<Test>::insert(other_netuid, MAX_CHILDREN_LIMIT + 1);
+        assert_eq!(
+            SubtensorModule::get_max_children(other_netuid),
+            MAX_CHILDREN_LIMIT
+        );
+        SetChildrenCooldown::<Test>::insert(other_netuid, 0);
+        assert_eq!(
+            SubtensorModule::get_set_children_cooldown(other_netuid),
+            MIN_SET_CHILDREN_COOLDOWN
+        );
+
+        // Children must get at least the minimum proportion of the subnet
+        SubtensorModule::set_min_child_proportion(netuid, 1001);
+        assert_noop!(
+            SubtensorModule::do_set_children(
+                RuntimeOrigin::signed(coldkey),
+                hotkey,
+                netuid,
+                vec![(1000, U256::from(10))]
+            ),
+            Error::<Test>::ChildProportionTooLow
+        );
+        assert_ok!(SubtensorModule::do_set_children(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            netuid,
+            vec![(1001, U256::from(10))]
+        ));
+
+        // The cooldown between setting children is per subnet
+        SubtensorModule::set_set_children_cooldown(netuid, 10);
+        assert_noop!(
+            SubtensorModule::do_set_children(
+                RuntimeOrigin::signed(coldkey),
+                hotkey,
+                netuid,
+                vec![(u64::MAX, U256::from(11))]
+            ),
+            Error::<Test>::TxRateLimitExceeded
+        );
+        step_block(10);
+        assert_ok!(SubtensorModule::do_set_children(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            netuid,
+            vec![(u64::MAX, U256::from(11))]
+        ));
+        assert_eq!(
+            SubtensorModule::get_rate_limit_on_subnet(&TransactionType::SetChildren, netuid),
+            10
+        );
+    });
+}
+
+// Test the childkey take bounds set per subnet
+// SKIP_WASM_BUILD=1 RUST_LOG=info cargo test --test children -- test_childkey_take_bounds_per_subnet --exact --nocapture
+#[test]
+fn test_childkey_take_bounds_per_subnet() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let hotkey = U256::from(2);
+        let netuid: u16 = 1;
+        let other_netuid: u16 = 2;
+
+        add_network(netuid, 13, 0);
+        add_network(other_netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey, coldkey, 0);
+        SubtensorModule::set_tx_childkey_take_rate_limit(0);
+
+        // Subnets follow the global bounds until they set their own
+        assert_eq!(
+            SubtensorModule::get_subnet_max_childkey_take(netuid),
+            SubtensorModule::get_max_childkey_take()
+        );
+        SubtensorModule::set_max_childkey_take(2000);
+        assert_eq!(SubtensorModule::get_subnet_max_childkey_take(netuid), 2000);
+
+        SubtensorModule::set_subnet_min_childkey_take(netuid, 500);
+        SubtensorModule::set_subnet_max_childkey_take(netuid, 1000);
+        assert_eq!(
+            SubtensorModule::get_subnet_hyperparameter(
+                netuid,
+                SubnetHyperparameter::MaxChildkeyTake
+            ),
+            1000
+        );
+
+        // The take must be within the bounds of the subnet
+        assert_noop!(
+            SubtensorModule::set_childkey_take(RuntimeOrigin::signed(coldkey), hotkey, netuid, 499),
+            Error::<Test>::InvalidChildkeyTake
+        );
+        assert_noop!(
+            SubtensorModule::set_childkey_take(
+                RuntimeOrigin::signed(coldkey),
+                hotkey,
+                netuid,
+                1001
+            ),
+            Error::<Test>::InvalidChildkeyTake
+        );
+        assert_ok!(SubtensorModule::set_childkey_take(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            netuid,
+            1000
+        ));
+        assert_eq!(SubtensorModule::get_childkey_take(&hotkey, netuid), 1000);
+
+        // Other subnets keep the global bounds
+        assert_ok!(SubtensorModule::set_childkey_take(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            other_netuid,
+            2000
+        ));
+        assert_eq!(
+            SubtensorModule::get_childkey_take(&hotkey, other_netuid),
+            2000
+        );
+
+        // A take set before the subnet lowers its upper bound is capped at the new bound
+        SubtensorModule::set_subnet_max_childkey_take(netuid, 800);
+        assert_eq!(SubtensorModule::get_childkey_take(&hotkey, netuid), 800);
+
+        // So is a take set before the global upper bound is lowered
+        SubtensorModule::set_max_childkey_take(1500);
+        assert_eq!(
+            SubtensorModule::get_subnet_max_childkey_take(other_netuid),
+            1500
+        );
+        assert_eq!(
+            SubtensorModule::get_childkey_take(&hotkey, other_netuid),
+            1500
+        );
+    });
+}
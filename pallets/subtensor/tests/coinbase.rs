@@ -1,7 +1,11 @@
 #![allow(unused, clippy::indexing_slicing, clippy::panic, clippy::unwrap_used)]
 use crate::mock::*;
 mod mock;
-// use frame_support::{assert_err, assert_ok};
+use frame_support::{assert_noop, assert_ok};
+use pallet_subtensor::{
+    Delegates, Error, HotkeyDrainHistory, RewardPayout, RewardPayouts, TotalStake, BLOCKS_PER_DAY,
+    DRAIN_HISTORY_DAYS,
+};
 use sp_core::U256;
 
 // Test the ability to hash all sorts of hotkeys.
@@ -154,3 +158,133 @@ fn test_set_and_get_hotkey_emission_tempo() {
         assert_eq!(updated_tempo, new_tempo);
     });
 }
+
+// Test the daily totals kept for the hotkey drains.
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test coinbase test_drain_hotkey_emission_records_history -- --nocapture
+#[test]
+fn test_drain_hotkey_emission_records_history() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey = U256::from(1);
+        let coldkey = U256::from(2);
+        let nominator = U256::from(3);
+        SubtensorModule::create_account_if_non_existent(&coldkey, &hotkey);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &hotkey, 1_000);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&nominator, &hotkey, 1_000);
+        Delegates::<Test>::insert(hotkey, u16::MAX / 10);
+
+        // Drains on the same day are added up
+        SubtensorModule::drain_hotkey_emission(&hotkey, 1_000, 10);
+        SubtensorModule::drain_hotkey_emission(&hotkey, 1_000, 20);
+        let history = HotkeyDrainHistory::<Test>::get(hotkey);
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].day, 0);
+        assert_eq!(history[0].drained, 2_000);
+        assert!(history[0].take > 0);
+        assert!(history[0].distributed > 0);
+        assert!(history[0].take + history[0].distributed <= 2_000);
+
+        // A drain on a later day starts a new record
+        SubtensorModule::drain_hotkey_emission(&hotkey, 500, BLOCKS_PER_DAY + 10);
+        let history = HotkeyDrainHistory::<Test>::get(hotkey);
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[1].day, 1);
+        assert_eq!(history[1].drained, 500);
+
+        // Days out of the window are dropped
+        SubtensorModule::drain_hotkey_emission(&hotkey, 500, DRAIN_HISTORY_DAYS * BLOCKS_PER_DAY);
+        let days: Vec<u64> = HotkeyDrainHistory::<Test>::get(hotkey)
+            .iter()
+            .map(|record| record.day)
+            .collect();
+        assert_eq!(days, vec![1, DRAIN_HISTORY_DAYS]);
+    });
+}
+
+// Test the nominator emission paid to free balances as set by the reward payouts.
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test coinbase test_drain_hotkey_emission_reward_payouts -- --nocapture
+#[test]
+fn test_drain_hotkey_emission_reward_payouts() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey = U256::from(1);
+        let coldkey = U256::from(2);
+        let free_nominator = U256::from(3);
+        let account_nominator = U256::from(4);
+        let treasury = U256::from(5);
+        SubtensorModule::create_account_if_non_existent(&coldkey, &hotkey);
+        for staker in [coldkey, free_nominator, account_nominator] {
+            SubtensorModule::increase_stake_on_coldkey_hotkey_account(&staker, &hotkey, 1_000);
+        }
+        Delegates::<Test>::insert(hotkey, 0);
+
+        // Only a coldkey staking on the hotkey sets a payout
+        assert_noop!(
+            SubtensorModule::set_reward_payout(
+                RuntimeOrigin::signed(treasury),
+                hotkey,
+                RewardPayout::FreeBalance
+            ),
+            Error::<Test>::NoStakeOnHotkey
+        );
+        assert_ok!(SubtensorModule::set_reward_payout(
+            RuntimeOrigin::signed(free_nominator),
+            hotkey,
+            RewardPayout::FreeBalance
+        ));
+        assert_ok!(SubtensorModule::set_reward_payout(
+            RuntimeOrigin::signed(account_nominator),
+            hotkey,
+            RewardPayout::Account(treasury)
+        ));
+
+        // Without take each staker earns a third, only the compounded part is staked
+        let total_stake = TotalStake::<Test>::get();
+        assert_eq!(
+            SubtensorModule::drain_hotkey_emission(&hotkey, 3_000, 10),
+            3_000
+        );
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&coldkey, &hotkey),
+            2_000
+        );
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&free_nominator, &hotkey),
+            1_000
+        );
+        assert_eq!(SubtensorModule::get_coldkey_balance(&free_nominator), 1_000);
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&account_nominator, &hotkey),
+            1_000
+        );
+        assert_eq!(SubtensorModule::get_coldkey_balance(&account_nominator), 0);
+        assert_eq!(SubtensorModule::get_coldkey_balance(&treasury), 1_000);
+        assert_eq!(TotalStake::<Test>::get(), total_stake + 1_000);
+
+        // Compounding again removes the payout
+        assert_ok!(SubtensorModule::set_reward_payout(
+            RuntimeOrigin::signed(free_nominator),
+            hotkey,
+            RewardPayout::Compound
+        ));
+        assert!(!RewardPayouts::<Test>::contains_key(hotkey, free_nominator));
+
+        // Unstaking the whole nomination removes the payout
+        SubtensorModule::decrease_stake_on_coldkey_hotkey_account(&account_nominator, &hotkey, 500);
+        assert!(RewardPayouts::<Test>::contains_key(
+            hotkey,
+            account_nominator
+        ));
+        SubtensorModule::decrease_stake_on_coldkey_hotkey_account(&account_nominator, &hotkey, 500);
+        assert!(!RewardPayouts::<Test>::contains_key(
+            hotkey,
+            account_nominator
+        ));
+        assert_noop!(
+            SubtensorModule::set_reward_payout(
+                RuntimeOrigin::signed(account_nominator),
+                hotkey,
+                RewardPayout::FreeBalance
+            ),
+            Error::<Test>::NoStakeOnHotkey
+        );
+    });
+}
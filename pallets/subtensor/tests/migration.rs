@@ -171,6 +171,7 @@ fn test_total_issuance_global() {
         assert_eq!(SubtensorModule::get_total_issuance(), 0); // initial is zero.
         assert_ok!(SubtensorModule::register_network(
             <<Test as Config>::RuntimeOrigin>::signed(owner),
+            None,
         ));
         SubtensorModule::set_max_allowed_uids(netuid, 1); // Set the maximum allowed unique identifiers for the network to 1.
         assert_eq!(SubtensorModule::get_total_issuance(), 0); // initial is zero.
@@ -430,3 +431,57 @@ fn run_migration_and_check(migration_name: &'static str) -> frame_support::weigh
     // Return the weight of the executed migration
     weight
 }
+
+// SKIP_WASM_BUILD=1 RUST_LOG=info cargo test --test migration -- test_migrate_seed_hyperparameter_bounds --exact --nocapture
+#[test]
+fn test_migrate_seed_hyperparameter_bounds() {
+    new_test_ext(1).execute_with(|| {
+        // Bounds root has already set are kept
+        HyperparameterBounds::<Test>::insert(SubnetHyperparameter::MaxChildren, (1, 5));
+
+        pallet_subtensor::migrations::migrate_seed_hyperparameter_bounds::migrate_seed_hyperparameter_bounds::<Test>();
+
+        assert!(HasMigrationRun::<Test>::get(
+            b"seed_hyperparameter_bounds".to_vec()
+        ));
+        assert_eq!(
+            SubtensorModule::get_hyperparameter_bounds(SubnetHyperparameter::MaxChildren),
+            (1, 5)
+        );
+        assert_eq!(
+            SubtensorModule::get_hyperparameter_bounds(SubnetHyperparameter::AlphaLow),
+            (1, u64::from(MIN_ALPHA_HIGH) - 1)
+        );
+        assert_eq!(
+            SubtensorModule::get_hyperparameter_bounds(SubnetHyperparameter::AlphaHigh),
+            (u64::from(MIN_ALPHA_HIGH), u64::from(u16::MAX))
+        );
+        assert_eq!(
+            SubtensorModule::get_hyperparameter_bounds(SubnetHyperparameter::SetChildrenCooldown),
+            (MIN_SET_CHILDREN_COOLDOWN, u64::MAX)
+        );
+        assert_eq!(
+            SubtensorModule::get_hyperparameter_bounds(SubnetHyperparameter::Rho),
+            (1, 100)
+        );
+        assert_eq!(
+            SubtensorModule::get_hyperparameter_bounds(SubnetHyperparameter::Kappa),
+            (u64::from(u16::MAX / 2), u64::from(u16::MAX))
+        );
+        assert_eq!(
+            SubtensorModule::get_hyperparameter_bounds(SubnetHyperparameter::ActivityCutoff),
+            (360, u64::from(u16::MAX))
+        );
+
+        // A bound root removes afterwards is not seeded again
+        assert_ok!(SubtensorModule::set_hyperparameter_bounds(
+            SubnetHyperparameter::AlphaLow,
+            0,
+            u64::MAX
+        ));
+        pallet_subtensor::migrations::migrate_seed_hyperparameter_bounds::migrate_seed_hyperparameter_bounds::<Test>();
+        assert!(!HyperparameterBounds::<Test>::contains_key(
+            SubnetHyperparameter::AlphaLow
+        ));
+    })
+}
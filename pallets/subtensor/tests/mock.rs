@@ -177,6 +177,9 @@ parameter_types! {
     pub const InitialNetworkMaxStake: u64 = u64::MAX; // Maximum possible value for u64
     pub const InitialColdkeySwapScheduleDuration: u64 =  5 * 24 * 60 * 60 / 12; // Default as 5 days
     pub const InitialDissolveNetworkScheduleDuration: u64 =  5 * 24 * 60 * 60 / 12; // Default as 5 days
+    pub const InitialSubnetOwnerTransferScheduleDuration: u64 =  5 * 24 * 60 * 60 / 12; // Default as 5 days
+    pub const InitialSenateAdjustmentInterval: u64 = 0; // Disabled for testing
+    pub const InitialDelegateTakeNoticePeriod: u64 = 0; // Increases apply immediately for testing
 }
 
 // Configure collective pallet for council
@@ -285,6 +288,8 @@ impl pallet_collective::Config<TriumvirateCollective> for Test {
     type CanPropose = CanProposeToTriumvirate;
     type CanVote = CanVoteToTriumvirate;
     type GetVotingMembers = GetSenateMemberCount;
+    type VotingWeights = ();
+    type WeightedThreshold = pallet_collective::MoreThanHalfWeight;
 }
 
 // We call council members Triumvirate
@@ -320,6 +325,8 @@ impl pallet_collective::Config<SenateCollective> for Test {
     type CanPropose = ();
     type CanVote = ();
     type GetVotingMembers = ();
+    type VotingWeights = ();
+    type WeightedThreshold = pallet_collective::MoreThanHalfWeight;
 }
 
 // We call our top K delegates membership Senate
@@ -401,6 +408,9 @@ impl pallet_subtensor::Config for Test {
     type Preimages = Preimage;
     type InitialColdkeySwapScheduleDuration = InitialColdkeySwapScheduleDuration;
     type InitialDissolveNetworkScheduleDuration = InitialDissolveNetworkScheduleDuration;
+    type InitialSubnetOwnerTransferScheduleDuration = InitialSubnetOwnerTransferScheduleDuration;
+    type InitialSenateAdjustmentInterval = InitialSenateAdjustmentInterval;
+    type InitialDelegateTakeNoticePeriod = InitialDelegateTakeNoticePeriod;
 }
 
 pub struct OriginPrivilegeCmp;
@@ -1,8 +1,12 @@
 use crate::mock::*;
-use frame_support::assert_ok;
+use frame_support::{assert_noop, assert_ok};
 use frame_system::Config;
-use pallet_subtensor::{ColdkeySwapScheduleDuration, DissolveNetworkScheduleDuration, Event};
+use pallet_subtensor::{
+    ColdkeySwapScheduleDuration, DissolveNetworkScheduleDuration, Error, Event,
+    PendingSubnetOwnerTransfer, ScheduledNetworkDissolutions, SubnetOwnerTransferScheduleDuration,
+};
 use sp_core::U256;
+use sp_runtime::DispatchError;
 
 mod mock;
 
@@ -126,23 +130,19 @@ fn test_non_owner_schedule_dissolve_network_execution() {
 
         assert!(SubtensorModule::if_subnet_exist(netuid));
 
-        assert_ok!(SubtensorModule::schedule_dissolve_network(
-            <<Test as Config>::RuntimeOrigin>::signed(non_network_owner_account_id),
-            netuid
-        ));
+        // only the network owner or root can schedule the dissolution
+        assert_noop!(
+            SubtensorModule::schedule_dissolve_network(
+                <<Test as Config>::RuntimeOrigin>::signed(non_network_owner_account_id),
+                netuid
+            ),
+            Error::<Test>::NotSubnetOwner
+        );
+        assert!(ScheduledNetworkDissolutions::<Test>::get(netuid).is_none());
 
         let current_block = System::block_number();
         let execution_block = current_block + DissolveNetworkScheduleDuration::<Test>::get();
 
-        System::assert_last_event(
-            Event::DissolveNetworkScheduled {
-                account: non_network_owner_account_id,
-                netuid,
-                execution_block,
-            }
-            .into(),
-        );
-
         run_to_block(execution_block);
         // network exists since the caller is no the network owner
         assert!(SubtensorModule::if_subnet_exist(netuid));
@@ -181,6 +181,19 @@ fn test_new_owner_schedule_dissolve_network_execution() {
         assert!(SubtensorModule::if_subnet_exist(netuid));
 
         // the account is not network owner when schedule the call
+        assert_noop!(
+            SubtensorModule::schedule_dissolve_network(
+                <<Test as Config>::RuntimeOrigin>::signed(new_network_owner_account_id),
+                netuid
+            ),
+            Error::<Test>::NotSubnetOwner
+        );
+
+        let current_block = System::block_number();
+        run_to_block(current_block + 1);
+        // become network owner
+        pallet_subtensor::SubnetOwner::<Test>::insert(netuid, new_network_owner_account_id);
+
         assert_ok!(SubtensorModule::schedule_dissolve_network(
             <<Test as Config>::RuntimeOrigin>::signed(new_network_owner_account_id),
             netuid
@@ -197,12 +210,9 @@ fn test_new_owner_schedule_dissolve_network_execution() {
             }
             .into(),
         );
-        run_to_block(current_block + 1);
-        // become network owner after call scheduled
-        pallet_subtensor::SubnetOwner::<Test>::insert(netuid, new_network_owner_account_id);
 
         run_to_block(execution_block);
-        // network exists since the caller is no the network owner
+        // network is dissolved since the caller is the network owner
         assert!(!SubtensorModule::if_subnet_exist(netuid));
     })
 }
@@ -252,33 +262,477 @@ fn test_schedule_dissolve_network_execution_with_coldkey_swap() {
 
         run_to_block(execution_block - 1);
 
-        // the account is not network owner when schedule the call
+        // the account is not network owner before the swap
+        assert_noop!(
+            SubtensorModule::schedule_dissolve_network(
+                <<Test as Config>::RuntimeOrigin>::signed(new_network_owner_account_id),
+                netuid
+            ),
+            Error::<Test>::NotSubnetOwner
+        );
+
+        run_to_block(execution_block);
+        assert_eq!(
+            pallet_subtensor::SubnetOwner::<Test>::get(netuid),
+            new_network_owner_account_id
+        );
+
         assert_ok!(SubtensorModule::schedule_dissolve_network(
             <<Test as Config>::RuntimeOrigin>::signed(new_network_owner_account_id),
             netuid
         ));
 
+        let current_block = System::block_number();
+        let execution_block = current_block + DissolveNetworkScheduleDuration::<Test>::get();
+
         System::assert_last_event(
             Event::DissolveNetworkScheduled {
                 account: new_network_owner_account_id,
                 netuid,
-                execution_block: DissolveNetworkScheduleDuration::<Test>::get() + execution_block
-                    - 1,
+                execution_block,
             }
             .into(),
         );
 
         run_to_block(execution_block);
+        // network exists since the caller is no the network owner
+        assert!(!SubtensorModule::if_subnet_exist(netuid));
+    })
+}
+
+#[test]
+fn test_subnet_owner_transfer_propose_and_accept() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 2;
+        let owner = U256::from(1);
+        let new_owner = U256::from(2);
+        let lock: u64 = 1_000_000;
+
+        add_network(netuid, 13, 0);
+        pallet_subtensor::SubnetOwner::<Test>::insert(netuid, owner);
+        SubtensorModule::set_subnet_locked_balance(netuid, lock);
+
+        // Only the owner can propose.
+        assert_noop!(
+            SubtensorModule::propose_subnet_owner_transfer(
+                <<Test as Config>::RuntimeOrigin>::signed(new_owner),
+                netuid,
+                new_owner
+            ),
+            Error::<Test>::NotSubnetOwner
+        );
+
+        assert_ok!(SubtensorModule::propose_subnet_owner_transfer(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            new_owner
+        ));
+        let executable_block =
+            System::block_number() + SubnetOwnerTransferScheduleDuration::<Test>::get();
+        System::assert_last_event(
+            Event::SubnetOwnerTransferProposed {
+                netuid,
+                current_owner: owner,
+                new_owner,
+                executable_block,
+            }
+            .into(),
+        );
+
+        // A second proposal is rejected while one is pending.
+        assert_noop!(
+            SubtensorModule::propose_subnet_owner_transfer(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                netuid,
+                U256::from(3)
+            ),
+            Error::<Test>::SubnetOwnerTransferAlreadyPending
+        );
+
+        // Only the proposed owner can accept, and only after the delay.
+        assert_noop!(
+            SubtensorModule::accept_subnet_owner_transfer(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(3)),
+                netuid
+            ),
+            Error::<Test>::NotProposedSubnetOwner
+        );
+        assert_noop!(
+            SubtensorModule::accept_subnet_owner_transfer(
+                <<Test as Config>::RuntimeOrigin>::signed(new_owner),
+                netuid
+            ),
+            Error::<Test>::SubnetOwnerTransferNotReady
+        );
+
+        System::set_block_number(executable_block);
+        assert_ok!(SubtensorModule::accept_subnet_owner_transfer(
+            <<Test as Config>::RuntimeOrigin>::signed(new_owner),
+            netuid
+        ));
+        System::assert_last_event(
+            Event::SubnetOwnerTransferred {
+                netuid,
+                old_owner: owner,
+                new_owner,
+            }
+            .into(),
+        );
+        assert_eq!(SubtensorModule::get_subnet_owner(netuid), new_owner);
+        assert!(!PendingSubnetOwnerTransfer::<Test>::contains_key(netuid));
+
+        // The locked balance is refunded to the new owner when the subnet is dissolved.
+        assert_ok!(SubtensorModule::user_remove_network(new_owner, netuid));
+        assert_eq!(SubtensorModule::get_coldkey_balance(&new_owner), lock);
+        assert_eq!(SubtensorModule::get_coldkey_balance(&owner), 0);
+    })
+}
+
+#[test]
+fn test_subnet_owner_transfer_cancel() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 2;
+        let owner = U256::from(1);
+        let new_owner = U256::from(2);
+        let stranger = U256::from(3);
+
+        add_network(netuid, 13, 0);
+        pallet_subtensor::SubnetOwner::<Test>::insert(netuid, owner);
+
+        assert_noop!(
+            SubtensorModule::cancel_subnet_owner_transfer(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                netuid
+            ),
+            Error::<Test>::NoPendingSubnetOwnerTransfer
+        );
+
+        assert_ok!(SubtensorModule::propose_subnet_owner_transfer(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            new_owner
+        ));
+
+        assert_noop!(
+            SubtensorModule::cancel_subnet_owner_transfer(
+                <<Test as Config>::RuntimeOrigin>::signed(stranger),
+                netuid
+            ),
+            DispatchError::BadOrigin
+        );
+
+        assert_ok!(SubtensorModule::cancel_subnet_owner_transfer(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid
+        ));
+        System::assert_last_event(Event::SubnetOwnerTransferCancelled { netuid, new_owner }.into());
+        assert!(!PendingSubnetOwnerTransfer::<Test>::contains_key(netuid));
+
+        // A cancelled transfer cannot be accepted.
+        System::set_block_number(
+            System::block_number() + SubnetOwnerTransferScheduleDuration::<Test>::get(),
+        );
+        assert_noop!(
+            SubtensorModule::accept_subnet_owner_transfer(
+                <<Test as Config>::RuntimeOrigin>::signed(new_owner),
+                netuid
+            ),
+            Error::<Test>::NoPendingSubnetOwnerTransfer
+        );
+        assert_eq!(SubtensorModule::get_subnet_owner(netuid), owner);
+
+        // The proposed owner and root can also cancel.
+        assert_ok!(SubtensorModule::propose_subnet_owner_transfer(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            new_owner
+        ));
+        assert_ok!(SubtensorModule::cancel_subnet_owner_transfer(
+            <<Test as Config>::RuntimeOrigin>::signed(new_owner),
+            netuid
+        ));
+        assert_ok!(SubtensorModule::propose_subnet_owner_transfer(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            new_owner
+        ));
+        assert_ok!(SubtensorModule::cancel_subnet_owner_transfer(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid
+        ));
+        assert!(!PendingSubnetOwnerTransfer::<Test>::contains_key(netuid));
+    })
+}
+
+#[test]
+fn test_cancel_scheduled_dissolve_network() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 2;
+        let owner = U256::from(1);
+        let stranger = U256::from(2);
+
+        add_network(netuid, 13, 0);
+        pallet_subtensor::SubnetOwner::<Test>::insert(netuid, owner);
+
+        assert_ok!(SubtensorModule::schedule_dissolve_network(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid
+        ));
+        let execution_block =
+            System::block_number() + DissolveNetworkScheduleDuration::<Test>::get();
         assert_eq!(
-            pallet_subtensor::SubnetOwner::<Test>::get(netuid),
-            new_network_owner_account_id
+            ScheduledNetworkDissolutions::<Test>::get(netuid),
+            Some((owner, execution_block))
+        );
+        assert!(SubtensorModule::get_scheduled_network_dissolution(netuid).is_some());
+
+        // A second dissolution cannot be scheduled while one is pending.
+        assert_noop!(
+            SubtensorModule::schedule_dissolve_network(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                netuid
+            ),
+            Error::<Test>::DissolveNetworkAlreadyScheduled
         );
 
+        assert_noop!(
+            SubtensorModule::cancel_scheduled_dissolve_network(
+                <<Test as Config>::RuntimeOrigin>::signed(stranger),
+                netuid
+            ),
+            DispatchError::BadOrigin
+        );
+
+        assert_ok!(SubtensorModule::cancel_scheduled_dissolve_network(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid
+        ));
+        System::assert_last_event(
+            Event::DissolveNetworkCancelled {
+                account: owner,
+                netuid,
+            }
+            .into(),
+        );
+        assert!(ScheduledNetworkDissolutions::<Test>::get(netuid).is_none());
+        assert_noop!(
+            SubtensorModule::cancel_scheduled_dissolve_network(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                netuid
+            ),
+            Error::<Test>::DissolveNetworkNotScheduled
+        );
+
+        // The cancelled dissolution never executes.
+        run_to_block(execution_block + 1);
+        assert!(SubtensorModule::if_subnet_exist(netuid));
+    })
+}
+
+#[test]
+fn test_scheduled_dissolve_network_record_cleared_on_execution() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 2;
+        let owner = U256::from(1);
+
+        add_network(netuid, 13, 0);
+        pallet_subtensor::SubnetOwner::<Test>::insert(netuid, owner);
+
+        assert_ok!(SubtensorModule::schedule_dissolve_network(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid
+        ));
+        let execution_block =
+            System::block_number() + DissolveNetworkScheduleDuration::<Test>::get();
+
+        // Root can cancel and reschedule on behalf of the owner.
+        assert_ok!(SubtensorModule::cancel_scheduled_dissolve_network(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid
+        ));
+        assert_ok!(SubtensorModule::schedule_dissolve_network(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid
+        ));
+
+        run_to_block(execution_block);
+        assert!(!SubtensorModule::if_subnet_exist(netuid));
+        assert!(ScheduledNetworkDissolutions::<Test>::get(netuid).is_none());
+        assert!(SubtensorModule::get_scheduled_network_dissolutions().is_empty());
+    })
+}
+
+#[test]
+fn test_root_schedule_dissolve_network() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 2;
+        let owner = U256::from(1);
+
+        assert_noop!(
+            SubtensorModule::schedule_dissolve_network(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid
+            ),
+            Error::<Test>::SubNetworkDoesNotExist
+        );
+
+        add_network(netuid, 13, 0);
+        pallet_subtensor::SubnetOwner::<Test>::insert(netuid, owner);
+
+        // Root schedules the dissolution on behalf of the owner.
+        assert_ok!(SubtensorModule::schedule_dissolve_network(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid
+        ));
+        let execution_block =
+            System::block_number() + DissolveNetworkScheduleDuration::<Test>::get();
+        System::assert_last_event(
+            Event::DissolveNetworkScheduled {
+                account: owner,
+                netuid,
+                execution_block,
+            }
+            .into(),
+        );
+        assert_eq!(
+            ScheduledNetworkDissolutions::<Test>::get(netuid),
+            Some((owner, execution_block))
+        );
+
+        run_to_block(execution_block);
+        assert!(!SubtensorModule::if_subnet_exist(netuid));
+        assert!(ScheduledNetworkDissolutions::<Test>::get(netuid).is_none());
+    })
+}
+
+#[test]
+fn test_schedule_dissolve_network_execution_after_owner_change() {
+    new_test_ext(1).execute_with(|| {
+        let block_number: u64 = 0;
+        let netuid: u16 = 2;
+        let tempo: u16 = 13;
+        let hotkey_account_id: U256 = U256::from(1);
+        let coldkey_account_id = U256::from(0); // Neighbour of the beast, har har
+        let new_network_owner_account_id = U256::from(2); //
+        let (nonce, work): (u64, Vec<u8>) = SubtensorModule::create_work_for_block_number(
+            netuid,
+            block_number,
+            129123813,
+            &hotkey_account_id,
+        );
+
+        //add network
+        add_network(netuid, tempo, 0);
+
+        assert_ok!(SubtensorModule::register(
+            <<Test as Config>::RuntimeOrigin>::signed(hotkey_account_id),
+            netuid,
+            block_number,
+            nonce,
+            work.clone(),
+            hotkey_account_id,
+            coldkey_account_id
+        ));
+
+        assert!(SubtensorModule::if_subnet_exist(netuid));
+
+        assert_ok!(SubtensorModule::schedule_dissolve_network(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey_account_id),
+            netuid
+        ));
+
         let current_block = System::block_number();
         let execution_block = current_block + DissolveNetworkScheduleDuration::<Test>::get();
 
+        System::assert_last_event(
+            Event::DissolveNetworkScheduled {
+                account: coldkey_account_id,
+                netuid,
+                execution_block,
+            }
+            .into(),
+        );
+        run_to_block(current_block + 1);
+        // the network changes owner after the call is scheduled
+        pallet_subtensor::SubnetOwner::<Test>::insert(netuid, new_network_owner_account_id);
+
+        run_to_block(execution_block);
+        // network exists since the scheduler is no longer the network owner, the record is dropped
+        assert!(SubtensorModule::if_subnet_exist(netuid));
+        System::assert_has_event(
+            Event::DissolveNetworkFailed {
+                account: coldkey_account_id,
+                netuid,
+                error: Error::<Test>::NotSubnetOwner.into(),
+            }
+            .into(),
+        );
+        assert!(ScheduledNetworkDissolutions::<Test>::get(netuid).is_none());
+        assert!(SubtensorModule::get_scheduled_network_dissolutions().is_empty());
+
+        // the new owner can schedule it again
+        assert_ok!(SubtensorModule::schedule_dissolve_network(
+            <<Test as Config>::RuntimeOrigin>::signed(new_network_owner_account_id),
+            netuid
+        ));
+    })
+}
+
+#[test]
+fn test_root_dissolve_network_keeps_scheduled_dissolution() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 2;
+        let owner = U256::from(1);
+        let non_owner = U256::from(2);
+
+        add_network(netuid, 13, 0);
+        pallet_subtensor::SubnetOwner::<Test>::insert(netuid, owner);
+
+        assert_ok!(SubtensorModule::schedule_dissolve_network(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid
+        ));
+        let execution_block =
+            System::block_number() + DissolveNetworkScheduleDuration::<Test>::get();
+
+        // A direct dissolution by root which fails leaves the scheduled one in place, even once
+        // it is due and its task has not run yet.
+        run_to_block(execution_block - 1);
+        System::set_block_number(execution_block);
+        assert_noop!(
+            SubtensorModule::dissolve_network(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                non_owner,
+                netuid
+            ),
+            Error::<Test>::NotSubnetOwner
+        );
+        assert_eq!(
+            ScheduledNetworkDissolutions::<Test>::get(netuid),
+            Some((owner, execution_block))
+        );
+        assert!(SubtensorModule::is_network_dissolution_scheduled(netuid));
+
+        // Only the scheduled call performs the scheduled dissolution.
+        assert_noop!(
+            SubtensorModule::dissolve_scheduled_network(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                netuid
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            SubtensorModule::dissolve_scheduled_network(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid + 1
+            ),
+            Error::<Test>::DissolveNetworkNotScheduled
+        );
+
+        System::set_block_number(execution_block - 1);
         run_to_block(execution_block);
-        // network exists since the caller is no the network owner
         assert!(!SubtensorModule::if_subnet_exist(netuid));
+        assert!(ScheduledNetworkDissolutions::<Test>::get(netuid).is_none());
+        assert!(!SubtensorModule::is_network_dissolution_scheduled(netuid));
     })
 }
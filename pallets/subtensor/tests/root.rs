@@ -5,7 +5,9 @@ use frame_support::{assert_err, assert_ok};
 use frame_system::Config;
 use frame_system::{EventRecord, Phase};
 use pallet_subtensor::Error;
-use pallet_subtensor::{migrations, SubnetIdentity};
+use pallet_subtensor::{
+    migrations, Event, HyperparameterTemplate, SubnetHyperparameter, SubnetIdentity,
+};
 use pallet_subtensor::{SubnetIdentities, SubnetIdentityOf};
 use sp_core::{Get, H256, U256};
 
@@ -237,6 +239,7 @@ fn test_root_set_weights() {
             log::debug!("Adding network with netuid: {}", netuid);
             assert_ok!(SubtensorModule::register_network(
                 <<Test as Config>::RuntimeOrigin>::signed(U256::from(netuid + 456)),
+                None,
             ));
         }
 
@@ -382,6 +385,7 @@ fn test_root_set_weights_out_of_order_netuids() {
             if netuid % 2 == 0 {
                 assert_ok!(SubtensorModule::register_network(
                     <<Test as Config>::RuntimeOrigin>::signed(U256::from(netuid)),
+                    None,
                 ));
             } else {
                 add_network(netuid as u16 * 10, 1000, 0)
@@ -473,6 +477,7 @@ fn test_root_subnet_creation_deletion() {
         // last_lock: 100000000000, min_lock: 100000000000, last_lock_block: 0, lock_reduction_interval: 2, current_block: 0, mult: 1 lock_cost: 100000000000
         assert_ok!(SubtensorModule::register_network(
             <<Test as Config>::RuntimeOrigin>::signed(owner),
+            None,
         ));
         // last_lock: 100000000000, min_lock: 100000000000, last_lock_block: 0, lock_reduction_interval: 2, current_block: 0, mult: 1 lock_cost: 100000000000
         assert_eq!(SubtensorModule::get_network_lock_cost(), 100_000_000_000);
@@ -480,6 +485,7 @@ fn test_root_subnet_creation_deletion() {
         // last_lock: 100000000000, min_lock: 100000000000, last_lock_block: 0, lock_reduction_interval: 2, current_block: 1, mult: 1 lock_cost: 100000000000
         assert_ok!(SubtensorModule::register_network(
             <<Test as Config>::RuntimeOrigin>::signed(owner),
+            None,
         ));
         // last_lock: 100000000000, min_lock: 100000000000, last_lock_block: 1, lock_reduction_interval: 2, current_block: 1, mult: 2 lock_cost: 200000000000
         assert_eq!(SubtensorModule::get_network_lock_cost(), 200_000_000_000); // Doubles from previous subnet creation
@@ -494,6 +500,7 @@ fn test_root_subnet_creation_deletion() {
         assert_eq!(SubtensorModule::get_network_lock_cost(), 100_000_000_000); // Reaches min value
         assert_ok!(SubtensorModule::register_network(
             <<Test as Config>::RuntimeOrigin>::signed(owner),
+            None,
         ));
         // last_lock: 100000000000, min_lock: 100000000000, last_lock_block: 4, lock_reduction_interval: 2, current_block: 4, mult: 2 lock_cost: 200000000000
         assert_eq!(SubtensorModule::get_network_lock_cost(), 200_000_000_000); // Doubles from previous subnet creation
@@ -501,6 +508,7 @@ fn test_root_subnet_creation_deletion() {
         // last_lock: 100000000000, min_lock: 100000000000, last_lock_block: 4, lock_reduction_interval: 2, current_block: 5, mult: 2 lock_cost: 150000000000
         assert_ok!(SubtensorModule::register_network(
             <<Test as Config>::RuntimeOrigin>::signed(owner),
+            None,
         ));
         // last_lock: 150000000000, min_lock: 100000000000, last_lock_block: 5, lock_reduction_interval: 2, current_block: 5, mult: 2 lock_cost: 300000000000
         assert_eq!(SubtensorModule::get_network_lock_cost(), 300_000_000_000); // Doubles from previous subnet creation
@@ -508,6 +516,7 @@ fn test_root_subnet_creation_deletion() {
         // last_lock: 150000000000, min_lock: 100000000000, last_lock_block: 5, lock_reduction_interval: 2, current_block: 6, mult: 2 lock_cost: 225000000000
         assert_ok!(SubtensorModule::register_network(
             <<Test as Config>::RuntimeOrigin>::signed(owner),
+            None,
         ));
         // last_lock: 225000000000, min_lock: 100000000000, last_lock_block: 6, lock_reduction_interval: 2, current_block: 6, mult: 2 lock_cost: 450000000000
         assert_eq!(SubtensorModule::get_network_lock_cost(), 450_000_000_000); // Increasing
@@ -515,16 +524,19 @@ fn test_root_subnet_creation_deletion() {
         // last_lock: 225000000000, min_lock: 100000000000, last_lock_block: 6, lock_reduction_interval: 2, current_block: 7, mult: 2 lock_cost: 337500000000
         assert_ok!(SubtensorModule::register_network(
             <<Test as Config>::RuntimeOrigin>::signed(owner),
+            None,
         ));
         // last_lock: 337500000000, min_lock: 100000000000, last_lock_block: 7, lock_reduction_interval: 2, current_block: 7, mult: 2 lock_cost: 675000000000
         assert_eq!(SubtensorModule::get_network_lock_cost(), 675_000_000_000); // Increasing.
         assert_ok!(SubtensorModule::register_network(
             <<Test as Config>::RuntimeOrigin>::signed(owner),
+            None,
         ));
         // last_lock: 337500000000, min_lock: 100000000000, last_lock_block: 7, lock_reduction_interval: 2, current_block: 7, mult: 2 lock_cost: 675000000000
         assert_eq!(SubtensorModule::get_network_lock_cost(), 1_350_000_000_000); // Double increasing.
         assert_ok!(SubtensorModule::register_network(
             <<Test as Config>::RuntimeOrigin>::signed(owner),
+            None,
         ));
         assert_eq!(SubtensorModule::get_network_lock_cost(), 2_700_000_000_000); // Double increasing again.
 
@@ -574,6 +586,7 @@ fn test_network_pruning() {
             ));
             assert_ok!(SubtensorModule::register_network(
                 <<Test as Config>::RuntimeOrigin>::signed(cold),
+                None,
             ));
             log::debug!("Adding network with netuid: {}", (i as u16) + 1);
             assert!(SubtensorModule::if_subnet_exist((i as u16) + 1));
@@ -647,16 +660,19 @@ fn test_network_prune_results() {
 
         assert_ok!(SubtensorModule::register_network(
             <<Test as Config>::RuntimeOrigin>::signed(owner),
+            None,
         ));
         step_block(3);
 
         assert_ok!(SubtensorModule::register_network(
             <<Test as Config>::RuntimeOrigin>::signed(owner),
+            None,
         ));
         step_block(3);
 
         assert_ok!(SubtensorModule::register_network(
             <<Test as Config>::RuntimeOrigin>::signed(owner),
+            None,
         ));
         step_block(3);
 
@@ -701,6 +717,7 @@ fn test_weights_after_network_pruning() {
             // Register a network
             assert_ok!(SubtensorModule::register_network(
                 <<Test as Config>::RuntimeOrigin>::signed(cold),
+                None,
             ));
 
             log::debug!("Adding network with netuid: {}", (i as u16) + 1);
@@ -761,6 +778,7 @@ fn test_weights_after_network_pruning() {
 
         assert_ok!(SubtensorModule::register_network(
             <<Test as Config>::RuntimeOrigin>::signed(cold),
+            None,
         ));
 
         // Subnet should not exist, as it would replace a previous subnet.
@@ -1014,7 +1032,8 @@ fn test_user_add_network_with_identity_fields_ok() {
 
         assert_ok!(SubtensorModule::user_add_network(
             RuntimeOrigin::signed(coldkey_1),
-            Some(identity_value_1.clone())
+            Some(identity_value_1.clone()),
+            None
         ));
 
         let balance_2 = SubtensorModule::get_network_lock_cost() + 10_000;
@@ -1022,7 +1041,8 @@ fn test_user_add_network_with_identity_fields_ok() {
 
         assert_ok!(SubtensorModule::user_add_network(
             RuntimeOrigin::signed(coldkey_2),
-            Some(identity_value_2.clone())
+            Some(identity_value_2.clone()),
+            None
         ));
 
         let stored_identity_1: SubnetIdentity = SubnetIdentities::<Test>::get(1).unwrap();
@@ -1052,3 +1072,90 @@ fn test_user_add_network_with_identity_fields_ok() {
         );
     });
 }
+
+#[test]
+fn test_register_network_with_hyperparameter_template() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let template_id: u16 = 7;
+        let template = HyperparameterTemplate {
+            name: b"small-experimental".to_vec(),
+            params: vec![
+                (SubnetHyperparameter::Rho, 20),
+                (SubnetHyperparameter::MaxAllowedUids, 128),
+                // More validators than UIDs, so it is skipped.
+                (SubnetHyperparameter::MaxAllowedValidators, 200),
+            ],
+        };
+
+        // Templates must have a name and set each hyperparameter once.
+        assert_err!(
+            SubtensorModule::set_hyperparameter_template(
+                template_id,
+                HyperparameterTemplate {
+                    name: Vec::new(),
+                    params: template.params.clone(),
+                }
+            ),
+            Error::<Test>::InvalidHyperparameterTemplate
+        );
+        assert_err!(
+            SubtensorModule::set_hyperparameter_template(
+                template_id,
+                HyperparameterTemplate {
+                    name: template.name.clone(),
+                    params: vec![
+                        (SubnetHyperparameter::Rho, 20),
+                        (SubnetHyperparameter::Rho, 30)
+                    ],
+                }
+            ),
+            Error::<Test>::InvalidHyperparameterTemplate
+        );
+        assert_ok!(SubtensorModule::set_hyperparameter_template(
+            template_id,
+            template.clone()
+        ));
+        assert_eq!(
+            SubtensorModule::get_hyperparameter_templates(),
+            vec![(template_id, template)]
+        );
+
+        SubtensorModule::add_balance_to_coldkey_account(
+            &coldkey,
+            SubtensorModule::get_network_lock_cost() * 2,
+        );
+        assert_err!(
+            SubtensorModule::register_network(
+                RuntimeOrigin::signed(coldkey),
+                Some(template_id + 1)
+            ),
+            Error::<Test>::HyperparameterTemplateNotFound
+        );
+        assert_ok!(SubtensorModule::register_network(
+            RuntimeOrigin::signed(coldkey),
+            Some(template_id)
+        ));
+
+        let netuid: u16 = 1;
+        assert_eq!(SubtensorModule::get_rho(netuid), 20);
+        assert_eq!(SubtensorModule::get_max_allowed_uids(netuid), 128);
+        assert_eq!(SubtensorModule::get_max_allowed_validators(netuid), 64);
+        System::assert_last_event(Event::NetworkAdded(netuid, 0).into());
+        System::assert_has_event(
+            Event::HyperparameterTemplateApplied {
+                netuid,
+                template_id,
+            }
+            .into(),
+        );
+
+        // Removing the template leaves the subnet untouched.
+        assert_ok!(SubtensorModule::remove_hyperparameter_template(template_id));
+        assert_err!(
+            SubtensorModule::remove_hyperparameter_template(template_id),
+            Error::<Test>::HyperparameterTemplateNotFound
+        );
+        assert_eq!(SubtensorModule::get_rho(netuid), 20);
+    });
+}
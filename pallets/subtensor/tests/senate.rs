@@ -216,7 +216,8 @@ fn test_senate_vote_works() {
                     account: senate_hotkey,
                     proposal_index: 0,
                     proposal_hash: hash,
-                    threshold: 1
+                    threshold: 1,
+                    metadata: None
                 })),
                 record(RuntimeEvent::Triumvirate(CollectiveEvent::Voted {
                     account: hotkey_account_id,
@@ -801,10 +802,114 @@ fn test_adjust_senate_events() {
         assert!(
             System::events().contains(&record(RuntimeEvent::SubtensorModule(
                 SubtensorEvent::SenateAdjusted {
-                    old_member: None,
+                    old_member: Some(hotkey_account_id),
                     new_member: replacement_hotkey_account_id
                 }
             )))
         );
     });
 }
+
+#[test]
+fn test_senate_rebalanced_by_root_stake() {
+    new_test_ext().execute_with(|| {
+        migrations::migrate_create_root_network::migrate_create_root_network::<Test>();
+
+        let netuid: u16 = 1;
+        let root_netuid = SubtensorModule::get_root_netuid();
+        let coldkey_account_id = U256::from(667);
+        let replacement_hotkey = U256::from(8);
+        let hotkeys = [U256::from(6), U256::from(7), replacement_hotkey];
+
+        add_network(netuid, 0, 0);
+        SubtensorModule::set_burn(netuid, 0);
+        SubtensorModule::set_max_registrations_per_block(netuid, 10);
+        SubtensorModule::set_target_registrations_per_interval(netuid, 10);
+        SubtensorModule::set_max_registrations_per_block(root_netuid, 10);
+        SubtensorModule::set_target_registrations_per_interval(root_netuid, 10);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey_account_id, 100_000);
+
+        // Register three staked root validators, they all join the senate.
+        for (i, hotkey) in hotkeys.iter().enumerate() {
+            assert_ok!(SubtensorModule::burned_register(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey_account_id),
+                netuid,
+                *hotkey
+            ));
+            assert_ok!(SubtensorModule::add_stake(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey_account_id),
+                *hotkey,
+                10_000 + i as u64
+            ));
+            assert_ok!(SubtensorModule::root_register(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey_account_id),
+                *hotkey
+            ));
+            assert!(Senate::is_member(hotkey));
+        }
+        // Take one of them out of the senate again.
+        assert_ok!(SenateMembers::remove_member(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            replacement_hotkey
+        ));
+
+        // The genesis members, which are not root validators, vote on a proposal.
+        let proposal = make_proposal(42);
+        let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+        let hash = BlakeTwo256::hash_of(&proposal);
+        assert_ok!(Triumvirate::propose(
+            RuntimeOrigin::signed(U256::from(1)),
+            Box::new(proposal.clone()),
+            proposal_len,
+            TryInto::<BlockNumberFor<Test>>::try_into(100u64)
+                .expect("convert u64 to block number.")
+        ));
+        assert_ok!(Triumvirate::do_vote(U256::from(1), hash, 0, true));
+        assert_ok!(Triumvirate::do_vote(U256::from(2), hash, 0, false));
+
+        // Nothing happens while the rebalance is disabled.
+        step_block(10);
+        assert_eq!(Senate::members().len(), 7);
+
+        SubtensorModule::set_senate_adjustment_interval(5);
+        System::reset_events();
+        run_to_block(15);
+        assert_eq!(System::block_number(), 15);
+
+        // The senate now consists of exactly the root validators.
+        let mut members = Senate::members();
+        members.sort();
+        assert_eq!(members, hotkeys.to_vec());
+
+        // The first outgoing member was replaced, the others removed.
+        let events: Vec<RuntimeEvent> = System::events().into_iter().map(|e| e.event).collect();
+        assert!(events.contains(&RuntimeEvent::SubtensorModule(
+            SubtensorEvent::SenateAdjusted {
+                old_member: Some(U256::from(1)),
+                new_member: replacement_hotkey
+            }
+        )));
+        for member in 2..=5 {
+            assert!(events.contains(&RuntimeEvent::SubtensorModule(
+                SubtensorEvent::SenateMemberRemoved {
+                    member: U256::from(member)
+                }
+            )));
+        }
+
+        // The votes of the outgoing members were removed.
+        assert_eq!(Triumvirate::has_voted(hash, 0, &U256::from(1)), Ok(false));
+        assert_eq!(Triumvirate::has_voted(hash, 0, &U256::from(2)), Ok(false));
+
+        // Blocks between rebuilds only read the interval, a rebuild reads the root validators.
+        let db_weight = <Test as frame_system::Config>::DbWeight::get();
+        assert_eq!(
+            SubtensorModule::adjust_senate_if_due(16),
+            db_weight.reads(1)
+        );
+        assert_eq!(
+            SubtensorModule::adjust_senate_if_due(20),
+            db_weight.reads(9)
+        );
+    });
+}
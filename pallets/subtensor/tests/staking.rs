@@ -9,6 +9,8 @@ use frame_support::sp_runtime::DispatchError;
 use mock::*;
 use pallet_subtensor::*;
 use sp_core::{H256, U256};
+use sp_runtime::traits::{DispatchInfoOf, SignedExtension};
+use sp_runtime::transaction_validity::{InvalidTransaction, TransactionValidityError};
 
 /***********************************************************
     staking::add_stake() tests
@@ -531,7 +533,7 @@ fn test_remove_stake_dispatch_info_ok() {
         assert_eq!(
             call.get_dispatch_info(),
             DispatchInfo {
-                weight: frame_support::weights::Weight::from_parts(1_061_000_000, 0)
+                weight: frame_support::weights::Weight::from_parts(1_311_000_000, 0)
                     .add_proof_size(43991),
                 class: DispatchClass::Normal,
                 pays_fee: Pays::No
@@ -811,6 +813,160 @@ fn test_remove_stake_total_issuance_no_change() {
     });
 }
 
+#[test]
+fn test_remove_stake_with_unbonding_period() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey_account_id = U256::from(4343);
+        let hotkey_account_id = U256::from(4968585);
+        let amount = 10000;
+        let netuid: u16 = 1;
+        let period: u64 = 10;
+
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey_account_id, coldkey_account_id, 0);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(
+            &coldkey_account_id,
+            &hotkey_account_id,
+            amount,
+        );
+        SubtensorModule::set_unbonding_period(period);
+
+        // Removed stake waits in the unbonding queue instead of the free balance
+        assert_ok!(SubtensorModule::remove_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey_account_id),
+            hotkey_account_id,
+            amount / 2
+        ));
+        let unlock_block = SubtensorModule::get_current_block_as_u64() + period;
+        System::assert_last_event(Event::StakeRemoved(hotkey_account_id, amount / 2).into());
+        System::assert_has_event(
+            Event::StakeUnbonding {
+                coldkey: coldkey_account_id,
+                hotkey: hotkey_account_id,
+                amount: amount / 2,
+                unlock_block,
+            }
+            .into(),
+        );
+        assert_eq!(SubtensorModule::get_coldkey_balance(&coldkey_account_id), 0);
+        assert_eq!(
+            SubtensorModule::get_total_stake_for_hotkey(&hotkey_account_id),
+            amount / 2
+        );
+        assert_eq!(SubtensorModule::get_total_stake(), amount / 2);
+        assert_eq!(
+            UnbondingQueue::<Test>::get(coldkey_account_id),
+            vec![(amount / 2, unlock_block)]
+        );
+        assert_eq!(TotalUnbonding::<Test>::get(), amount / 2);
+
+        // Nothing can be withdrawn before the unlock block
+        assert_noop!(
+            SubtensorModule::withdraw_unbonded(<<Test as Config>::RuntimeOrigin>::signed(
+                coldkey_account_id
+            )),
+            Error::<Test>::NoUnbondedStakeToWithdraw
+        );
+
+        // A later removal unlocks later
+        run_to_block(3);
+        assert_ok!(SubtensorModule::remove_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey_account_id),
+            hotkey_account_id,
+            amount / 2
+        ));
+        let later_unlock_block = SubtensorModule::get_current_block_as_u64() + period;
+        assert_eq!(
+            UnbondingQueue::<Test>::get(coldkey_account_id),
+            vec![(amount / 2, unlock_block), (amount / 2, later_unlock_block)]
+        );
+
+        // Only the unlocked entry is withdrawn
+        run_to_block(unlock_block);
+        assert_ok!(SubtensorModule::withdraw_unbonded(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey_account_id)
+        ));
+        System::assert_last_event(
+            Event::UnbondedWithdrawn {
+                coldkey: coldkey_account_id,
+                amount: amount / 2,
+            }
+            .into(),
+        );
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&coldkey_account_id),
+            amount / 2
+        );
+        assert_eq!(
+            UnbondingQueue::<Test>::get(coldkey_account_id),
+            vec![(amount / 2, later_unlock_block)]
+        );
+        assert_eq!(TotalUnbonding::<Test>::get(), amount / 2);
+
+        run_to_block(later_unlock_block);
+        assert_ok!(SubtensorModule::withdraw_unbonded(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey_account_id)
+        ));
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&coldkey_account_id),
+            amount
+        );
+        assert!(!UnbondingQueue::<Test>::contains_key(coldkey_account_id));
+        assert_eq!(TotalUnbonding::<Test>::get(), 0);
+    });
+}
+
+#[test]
+fn test_forced_unstake_with_unbonding_period() {
+    new_test_ext(1).execute_with(|| {
+        let owner_coldkey = U256::from(4343);
+        let nominator_coldkey = U256::from(4344);
+        let hotkey = U256::from(4968585);
+        let amount = 10000;
+        let netuid: u16 = 1;
+        let period: u64 = 10;
+
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey, owner_coldkey, 0);
+        SubtensorModule::set_unbonding_period(period);
+        let unlock_block = SubtensorModule::get_current_block_as_u64() + period;
+
+        // A cleared small nomination is queued to unbond
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&nominator_coldkey, &hotkey, 10);
+        SubtensorModule::set_nominator_min_required_stake(amount);
+        SubtensorModule::clear_small_nomination_if_required(&hotkey, &nominator_coldkey, 10);
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&nominator_coldkey, &hotkey),
+            0
+        );
+        assert_eq!(SubtensorModule::get_coldkey_balance(&nominator_coldkey), 0);
+        assert_eq!(
+            UnbondingQueue::<Test>::get(nominator_coldkey),
+            vec![(10, unlock_block)]
+        );
+
+        // So is the stake removed by unstaking every coldkey from the hotkey
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&owner_coldkey, &hotkey, amount);
+        SubtensorModule::unstake_all_coldkeys_from_hotkey_account(&hotkey);
+        assert_eq!(SubtensorModule::get_total_stake_for_hotkey(&hotkey), 0);
+        assert_eq!(SubtensorModule::get_coldkey_balance(&owner_coldkey), 0);
+        System::assert_last_event(
+            Event::StakeUnbonding {
+                coldkey: owner_coldkey,
+                hotkey,
+                amount,
+                unlock_block,
+            }
+            .into(),
+        );
+        assert_eq!(
+            UnbondingQueue::<Test>::get(owner_coldkey),
+            vec![(amount, unlock_block)]
+        );
+        assert_eq!(TotalUnbonding::<Test>::get(), amount + 10);
+    });
+}
+
 /***********************************************************
     staking::get_coldkey_balance() tests
 ************************************************************/
@@ -1834,6 +1990,88 @@ fn test_delegate_take_can_be_increased() {
     });
 }
 
+// Verify a delegate take increase only applies once the notice period has passed
+#[test]
+fn test_delegate_take_increase_waits_for_notice_period() {
+    new_test_ext(1).execute_with(|| {
+        // Make account
+        let hotkey0 = U256::from(1);
+        let coldkey0 = U256::from(3);
+
+        // Add balance
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey0, 100000);
+
+        // Register the neuron to a new network
+        let netuid = 1;
+        add_network(netuid, 0, 0);
+        register_ok_neuron(netuid, hotkey0, coldkey0, 124124);
+
+        // Coldkey / hotkey 0 become delegates with 9% take
+        assert_ok!(SubtensorModule::do_become_delegate(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey0),
+            hotkey0,
+            SubtensorModule::get_min_delegate_take()
+        ));
+        DelegateTakeNoticePeriod::<Test>::put(10);
+
+        step_block(1 + InitialTxDelegateTakeRateLimit::get() as u16);
+
+        // Coldkey / hotkey 0 announces an increase to 12.5%
+        assert_ok!(SubtensorModule::do_increase_take(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey0),
+            hotkey0,
+            u16::MAX / 8
+        ));
+        let effective_block = SubtensorModule::get_current_block_as_u64() + 10;
+        assert_eq!(
+            SubtensorModule::get_hotkey_take(&hotkey0),
+            SubtensorModule::get_min_delegate_take()
+        );
+        assert_eq!(
+            SubtensorModule::get_pending_delegate_take(&hotkey0),
+            Some((u16::MAX / 8, effective_block))
+        );
+        System::assert_last_event(
+            Event::TakeIncreaseScheduled {
+                coldkey: coldkey0,
+                hotkey: hotkey0,
+                take: u16::MAX / 8,
+                effective_block,
+            }
+            .into(),
+        );
+
+        // The take is unchanged until the notice period has passed
+        step_block(9);
+        assert_eq!(
+            SubtensorModule::get_hotkey_take(&hotkey0),
+            SubtensorModule::get_min_delegate_take()
+        );
+        step_block(1);
+        assert_eq!(SubtensorModule::get_hotkey_take(&hotkey0), u16::MAX / 8);
+        assert_eq!(SubtensorModule::get_pending_delegate_take(&hotkey0), None);
+
+        // Decreasing the take drops an announced increase
+        step_block(1 + InitialTxDelegateTakeRateLimit::get() as u16);
+        assert_ok!(SubtensorModule::do_increase_take(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey0),
+            hotkey0,
+            u16::MAX / 6
+        ));
+        assert_ok!(SubtensorModule::do_decrease_take(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey0),
+            hotkey0,
+            SubtensorModule::get_min_delegate_take()
+        ));
+        assert_eq!(SubtensorModule::get_pending_delegate_take(&hotkey0), None);
+        step_block(10);
+        assert_eq!(
+            SubtensorModule::get_hotkey_take(&hotkey0),
+            SubtensorModule::get_min_delegate_take()
+        );
+    });
+}
+
 // Verify delegate take can not be decreased with increase_take
 #[test]
 fn test_delegate_take_can_not_be_decreased_with_increase_take() {
@@ -2306,3 +2544,600 @@ fn test_get_total_delegated_stake_exclude_owner_stake() {
         );
     });
 }
+
+/************************************************************
+    staking::move_stake() tests
+************************************************************/
+
+#[test]
+fn test_move_stake_ok() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let coldkey = U256::from(1);
+        let origin_hotkey = U256::from(2);
+        let destination_hotkey = U256::from(3);
+        let amount = 10_000;
+
+        add_network(netuid, 0, 0);
+        register_ok_neuron(netuid, origin_hotkey, coldkey, 0);
+        register_ok_neuron(netuid, destination_hotkey, coldkey, 1);
+
+        SubtensorModule::add_balance_to_coldkey_account(
+            &coldkey,
+            amount + ExistentialDeposit::get(),
+        );
+        assert_ok!(SubtensorModule::add_stake(
+            RuntimeOrigin::signed(coldkey),
+            origin_hotkey,
+            amount
+        ));
+        let balance_before = SubtensorModule::get_coldkey_balance(&coldkey);
+        let total_stake_before = SubtensorModule::get_total_stake();
+
+        assert_ok!(SubtensorModule::move_stake(
+            RuntimeOrigin::signed(coldkey),
+            origin_hotkey,
+            destination_hotkey,
+            4_000
+        ));
+
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&coldkey, &origin_hotkey),
+            amount - 4_000
+        );
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&coldkey, &destination_hotkey),
+            4_000
+        );
+        assert_eq!(
+            SubtensorModule::get_total_stake_for_coldkey(&coldkey),
+            amount
+        );
+        assert_eq!(SubtensorModule::get_total_stake(), total_stake_before);
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&coldkey),
+            balance_before
+        );
+        System::assert_last_event(
+            Event::StakeMoved {
+                coldkey,
+                origin_hotkey,
+                destination_hotkey,
+                amount: 4_000,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn test_move_stake_err() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let coldkey = U256::from(1);
+        let origin_hotkey = U256::from(2);
+        let destination_hotkey = U256::from(3);
+        let other_coldkey = U256::from(4);
+        let other_hotkey = U256::from(5);
+        let amount = 10_000;
+
+        add_network(netuid, 0, 0);
+        register_ok_neuron(netuid, origin_hotkey, coldkey, 0);
+        register_ok_neuron(netuid, destination_hotkey, coldkey, 1);
+        register_ok_neuron(netuid, other_hotkey, other_coldkey, 2);
+
+        SubtensorModule::add_balance_to_coldkey_account(
+            &coldkey,
+            amount + ExistentialDeposit::get(),
+        );
+        assert_ok!(SubtensorModule::add_stake(
+            RuntimeOrigin::signed(coldkey),
+            origin_hotkey,
+            amount
+        ));
+
+        assert_noop!(
+            SubtensorModule::move_stake(
+                RuntimeOrigin::signed(coldkey),
+                origin_hotkey,
+                origin_hotkey,
+                1_000
+            ),
+            Error::<Test>::CannotMoveStakeToSameHotkey
+        );
+        assert_noop!(
+            SubtensorModule::move_stake(
+                RuntimeOrigin::signed(coldkey),
+                origin_hotkey,
+                U256::from(99),
+                1_000
+            ),
+            Error::<Test>::HotKeyAccountNotExists
+        );
+        assert_noop!(
+            SubtensorModule::move_stake(
+                RuntimeOrigin::signed(coldkey),
+                origin_hotkey,
+                other_hotkey,
+                1_000
+            ),
+            Error::<Test>::HotKeyNotDelegateAndSignerNotOwnHotKey
+        );
+        assert_noop!(
+            SubtensorModule::move_stake(
+                RuntimeOrigin::signed(coldkey),
+                origin_hotkey,
+                destination_hotkey,
+                0
+            ),
+            Error::<Test>::StakeToWithdrawIsZero
+        );
+        assert_noop!(
+            SubtensorModule::move_stake(
+                RuntimeOrigin::signed(coldkey),
+                origin_hotkey,
+                destination_hotkey,
+                amount + 1
+            ),
+            Error::<Test>::NotEnoughStakeToWithdraw
+        );
+    });
+}
+
+#[test]
+fn test_move_stake_between_delegates_nominator_min() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let delegate_coldkey = U256::from(1);
+        let origin_hotkey = U256::from(2);
+        let destination_hotkey = U256::from(3);
+        let nominator = U256::from(4);
+        let minimum_threshold = 10_000;
+
+        add_network(netuid, 0, 0);
+        register_ok_neuron(netuid, origin_hotkey, delegate_coldkey, 0);
+        register_ok_neuron(netuid, destination_hotkey, delegate_coldkey, 1);
+        assert_ok!(SubtensorModule::become_delegate(
+            RuntimeOrigin::signed(delegate_coldkey),
+            origin_hotkey
+        ));
+        step_block(1);
+        assert_ok!(SubtensorModule::become_delegate(
+            RuntimeOrigin::signed(delegate_coldkey),
+            destination_hotkey
+        ));
+        SubtensorModule::set_nominator_min_required_stake(minimum_threshold);
+
+        SubtensorModule::add_balance_to_coldkey_account(
+            &nominator,
+            25_000 + ExistentialDeposit::get(),
+        );
+        assert_ok!(SubtensorModule::add_stake(
+            RuntimeOrigin::signed(nominator),
+            origin_hotkey,
+            25_000
+        ));
+
+        // The nomination on the destination would be below the minimum.
+        assert_noop!(
+            SubtensorModule::move_stake(
+                RuntimeOrigin::signed(nominator),
+                origin_hotkey,
+                destination_hotkey,
+                minimum_threshold - 1
+            ),
+            Error::<Test>::NomStakeBelowMinimumThreshold
+        );
+
+        // Leaving less than the minimum on the origin clears the remainder to the balance.
+        assert_ok!(SubtensorModule::move_stake(
+            RuntimeOrigin::signed(nominator),
+            origin_hotkey,
+            destination_hotkey,
+            20_000
+        ));
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&nominator, &origin_hotkey),
+            0
+        );
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&nominator, &destination_hotkey),
+            20_000
+        );
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&nominator),
+            5_000 + ExistentialDeposit::get()
+        );
+    });
+}
+
+#[test]
+fn test_move_stake_keeps_emission_eligibility() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let coldkey = U256::from(1);
+        let origin_hotkey = U256::from(2);
+        let destination_hotkey = U256::from(3);
+        let amount = 10_000;
+
+        add_network(netuid, 0, 0);
+        register_ok_neuron(netuid, origin_hotkey, coldkey, 0);
+        register_ok_neuron(netuid, destination_hotkey, coldkey, 1);
+        SubtensorModule::add_balance_to_coldkey_account(
+            &coldkey,
+            amount + ExistentialDeposit::get(),
+        );
+        assert_ok!(SubtensorModule::add_stake(
+            RuntimeOrigin::signed(coldkey),
+            origin_hotkey,
+            amount
+        ));
+        let staked_at = LastAddStakeIncrease::<Test>::get(origin_hotkey, coldkey);
+
+        // Both hotkeys drained at the same block: the stake keeps its original age.
+        LastHotkeyEmissionDrain::<Test>::insert(origin_hotkey, 5);
+        LastHotkeyEmissionDrain::<Test>::insert(destination_hotkey, 5);
+        System::set_block_number(10);
+        assert_ok!(SubtensorModule::move_stake(
+            RuntimeOrigin::signed(coldkey),
+            origin_hotkey,
+            destination_hotkey,
+            1_000
+        ));
+        assert_eq!(
+            LastAddStakeIncrease::<Test>::get(destination_hotkey, coldkey),
+            staked_at
+        );
+
+        // The origin drained after the destination: the moved stake is treated as new.
+        LastHotkeyEmissionDrain::<Test>::insert(origin_hotkey, 8);
+        System::set_block_number(20);
+        assert_ok!(SubtensorModule::move_stake(
+            RuntimeOrigin::signed(coldkey),
+            origin_hotkey,
+            destination_hotkey,
+            1_000
+        ));
+        assert_eq!(
+            LastAddStakeIncrease::<Test>::get(destination_hotkey, coldkey),
+            20
+        );
+    });
+}
+
+#[test]
+fn test_move_stake_away_from_pending_take_increase_is_not_paid_twice() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let coldkey = U256::from(1);
+        let origin_hotkey = U256::from(2);
+        let destination_hotkey = U256::from(3);
+        let amount = 10_000;
+
+        add_network(netuid, 0, 0);
+        register_ok_neuron(netuid, origin_hotkey, coldkey, 0);
+        register_ok_neuron(netuid, destination_hotkey, coldkey, 1);
+        SubtensorModule::add_balance_to_coldkey_account(
+            &coldkey,
+            amount + ExistentialDeposit::get(),
+        );
+        assert_ok!(SubtensorModule::add_stake(
+            RuntimeOrigin::signed(coldkey),
+            origin_hotkey,
+            amount
+        ));
+
+        // The origin drained after the destination and has announced a take increase: the
+        // moved stake is still treated as new.
+        LastHotkeyEmissionDrain::<Test>::insert(origin_hotkey, 8);
+        LastHotkeyEmissionDrain::<Test>::insert(destination_hotkey, 5);
+        PendingDelegateTakes::<Test>::insert(origin_hotkey, (u16::MAX / 8, 100));
+        System::set_block_number(10);
+        assert_ok!(SubtensorModule::move_stake(
+            RuntimeOrigin::signed(coldkey),
+            origin_hotkey,
+            destination_hotkey,
+            1_000
+        ));
+        assert_eq!(
+            LastAddStakeIncrease::<Test>::get(destination_hotkey, coldkey),
+            10
+        );
+    });
+}
+
+#[test]
+fn test_unstake_during_take_notice_restakes_without_penalty() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let coldkey = U256::from(1);
+        let origin_hotkey = U256::from(2);
+        let destination_hotkey = U256::from(3);
+        let amount = 10_000;
+
+        add_network(netuid, 0, 0);
+        register_ok_neuron(netuid, origin_hotkey, coldkey, 0);
+        register_ok_neuron(netuid, destination_hotkey, coldkey, 1);
+        SubtensorModule::add_balance_to_coldkey_account(
+            &coldkey,
+            2 * amount + ExistentialDeposit::get(),
+        );
+        assert_ok!(SubtensorModule::add_stake(
+            RuntimeOrigin::signed(coldkey),
+            origin_hotkey,
+            amount
+        ));
+        LastAddStakeIncrease::<Test>::insert(destination_hotkey, coldkey, 1);
+        LastHotkeyEmissionDrain::<Test>::insert(origin_hotkey, 5);
+        LastHotkeyEmissionDrain::<Test>::insert(destination_hotkey, 8);
+        SubtensorModule::schedule_pending_delegate_take(&origin_hotkey, u16::MAX / 8, 100);
+
+        // Unstaking during the notice period is recorded
+        System::set_block_number(10);
+        assert_ok!(SubtensorModule::remove_stake(
+            RuntimeOrigin::signed(coldkey),
+            origin_hotkey,
+            5_000
+        ));
+        assert_eq!(
+            TakeNoticeUnstakes::<Test>::get(coldkey),
+            Some((5_000, 5, 100))
+        );
+
+        // More than was unstaked is treated as new stake
+        System::set_block_number(11);
+        assert_ok!(SubtensorModule::add_stake(
+            RuntimeOrigin::signed(coldkey),
+            destination_hotkey,
+            6_000
+        ));
+        assert_eq!(
+            LastAddStakeIncrease::<Test>::get(destination_hotkey, coldkey),
+            11
+        );
+
+        // The unstaked amount keeps its eligibility on a hotkey drained no earlier
+        LastAddStakeIncrease::<Test>::insert(destination_hotkey, coldkey, 1);
+        System::set_block_number(12);
+        assert_ok!(SubtensorModule::add_stake(
+            RuntimeOrigin::signed(coldkey),
+            destination_hotkey,
+            4_000
+        ));
+        assert_eq!(
+            LastAddStakeIncrease::<Test>::get(destination_hotkey, coldkey),
+            1
+        );
+        assert_eq!(
+            TakeNoticeUnstakes::<Test>::get(coldkey),
+            Some((1_000, 5, 100))
+        );
+    });
+}
+
+/************************************************************
+    staking::transfer_stake() tests
+************************************************************/
+
+#[test]
+fn test_transfer_stake_ok() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let delegate_coldkey = U256::from(1);
+        let hotkey = U256::from(2);
+        let origin_coldkey = U256::from(3);
+        let destination_coldkey = U256::from(4);
+        let amount = 10_000;
+
+        add_network(netuid, 0, 0);
+        register_ok_neuron(netuid, hotkey, delegate_coldkey, 0);
+        assert_ok!(SubtensorModule::become_delegate(
+            RuntimeOrigin::signed(delegate_coldkey),
+            hotkey
+        ));
+        SubtensorModule::add_balance_to_coldkey_account(
+            &origin_coldkey,
+            amount + ExistentialDeposit::get(),
+        );
+        assert_ok!(SubtensorModule::add_stake(
+            RuntimeOrigin::signed(origin_coldkey),
+            hotkey,
+            amount
+        ));
+        LastAddStakeIncrease::<Test>::insert(hotkey, origin_coldkey, 1);
+        let total_hotkey_stake = SubtensorModule::get_total_stake_for_hotkey(&hotkey);
+
+        assert_ok!(SubtensorModule::transfer_stake(
+            RuntimeOrigin::signed(origin_coldkey),
+            destination_coldkey,
+            hotkey,
+            4_000
+        ));
+
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&origin_coldkey, &hotkey),
+            amount - 4_000
+        );
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&destination_coldkey, &hotkey),
+            4_000
+        );
+        assert_eq!(
+            SubtensorModule::get_total_stake_for_coldkey(&origin_coldkey),
+            amount - 4_000
+        );
+        assert_eq!(
+            SubtensorModule::get_total_stake_for_coldkey(&destination_coldkey),
+            4_000
+        );
+        assert_eq!(
+            SubtensorModule::get_total_stake_for_hotkey(&hotkey),
+            total_hotkey_stake
+        );
+        assert_eq!(
+            StakingHotkeys::<Test>::get(destination_coldkey),
+            vec![hotkey]
+        );
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&destination_coldkey),
+            0
+        );
+        // The destination keeps the emission eligibility of the transferred stake.
+        assert_eq!(
+            LastAddStakeIncrease::<Test>::get(hotkey, destination_coldkey),
+            1
+        );
+        System::assert_last_event(
+            Event::StakeTransferred {
+                origin_coldkey,
+                destination_coldkey,
+                hotkey,
+                amount: 4_000,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn test_transfer_stake_err() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let coldkey = U256::from(1);
+        let hotkey = U256::from(2);
+        let destination_coldkey = U256::from(3);
+        let amount = 10_000;
+
+        add_network(netuid, 0, 0);
+        register_ok_neuron(netuid, hotkey, coldkey, 0);
+        SubtensorModule::add_balance_to_coldkey_account(
+            &coldkey,
+            amount + ExistentialDeposit::get(),
+        );
+        assert_ok!(SubtensorModule::add_stake(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            amount
+        ));
+
+        assert_noop!(
+            SubtensorModule::transfer_stake(RuntimeOrigin::signed(coldkey), coldkey, hotkey, 1_000),
+            Error::<Test>::SameColdkey
+        );
+        // The hotkey is not a delegate, so only its owner can hold stake on it.
+        assert_noop!(
+            SubtensorModule::transfer_stake(
+                RuntimeOrigin::signed(coldkey),
+                destination_coldkey,
+                hotkey,
+                1_000
+            ),
+            Error::<Test>::HotKeyNotDelegateAndSignerNotOwnHotKey
+        );
+
+        assert_ok!(SubtensorModule::become_delegate(
+            RuntimeOrigin::signed(coldkey),
+            hotkey
+        ));
+        assert_noop!(
+            SubtensorModule::transfer_stake(
+                RuntimeOrigin::signed(coldkey),
+                destination_coldkey,
+                hotkey,
+                amount + 1
+            ),
+            Error::<Test>::NotEnoughStakeToWithdraw
+        );
+        SubtensorModule::set_nominator_min_required_stake(5_000);
+        assert_noop!(
+            SubtensorModule::transfer_stake(
+                RuntimeOrigin::signed(coldkey),
+                destination_coldkey,
+                hotkey,
+                1_000
+            ),
+            Error::<Test>::NomStakeBelowMinimumThreshold
+        );
+
+        ColdkeySwapScheduled::<Test>::insert(coldkey, ());
+        assert_noop!(
+            SubtensorModule::transfer_stake(
+                RuntimeOrigin::signed(coldkey),
+                destination_coldkey,
+                hotkey,
+                5_000
+            ),
+            Error::<Test>::SwapAlreadyScheduled
+        );
+    });
+}
+
+#[test]
+fn test_transfer_stake_signed_extension() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let info: DispatchInfo =
+            DispatchInfoOf::<<Test as frame_system::Config>::RuntimeCall>::default();
+        let extension = SubtensorSignedExtension::<Test>::new();
+        let transfer_stake: RuntimeCall = SubtensorCall::transfer_stake {
+            destination_coldkey: U256::from(2),
+            hotkey: U256::from(3),
+            amount: 1,
+        }
+        .into();
+
+        assert_ok!(extension.validate(&coldkey, &transfer_stake, &info, 10));
+
+        ColdkeySwapScheduled::<Test>::insert(coldkey, ());
+        assert_eq!(
+            extension.validate(&coldkey, &transfer_stake, &info, 10),
+            Err(TransactionValidityError::Invalid(
+                InvalidTransaction::Custom(CustomTransactionError::ColdkeyInSwapSchedule.into())
+            ))
+        );
+    });
+}
+
+#[test]
+fn test_set_reward_payout_signed_extension() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let info: DispatchInfo =
+            DispatchInfoOf::<<Test as frame_system::Config>::RuntimeCall>::default();
+        let extension = SubtensorSignedExtension::<Test>::new();
+        let set_reward_payout: RuntimeCall = SubtensorCall::set_reward_payout {
+            hotkey: U256::from(3),
+            payout: RewardPayout::Account(U256::from(2)),
+        }
+        .into();
+
+        assert_ok!(extension.validate(&coldkey, &set_reward_payout, &info, 10));
+
+        // Rewards cannot be redirected while the coldkey is being swapped
+        ColdkeySwapScheduled::<Test>::insert(coldkey, ());
+        assert_eq!(
+            extension.validate(&coldkey, &set_reward_payout, &info, 10),
+            Err(TransactionValidityError::Invalid(
+                InvalidTransaction::Custom(CustomTransactionError::ColdkeyInSwapSchedule.into())
+            ))
+        );
+        ColdkeySwapScheduled::<Test>::remove(coldkey);
+
+        ColdkeySwapDisputes::<Test>::insert(coldkey, vec![U256::from(4)]);
+        assert_eq!(
+            extension.validate(&coldkey, &set_reward_payout, &info, 10),
+            Err(TransactionValidityError::Invalid(
+                InvalidTransaction::Custom(CustomTransactionError::ColdkeyInArbitration.into())
+            ))
+        );
+        ColdkeySwapDisputes::<Test>::remove(coldkey);
+
+        KeySwapLocks::<Test>::insert(coldkey, U256::from(4));
+        assert_eq!(
+            extension.validate(&coldkey, &set_reward_payout, &info, 10),
+            Err(TransactionValidityError::Invalid(
+                InvalidTransaction::Custom(CustomTransactionError::KeySwapInProgress.into())
+            ))
+        );
+    });
+}
@@ -4,6 +4,7 @@ use frame_support::weights::Weight;
 use frame_support::{assert_err, assert_noop, assert_ok};
 use frame_system::{Config, RawOrigin};
 mod mock;
+use frame_support::dispatch::DispatchInfo;
 use frame_support::error::BadOrigin;
 use frame_support::traits::schedule::v3::Named as ScheduleNamed;
 use frame_support::traits::schedule::DispatchTime;
@@ -13,6 +14,8 @@ use pallet_subtensor::*;
 use pallet_subtensor::{Call, ColdkeySwapScheduleDuration, Error};
 use sp_core::H256;
 use sp_core::U256;
+use sp_runtime::traits::{DispatchInfoOf, SignedExtension};
+use sp_runtime::transaction_validity::{InvalidTransaction, TransactionValidityError};
 use sp_runtime::DispatchError;
 
 // SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test swap_coldkey -- test_swap_total_hotkey_coldkey_stakes_this_interval --exact --nocapture
@@ -1628,3 +1631,814 @@ fn test_coldkey_swap_no_identity_no_changes_newcoldkey_exists() {
         assert!(Identities::<Test>::get(new_coldkey).is_some());
     });
 }
+
+// SKIP_WASM_BUILD=1 RUST_LOG=info cargo test --test swap_coldkey -- test_cancel_scheduled_swap_coldkey --exact --nocapture
+#[test]
+fn test_cancel_scheduled_swap_coldkey() {
+    new_test_ext(1).execute_with(|| {
+        let old_coldkey = U256::from(1);
+        let new_coldkey = U256::from(2);
+        let attacker = U256::from(3);
+        let hotkey = U256::from(4);
+        let netuid = 1u16;
+
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey, old_coldkey, 0);
+        SubtensorModule::add_balance_to_coldkey_account(&old_coldkey, 1_000_000_000_000);
+
+        assert_ok!(SubtensorModule::schedule_swap_coldkey(
+            <<Test as Config>::RuntimeOrigin>::signed(old_coldkey),
+            new_coldkey
+        ));
+        let execution_block = System::block_number() + ColdkeySwapScheduleDuration::<Test>::get();
+        assert_eq!(
+            ScheduledColdkeySwaps::<Test>::get(old_coldkey),
+            Some((new_coldkey, execution_block))
+        );
+        assert!(SubtensorModule::get_scheduled_coldkey_swap(old_coldkey.encode()).is_some());
+
+        // Only the scheduling coldkey or root can cancel.
+        assert_noop!(
+            SubtensorModule::cancel_scheduled_swap_coldkey(
+                <<Test as Config>::RuntimeOrigin>::signed(attacker),
+                old_coldkey
+            ),
+            BadOrigin
+        );
+
+        assert_ok!(SubtensorModule::cancel_scheduled_swap_coldkey(
+            <<Test as Config>::RuntimeOrigin>::signed(old_coldkey),
+            old_coldkey
+        ));
+        System::assert_last_event(
+            Event::ColdkeySwapCancelled {
+                old_coldkey,
+                new_coldkey,
+            }
+            .into(),
+        );
+        assert!(!ColdkeySwapScheduled::<Test>::contains_key(old_coldkey));
+        assert!(ScheduledColdkeySwaps::<Test>::get(old_coldkey).is_none());
+        assert!(SubtensorModule::get_scheduled_coldkey_swap(old_coldkey.encode()).is_none());
+
+        // Nothing left to cancel.
+        assert_noop!(
+            SubtensorModule::cancel_scheduled_swap_coldkey(
+                <<Test as Config>::RuntimeOrigin>::signed(old_coldkey),
+                old_coldkey
+            ),
+            Error::<Test>::SwapNotScheduled
+        );
+
+        // The cancelled swap never executes.
+        run_to_block(execution_block + 1);
+        assert_eq!(Owner::<Test>::get(hotkey), old_coldkey);
+
+        // A new swap can be scheduled and cancelled by root.
+        assert_ok!(SubtensorModule::schedule_swap_coldkey(
+            <<Test as Config>::RuntimeOrigin>::signed(old_coldkey),
+            new_coldkey
+        ));
+        assert_ok!(SubtensorModule::cancel_scheduled_swap_coldkey(
+            RawOrigin::Root.into(),
+            old_coldkey
+        ));
+        assert!(!ColdkeySwapScheduled::<Test>::contains_key(old_coldkey));
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=info cargo test --test swap_coldkey -- test_contested_swap_coldkey_freezes_swap --exact --nocapture
+#[test]
+fn test_contested_swap_coldkey_freezes_swap() {
+    new_test_ext(1).execute_with(|| {
+        let old_coldkey = U256::from(1);
+        let owner_destination = U256::from(2);
+        let attacker_destination = U256::from(3);
+        let hotkey = U256::from(4);
+        let netuid = 1u16;
+
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey, old_coldkey, 0);
+        SubtensorModule::add_balance_to_coldkey_account(&old_coldkey, 1_000_000_000_000);
+
+        assert_ok!(SubtensorModule::schedule_swap_coldkey(
+            <<Test as Config>::RuntimeOrigin>::signed(old_coldkey),
+            attacker_destination
+        ));
+        let execution_block = System::block_number() + ColdkeySwapScheduleDuration::<Test>::get();
+
+        // A request to a different destination contests the swap.
+        assert_ok!(SubtensorModule::schedule_swap_coldkey(
+            <<Test as Config>::RuntimeOrigin>::signed(old_coldkey),
+            owner_destination
+        ));
+        System::assert_last_event(
+            Event::ColdkeySwapContested {
+                old_coldkey,
+                new_coldkey: owner_destination,
+            }
+            .into(),
+        );
+        assert_eq!(
+            ColdkeySwapDisputes::<Test>::get(old_coldkey),
+            Some(vec![attacker_destination, owner_destination])
+        );
+        assert!(ColdkeySwapScheduled::<Test>::contains_key(old_coldkey));
+        assert!(ScheduledColdkeySwaps::<Test>::get(old_coldkey).is_none());
+
+        // Repeating a recorded destination is rejected.
+        assert_noop!(
+            SubtensorModule::schedule_swap_coldkey(
+                <<Test as Config>::RuntimeOrigin>::signed(old_coldkey),
+                owner_destination
+            ),
+            Error::<Test>::SwapAlreadyScheduled
+        );
+
+        // The contested swap can no longer be cancelled by the coldkey.
+        assert_noop!(
+            SubtensorModule::cancel_scheduled_swap_coldkey(
+                <<Test as Config>::RuntimeOrigin>::signed(old_coldkey),
+                old_coldkey
+            ),
+            Error::<Test>::ColdkeyIsInArbitration
+        );
+
+        // A destination in arbitration cannot be used by another swap.
+        assert_noop!(
+            SubtensorModule::schedule_swap_coldkey(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(5)),
+                old_coldkey
+            ),
+            Error::<Test>::NewColdkeyIsInArbitration
+        );
+
+        // The frozen swap never executes.
+        run_to_block(execution_block + 1);
+        assert_eq!(Owner::<Test>::get(hotkey), old_coldkey);
+        assert!(ColdkeySwapDisputes::<Test>::contains_key(old_coldkey));
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=info cargo test --test swap_coldkey -- test_contested_swap_coldkey_max_destinations --exact --nocapture
+#[test]
+fn test_contested_swap_coldkey_max_destinations() {
+    new_test_ext(1).execute_with(|| {
+        let old_coldkey = U256::from(1);
+        SubtensorModule::add_balance_to_coldkey_account(&old_coldkey, 1_000_000_000_000);
+
+        for i in 0..MAX_COLDKEY_SWAP_DESTINATIONS {
+            assert_ok!(SubtensorModule::schedule_swap_coldkey(
+                <<Test as Config>::RuntimeOrigin>::signed(old_coldkey),
+                U256::from(100 + i)
+            ));
+        }
+        assert_eq!(
+            ColdkeySwapDisputes::<Test>::get(old_coldkey).map(|d| d.len()),
+            Some(MAX_COLDKEY_SWAP_DESTINATIONS)
+        );
+
+        assert_noop!(
+            SubtensorModule::schedule_swap_coldkey(
+                <<Test as Config>::RuntimeOrigin>::signed(old_coldkey),
+                U256::from(999)
+            ),
+            Error::<Test>::MaxColdkeyDestinationsReached
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=info cargo test --test swap_coldkey -- test_contested_swap_coldkey_burns_swap_cost --exact --nocapture
+#[test]
+fn test_contested_swap_coldkey_burns_swap_cost() {
+    new_test_ext(1).execute_with(|| {
+        let old_coldkey = U256::from(1);
+        let swap_cost = SubtensorModule::get_key_swap_cost();
+        SubtensorModule::add_balance_to_coldkey_account(&old_coldkey, swap_cost + 1_000);
+
+        // Scheduling the swap burns nothing yet.
+        assert_ok!(SubtensorModule::schedule_swap_coldkey(
+            <<Test as Config>::RuntimeOrigin>::signed(old_coldkey),
+            U256::from(2)
+        ));
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&old_coldkey),
+            swap_cost + 1_000
+        );
+
+        // Each contesting request burns the swap cost.
+        assert_ok!(SubtensorModule::schedule_swap_coldkey(
+            <<Test as Config>::RuntimeOrigin>::signed(old_coldkey),
+            U256::from(3)
+        ));
+        assert_eq!(SubtensorModule::get_coldkey_balance(&old_coldkey), 1_000);
+        assert_noop!(
+            SubtensorModule::schedule_swap_coldkey(
+                <<Test as Config>::RuntimeOrigin>::signed(old_coldkey),
+                U256::from(4)
+            ),
+            Error::<Test>::NotEnoughBalanceToPaySwapColdKey
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=info cargo test --test swap_coldkey -- test_contested_swap_coldkey_signed_extension --exact --nocapture
+#[test]
+fn test_contested_swap_coldkey_signed_extension() {
+    new_test_ext(1).execute_with(|| {
+        let old_coldkey = U256::from(1);
+        let hotkey = U256::from(4);
+        SubtensorModule::add_balance_to_coldkey_account(&old_coldkey, 1_000_000_000_000);
+        let info: DispatchInfo =
+            DispatchInfoOf::<<Test as frame_system::Config>::RuntimeCall>::default();
+        let extension = SubtensorSignedExtension::<Test>::new();
+        let add_stake: RuntimeCall = SubtensorCall::add_stake {
+            hotkey,
+            amount_staked: 1,
+        }
+        .into();
+        let remove_stake: RuntimeCall = SubtensorCall::remove_stake {
+            hotkey,
+            amount_unstaked: 1,
+        }
+        .into();
+        let transfer: RuntimeCall = BalanceCall::transfer_allow_death {
+            dest: U256::from(9),
+            value: 1,
+        }
+        .into();
+
+        // Staking is allowed while a swap is merely scheduled.
+        assert_ok!(SubtensorModule::schedule_swap_coldkey(
+            <<Test as Config>::RuntimeOrigin>::signed(old_coldkey),
+            U256::from(2)
+        ));
+        assert_ok!(extension.validate(&old_coldkey, &add_stake, &info, 10));
+
+        // Contesting locks staking, transfers stay locked.
+        assert_ok!(SubtensorModule::schedule_swap_coldkey(
+            <<Test as Config>::RuntimeOrigin>::signed(old_coldkey),
+            U256::from(3)
+        ));
+        assert_eq!(
+            extension.validate(&old_coldkey, &add_stake, &info, 10),
+            Err(TransactionValidityError::Invalid(
+                InvalidTransaction::Custom(CustomTransactionError::ColdkeyInArbitration.into())
+            ))
+        );
+        assert_eq!(
+            extension.validate(&old_coldkey, &remove_stake, &info, 10),
+            Err(TransactionValidityError::Invalid(
+                InvalidTransaction::Custom(CustomTransactionError::ColdkeyInArbitration.into())
+            ))
+        );
+        assert_eq!(
+            extension.validate(&old_coldkey, &transfer, &info, 10),
+            Err(TransactionValidityError::Invalid(
+                InvalidTransaction::Custom(CustomTransactionError::ColdkeyInSwapSchedule.into())
+            ))
+        );
+
+        // Rejecting the dispute releases the coldkey.
+        assert_ok!(SubtensorModule::resolve_coldkey_swap_dispute(
+            RawOrigin::Root.into(),
+            old_coldkey,
+            None
+        ));
+        assert_ok!(extension.validate(&old_coldkey, &add_stake, &info, 10));
+        assert_ok!(extension.validate(&old_coldkey, &transfer, &info, 10));
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=info cargo test --test swap_coldkey -- test_resolve_coldkey_swap_dispute --exact --nocapture
+#[test]
+fn test_resolve_coldkey_swap_dispute() {
+    new_test_ext(1).execute_with(|| {
+        let old_coldkey = U256::from(1);
+        let owner_destination = U256::from(2);
+        let attacker_destination = U256::from(3);
+        let hotkey = U256::from(4);
+        let netuid = 1u16;
+
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey, old_coldkey, 0);
+        SubtensorModule::add_balance_to_coldkey_account(&old_coldkey, 1_000_000_000_000);
+
+        // Nothing to resolve yet.
+        assert_noop!(
+            SubtensorModule::resolve_coldkey_swap_dispute(
+                RawOrigin::Root.into(),
+                old_coldkey,
+                Some(owner_destination)
+            ),
+            Error::<Test>::ColdkeyNotInArbitration
+        );
+
+        assert_ok!(SubtensorModule::schedule_swap_coldkey(
+            <<Test as Config>::RuntimeOrigin>::signed(old_coldkey),
+            attacker_destination
+        ));
+        assert_ok!(SubtensorModule::schedule_swap_coldkey(
+            <<Test as Config>::RuntimeOrigin>::signed(old_coldkey),
+            owner_destination
+        ));
+
+        // Only root or the council can resolve.
+        assert_noop!(
+            SubtensorModule::resolve_coldkey_swap_dispute(
+                RawOrigin::None.into(),
+                old_coldkey,
+                Some(owner_destination)
+            ),
+            BadOrigin
+        );
+
+        // Governance can only choose one of the contested destinations.
+        assert_noop!(
+            SubtensorModule::resolve_coldkey_swap_dispute(
+                RawOrigin::Root.into(),
+                old_coldkey,
+                Some(U256::from(5))
+            ),
+            Error::<Test>::NotContestedColdkeySwapDestination
+        );
+
+        assert_ok!(SubtensorModule::resolve_coldkey_swap_dispute(
+            RawOrigin::Root.into(),
+            old_coldkey,
+            Some(owner_destination)
+        ));
+        System::assert_has_event(
+            Event::ColdkeySwapped {
+                old_coldkey,
+                new_coldkey: owner_destination,
+            }
+            .into(),
+        );
+        System::assert_last_event(
+            Event::ColdkeySwapDisputeResolved {
+                old_coldkey,
+                new_coldkey: Some(owner_destination),
+            }
+            .into(),
+        );
+        assert_eq!(Owner::<Test>::get(hotkey), owner_destination);
+        assert!(!ColdkeySwapDisputes::<Test>::contains_key(old_coldkey));
+        assert!(!ColdkeySwapScheduled::<Test>::contains_key(old_coldkey));
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=info cargo test --test swap_coldkey -- test_resolve_coldkey_swap_dispute_reject_all --exact --nocapture
+#[test]
+fn test_resolve_coldkey_swap_dispute_reject_all() {
+    new_test_ext(1).execute_with(|| {
+        let old_coldkey = U256::from(1);
+        SubtensorModule::add_balance_to_coldkey_account(&old_coldkey, 1_000_000_000_000);
+
+        assert_ok!(SubtensorModule::schedule_swap_coldkey(
+            <<Test as Config>::RuntimeOrigin>::signed(old_coldkey),
+            U256::from(2)
+        ));
+        assert_ok!(SubtensorModule::schedule_swap_coldkey(
+            <<Test as Config>::RuntimeOrigin>::signed(old_coldkey),
+            U256::from(3)
+        ));
+
+        // The council origin can resolve as well (any signed origin in the mock).
+        assert_ok!(SubtensorModule::resolve_coldkey_swap_dispute(
+            <<Test as Config>::RuntimeOrigin>::signed(U256::from(7)),
+            old_coldkey,
+            None
+        ));
+        System::assert_last_event(
+            Event::ColdkeySwapDisputeResolved {
+                old_coldkey,
+                new_coldkey: None,
+            }
+            .into(),
+        );
+        assert!(!ColdkeySwapDisputes::<Test>::contains_key(old_coldkey));
+        assert!(!ColdkeySwapScheduled::<Test>::contains_key(old_coldkey));
+
+        // The coldkey can schedule a fresh swap afterwards.
+        assert_ok!(SubtensorModule::schedule_swap_coldkey(
+            <<Test as Config>::RuntimeOrigin>::signed(old_coldkey),
+            U256::from(2)
+        ));
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test swap_coldkey -- test_coldkey_swap_preview --exact --nocapture
+#[test]
+fn test_coldkey_swap_preview() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let old_coldkey = U256::from(1);
+        let new_coldkey = U256::from(2);
+        let hotkey = U256::from(3);
+        let other_hotkey = U256::from(4);
+        let swap_cost = SubtensorModule::get_key_swap_cost();
+
+        add_network(netuid, 13, 0);
+        SubnetOwner::<Test>::insert(netuid, old_coldkey);
+        register_ok_neuron(netuid, hotkey, old_coldkey, 0);
+        SubtensorModule::add_balance_to_coldkey_account(&old_coldkey, swap_cost + 1_000);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&old_coldkey, &hotkey, 2_000);
+
+        let preview =
+            SubtensorModule::get_coldkey_swap_preview(old_coldkey.encode(), new_coldkey.encode())
+                .unwrap();
+        assert_eq!(preview.error, None);
+        assert_eq!(preview.swap_cost, swap_cost.into());
+        assert_eq!(preview.stake_moved, 2_000.into());
+        assert_eq!(preview.balance_moved, 1_000.into());
+        assert_eq!(preview.stake_entries, 1.into());
+        assert_eq!(preview.owned_hotkeys, 1.into());
+        assert_eq!(preview.subnets_owned, 1.into());
+        assert!(!preview.has_identity);
+
+        // Nothing is swapped by the preview
+        assert_eq!(Owner::<Test>::get(hotkey), old_coldkey);
+        assert_eq!(SubnetOwner::<Test>::get(netuid), old_coldkey);
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&old_coldkey),
+            swap_cost + 1_000
+        );
+
+        // A new coldkey which already stakes blocks the swap
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&new_coldkey, &other_hotkey, 1);
+        let preview =
+            SubtensorModule::get_coldkey_swap_preview(old_coldkey.encode(), new_coldkey.encode())
+                .unwrap();
+        assert_eq!(preview.error, Some(b"ColdKeyAlreadyAssociated".to_vec()));
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test swap_coldkey -- test_swap_coldkey_continues_in_on_idle --exact --nocapture
+#[test]
+fn test_swap_coldkey_continues_in_on_idle() {
+    new_test_ext(1).execute_with(|| {
+        let old_coldkey = U256::from(1);
+        let new_coldkey = U256::from(2);
+        let hotkeys: Vec<U256> = (100..300).map(U256::from).collect();
+        let info: DispatchInfo =
+            DispatchInfoOf::<<Test as frame_system::Config>::RuntimeCall>::default();
+        let extension = SubtensorSignedExtension::<Test>::new();
+
+        SubtensorModule::add_balance_to_coldkey_account(
+            &old_coldkey,
+            SubtensorModule::get_key_swap_cost() + 1_000,
+        );
+        for hotkey in hotkeys.iter() {
+            SubtensorModule::increase_stake_on_coldkey_hotkey_account(&old_coldkey, hotkey, 1_000);
+        }
+
+        assert_ok!(SubtensorModule::do_swap_coldkey(&old_coldkey, &new_coldkey));
+
+        // The balance and total stake moved, the stake rows are left for on_idle
+        assert_eq!(SubtensorModule::get_coldkey_balance(&new_coldkey), 1_000);
+        assert_eq!(
+            SubtensorModule::get_total_stake_for_coldkey(&new_coldkey),
+            200_000
+        );
+        let swap = PendingKeySwaps::<Test>::get(old_coldkey).unwrap();
+        assert_eq!(swap.phase, KeySwapPhase::ColdkeyStake);
+        let rows_left = StakingHotkeys::<Test>::get(old_coldkey).len();
+        assert!(rows_left > 0);
+        assert_eq!(swap.entries_moved as usize, hotkeys.len() - rows_left);
+        assert_eq!(
+            StakingHotkeys::<Test>::get(new_coldkey).len(),
+            hotkeys.len() - rows_left
+        );
+
+        // Staking from either coldkey is rejected until the swap completes
+        let add_stake: RuntimeCall = SubtensorCall::add_stake {
+            hotkey: hotkeys[0],
+            amount_staked: 1,
+        }
+        .into();
+        assert_eq!(
+            extension.validate(&new_coldkey, &add_stake, &info, 10),
+            Err(TransactionValidityError::Invalid(
+                InvalidTransaction::Custom(CustomTransactionError::KeySwapInProgress.into())
+            ))
+        );
+        assert_noop!(
+            SubtensorModule::remove_stake(
+                <<Test as Config>::RuntimeOrigin>::signed(new_coldkey),
+                hotkeys[0],
+                1
+            ),
+            Error::<Test>::KeySwapInProgress
+        );
+
+        // Emission compounded for the old coldkey while its rows are left follows them
+        let last_hotkey = *hotkeys.last().unwrap();
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&old_coldkey, &last_hotkey, 500);
+
+        // Blocks without weight left for on_idle still complete the swap in on_initialize
+        let mut block = System::block_number();
+        while PendingKeySwaps::<Test>::contains_key(old_coldkey) {
+            assert!(block < 100);
+            block += 1;
+            SubtensorModule::on_initialize(block);
+        }
+
+        assert!(!PendingKeySwaps::<Test>::contains_key(old_coldkey));
+        assert!(!SubtensorModule::is_key_swapping(&new_coldkey));
+        assert!(!StakingHotkeys::<Test>::contains_key(old_coldkey));
+        assert_eq!(
+            StakingHotkeys::<Test>::get(new_coldkey).len(),
+            hotkeys.len()
+        );
+        for hotkey in hotkeys.iter() {
+            let compounded = if *hotkey == last_hotkey { 500 } else { 0 };
+            assert_eq!(Stake::<Test>::get(hotkey, new_coldkey), 1_000 + compounded);
+            assert!(!Stake::<Test>::contains_key(hotkey, old_coldkey));
+        }
+        assert_eq!(
+            SubtensorModule::get_total_stake_for_coldkey(&old_coldkey),
+            0
+        );
+        assert_eq!(
+            SubtensorModule::get_total_stake_for_coldkey(&new_coldkey),
+            200_500
+        );
+        assert_ok!(extension.validate(&new_coldkey, &add_stake, &info, 10));
+        System::assert_has_event(
+            Event::KeySwapCompleted {
+                old_key: old_coldkey,
+                new_key: new_coldkey,
+                entries_moved: hotkeys.len() as u32,
+            }
+            .into(),
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test swap_coldkey -- test_coldkey_recovery_by_guardians --exact --nocapture
+#[test]
+fn test_coldkey_recovery_by_guardians() {
+    new_test_ext(1).execute_with(|| {
+        let old_coldkey = U256::from(1);
+        let new_coldkey = U256::from(2);
+        let hotkey = U256::from(3);
+        let guardians = vec![U256::from(10), U256::from(11), U256::from(12)];
+        let signed = <<Test as Config>::RuntimeOrigin>::signed;
+
+        SubtensorModule::add_balance_to_coldkey_account(
+            &old_coldkey,
+            SubtensorModule::get_key_swap_cost(),
+        );
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&old_coldkey, &hotkey, 1_000);
+
+        // The threshold must be reachable and the coldkey cannot guard itself
+        assert_noop!(
+            SubtensorModule::set_recovery_guardians(signed(old_coldkey), guardians.clone(), 4),
+            Error::<Test>::InvalidRecoveryGuardians
+        );
+        assert_noop!(
+            SubtensorModule::set_recovery_guardians(
+                signed(old_coldkey),
+                vec![guardians[0], old_coldkey],
+                1
+            ),
+            Error::<Test>::InvalidRecoveryGuardians
+        );
+        assert_ok!(SubtensorModule::set_recovery_guardians(
+            signed(old_coldkey),
+            guardians.clone(),
+            2
+        ));
+
+        // Only guardians vouch, each once per destination
+        assert_noop!(
+            SubtensorModule::vouch_coldkey_recovery(
+                signed(U256::from(13)),
+                old_coldkey,
+                new_coldkey
+            ),
+            Error::<Test>::NotRecoveryGuardian
+        );
+        assert_ok!(SubtensorModule::vouch_coldkey_recovery(
+            signed(guardians[0]),
+            old_coldkey,
+            U256::from(4)
+        ));
+        assert_noop!(
+            SubtensorModule::vouch_coldkey_recovery(
+                signed(guardians[0]),
+                old_coldkey,
+                U256::from(4)
+            ),
+            Error::<Test>::ColdkeyRecoveryAlreadyVouched
+        );
+
+        // A vouch for another destination does not block the recovery, and can be moved
+        assert_ok!(SubtensorModule::vouch_coldkey_recovery(
+            signed(guardians[1]),
+            old_coldkey,
+            new_coldkey
+        ));
+        System::assert_last_event(
+            Event::ColdkeyRecoveryVouched {
+                coldkey: old_coldkey,
+                guardian: guardians[1],
+                new_coldkey,
+                vouches: 1,
+            }
+            .into(),
+        );
+        assert_eq!(
+            ColdkeyRecoveries::<Test>::get(old_coldkey).and_then(|r| r.scheduled),
+            None
+        );
+
+        // Reaching the threshold for one destination schedules the swap, which the coldkey can veto
+        assert_ok!(SubtensorModule::vouch_coldkey_recovery(
+            signed(guardians[0]),
+            old_coldkey,
+            new_coldkey
+        ));
+        let execution_block = System::block_number() + ColdkeySwapScheduleDuration::<Test>::get();
+        System::assert_last_event(
+            Event::ColdkeyRecoveryScheduled {
+                coldkey: old_coldkey,
+                new_coldkey,
+                execution_block,
+            }
+            .into(),
+        );
+        assert_noop!(
+            SubtensorModule::set_recovery_guardians(signed(old_coldkey), vec![], 0),
+            Error::<Test>::ColdkeyRecoveryInProgress
+        );
+        assert_ok!(SubtensorModule::veto_coldkey_recovery(signed(old_coldkey)));
+        System::assert_last_event(
+            Event::ColdkeyRecoveryVetoed {
+                coldkey: old_coldkey,
+                new_coldkey: Some(new_coldkey),
+            }
+            .into(),
+        );
+        assert!(!ColdkeyRecoveries::<Test>::contains_key(old_coldkey));
+        run_to_block(execution_block);
+        assert_eq!(Stake::<Test>::get(hotkey, old_coldkey), 1_000);
+
+        // A recovery which is not vetoed swaps the coldkey, and the guardians follow it
+        assert_ok!(SubtensorModule::vouch_coldkey_recovery(
+            signed(guardians[1]),
+            old_coldkey,
+            new_coldkey
+        ));
+        assert_ok!(SubtensorModule::vouch_coldkey_recovery(
+            signed(guardians[2]),
+            old_coldkey,
+            new_coldkey
+        ));
+        let execution_block = System::block_number() + ColdkeySwapScheduleDuration::<Test>::get();
+        run_to_block(execution_block);
+
+        assert_eq!(Stake::<Test>::get(hotkey, new_coldkey), 1_000);
+        assert_eq!(Stake::<Test>::get(hotkey, old_coldkey), 0);
+        assert!(!ColdkeyRecoveries::<Test>::contains_key(old_coldkey));
+        assert!(!ColdkeyRecoveryGuardians::<Test>::contains_key(old_coldkey));
+        assert_eq!(
+            ColdkeyRecoveryGuardians::<Test>::get(new_coldkey).map(|g| g.guardians),
+            Some(guardians)
+        );
+        System::assert_has_event(
+            Event::ColdkeyRecovered {
+                old_coldkey,
+                new_coldkey,
+            }
+            .into(),
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test swap_coldkey -- test_coldkey_recovery_and_swap_in_arbitration --exact --nocapture
+#[test]
+fn test_coldkey_recovery_and_swap_in_arbitration() {
+    new_test_ext(1).execute_with(|| {
+        let old_coldkey = U256::from(1);
+        let new_coldkey = U256::from(2);
+        let other_coldkey = U256::from(3);
+        let guardians = vec![U256::from(10), U256::from(11)];
+        let signed = <<Test as Config>::RuntimeOrigin>::signed;
+
+        SubtensorModule::add_balance_to_coldkey_account(
+            &old_coldkey,
+            SubtensorModule::get_key_swap_cost() * 3,
+        );
+        assert_ok!(SubtensorModule::set_recovery_guardians(
+            signed(old_coldkey),
+            guardians.clone(),
+            1
+        ));
+
+        // A direct swap cancels the task of the swap it replaces
+        assert_ok!(SubtensorModule::schedule_swap_coldkey(
+            signed(old_coldkey),
+            new_coldkey
+        ));
+        let task_name = SubtensorModule::coldkey_swap_task_name(&old_coldkey);
+        assert!(pallet_scheduler::Lookup::<Test>::contains_key(task_name));
+        assert_ok!(SubtensorModule::swap_coldkey(
+            RawOrigin::Root.into(),
+            old_coldkey,
+            new_coldkey
+        ));
+        assert!(!pallet_scheduler::Lookup::<Test>::contains_key(task_name));
+        assert!(ScheduledColdkeySwaps::<Test>::get(old_coldkey).is_none());
+
+        // Guardians cannot recover a coldkey in arbitration
+        let (old_coldkey, new_coldkey) = (new_coldkey, U256::from(4));
+        SubtensorModule::add_balance_to_coldkey_account(
+            &old_coldkey,
+            SubtensorModule::get_key_swap_cost() * 3,
+        );
+        assert_ok!(SubtensorModule::schedule_swap_coldkey(
+            signed(old_coldkey),
+            new_coldkey
+        ));
+        assert_ok!(SubtensorModule::schedule_swap_coldkey(
+            signed(old_coldkey),
+            other_coldkey
+        ));
+        assert!(ColdkeySwapDisputes::<Test>::contains_key(old_coldkey));
+        assert_noop!(
+            SubtensorModule::vouch_coldkey_recovery(signed(guardians[0]), old_coldkey, new_coldkey),
+            Error::<Test>::ColdkeyIsInArbitration
+        );
+
+        // A recovery scheduled before the dispute does not run while it is in arbitration
+        ColdkeyRecoveries::<Test>::insert(
+            old_coldkey,
+            ColdkeyRecovery {
+                vouches: vec![(guardians[0], new_coldkey)],
+                scheduled: Some((new_coldkey, System::block_number())),
+            },
+        );
+        assert_noop!(
+            SubtensorModule::recover_coldkey(RawOrigin::Root.into(), old_coldkey, new_coldkey),
+            Error::<Test>::ColdkeyIsInArbitration
+        );
+
+        // A direct swap ends the arbitration
+        assert_ok!(SubtensorModule::swap_coldkey(
+            RawOrigin::Root.into(),
+            old_coldkey,
+            new_coldkey
+        ));
+        assert!(!ColdkeySwapDisputes::<Test>::contains_key(old_coldkey));
+        assert!(!ColdkeySwapScheduled::<Test>::contains_key(old_coldkey));
+        assert!(!ColdkeyRecoveries::<Test>::contains_key(old_coldkey));
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test swap_coldkey -- test_coldkey_recovery_signed_extension --exact --nocapture
+#[test]
+fn test_coldkey_recovery_signed_extension() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let info: DispatchInfo =
+            DispatchInfoOf::<<Test as frame_system::Config>::RuntimeCall>::default();
+        let extension = SubtensorSignedExtension::<Test>::new();
+        let set_recovery_guardians: RuntimeCall = SubtensorCall::set_recovery_guardians {
+            guardians: vec![U256::from(2)],
+            threshold: 1,
+        }
+        .into();
+        let veto_coldkey_recovery: RuntimeCall = SubtensorCall::veto_coldkey_recovery {}.into();
+        let calls = [set_recovery_guardians, veto_coldkey_recovery];
+
+        for call in calls.iter() {
+            assert_ok!(extension.validate(&coldkey, call, &info, 10));
+        }
+
+        // The recovery cannot be changed while the coldkey is being swapped
+        ColdkeySwapScheduled::<Test>::insert(coldkey, ());
+        for call in calls.iter() {
+            assert_eq!(
+                extension.validate(&coldkey, call, &info, 10),
+                Err(TransactionValidityError::Invalid(
+                    InvalidTransaction::Custom(
+                        CustomTransactionError::ColdkeyInSwapSchedule.into()
+                    )
+                ))
+            );
+        }
+        ColdkeySwapScheduled::<Test>::remove(coldkey);
+
+        ColdkeySwapDisputes::<Test>::insert(coldkey, vec![U256::from(3)]);
+        for call in calls.iter() {
+            assert_eq!(
+                extension.validate(&coldkey, call, &info, 10),
+                Err(TransactionValidityError::Invalid(
+                    InvalidTransaction::Custom(CustomTransactionError::ColdkeyInArbitration.into())
+                ))
+            );
+        }
+    });
+}
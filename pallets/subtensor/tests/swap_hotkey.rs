@@ -1,14 +1,15 @@
 #![allow(unused, clippy::indexing_slicing, clippy::panic, clippy::unwrap_used)]
 
 use codec::Encode;
+use frame_support::traits::OnIdle;
 use frame_support::weights::Weight;
 use frame_support::{assert_err, assert_noop, assert_ok};
 use frame_system::{Config, RawOrigin};
 mod mock;
 use mock::*;
 use pallet_subtensor::*;
-use sp_core::H256;
 use sp_core::U256;
+use sp_core::{Get, H256};
 
 // SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test swap_hotkey -- test_swap_owner --exact --nocapture
 #[test]
@@ -984,6 +985,97 @@ fn test_swap_child_keys() {
     });
 }
 
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test swap_hotkey -- test_swap_child_keys_updates_parent_keys_of_children --exact --nocapture
+#[test]
+fn test_swap_child_keys_updates_parent_keys_of_children() {
+    new_test_ext(1).execute_with(|| {
+        let old_hotkey = U256::from(1);
+        let new_hotkey = U256::from(2);
+        let coldkey = U256::from(3);
+        let child1 = U256::from(4);
+        let child2 = U256::from(5);
+        let other_parent = U256::from(6);
+        let netuid = 0u16;
+        let mut weight = Weight::zero();
+
+        add_network(netuid, 1, 0);
+        ChildKeys::<Test>::insert(old_hotkey, netuid, vec![(100u64, child1), (200u64, child2)]);
+        ParentKeys::<Test>::insert(child1, netuid, vec![(100u64, old_hotkey)]);
+        ParentKeys::<Test>::insert(
+            child2,
+            netuid,
+            vec![(300u64, other_parent), (200u64, old_hotkey)],
+        );
+
+        // Perform the swap
+        SubtensorModule::perform_hotkey_swap(&old_hotkey, &new_hotkey, &coldkey, &mut weight);
+
+        // The children name the new hotkey as their parent
+        assert_eq!(
+            ParentKeys::<Test>::get(child1, netuid),
+            vec![(100u64, new_hotkey)]
+        );
+        assert_eq!(
+            ParentKeys::<Test>::get(child2, netuid),
+            vec![(300u64, other_parent), (200u64, new_hotkey)]
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test swap_hotkey -- test_swap_hotkey_in_childkey_proposals --exact --nocapture
+#[test]
+fn test_swap_hotkey_in_childkey_proposals() {
+    new_test_ext(1).execute_with(|| {
+        let old_hotkey = U256::from(1);
+        let new_hotkey = U256::from(2);
+        let coldkey = U256::from(3);
+        let parent = U256::from(4);
+        let coldkey_parent = U256::from(5);
+        let netuid = 1u16;
+        let mut weight = Weight::zero();
+
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, parent, coldkey_parent, 0);
+        register_ok_neuron(netuid, old_hotkey, coldkey, 1);
+
+        // The parent proposes the old hotkey as its child
+        assert_ok!(SubtensorModule::do_set_children(
+            RuntimeOrigin::signed(coldkey_parent),
+            parent,
+            netuid,
+            vec![(1000u64, old_hotkey)]
+        ));
+
+        // Perform the swap
+        SubtensorModule::perform_hotkey_swap(&old_hotkey, &new_hotkey, &coldkey, &mut weight);
+
+        // The proposal names the new hotkey instead
+        let proposal = SubtensorModule::get_pending_children(&parent, netuid).unwrap();
+        assert_eq!(proposal.children, vec![(1000u64, new_hotkey)]);
+        assert!(SubtensorModule::get_pending_parents(&old_hotkey).is_empty());
+        assert_eq!(
+            SubtensorModule::get_pending_parents(&new_hotkey),
+            vec![(parent, netuid, proposal)]
+        );
+
+        // The coldkey accepts as the owner of the new hotkey
+        assert_ok!(SubtensorModule::do_accept_child(
+            RuntimeOrigin::signed(coldkey),
+            parent,
+            new_hotkey,
+            netuid
+        ));
+        assert_eq!(
+            ChildKeys::<Test>::get(parent, netuid),
+            vec![(1000u64, new_hotkey)]
+        );
+        assert_eq!(
+            ParentKeys::<Test>::get(new_hotkey, netuid),
+            vec![(1000u64, parent)]
+        );
+    });
+}
+
 // SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test swap_hotkey -- test_swap_parent_keys --exact --nocapture
 #[test]
 fn test_swap_parent_keys() {
@@ -1115,3 +1207,326 @@ fn test_swap_complex_parent_child_structure() {
         );
     });
 }
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test swap_hotkey -- test_hotkey_swap_preview --exact --nocapture
+#[test]
+fn test_hotkey_swap_preview() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let old_hotkey = U256::from(1);
+        let new_hotkey = U256::from(2);
+        let coldkey = U256::from(3);
+        let staker = U256::from(4);
+        let swap_cost = SubtensorModule::get_key_swap_cost();
+
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, old_hotkey, coldkey, 0);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &old_hotkey, 1_000);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&staker, &old_hotkey, 500);
+
+        // Without the balance for the swap cost, the swap is blocked
+        let preview = SubtensorModule::get_hotkey_swap_preview(
+            coldkey.encode(),
+            old_hotkey.encode(),
+            new_hotkey.encode(),
+        )
+        .unwrap();
+        assert_eq!(
+            preview.error,
+            Some(b"NotEnoughBalanceToPaySwapHotKey".to_vec())
+        );
+        assert_eq!(preview.swap_cost, swap_cost.into());
+        assert_eq!(preview.stake_moved, 1_500.into());
+        assert_eq!(preview.stake_entries, 2.into());
+        assert_eq!(preview.subnets, 1.into());
+
+        // With the balance the swap would succeed, but nothing is swapped
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, swap_cost);
+        let preview = SubtensorModule::get_hotkey_swap_preview(
+            coldkey.encode(),
+            old_hotkey.encode(),
+            new_hotkey.encode(),
+        )
+        .unwrap();
+        assert_eq!(preview.error, None);
+        assert_eq!(Owner::<Test>::get(old_hotkey), coldkey);
+        assert!(!Owner::<Test>::contains_key(new_hotkey));
+        assert_eq!(SubtensorModule::get_coldkey_balance(&coldkey), swap_cost);
+
+        // A new hotkey which is registered blocks the swap
+        register_ok_neuron(netuid, new_hotkey, coldkey, 1);
+        let preview = SubtensorModule::get_hotkey_swap_preview(
+            coldkey.encode(),
+            old_hotkey.encode(),
+            new_hotkey.encode(),
+        )
+        .unwrap();
+        assert_eq!(
+            preview.error,
+            Some(b"HotKeyAlreadyRegisteredInSubNet".to_vec())
+        );
+
+        assert!(SubtensorModule::get_hotkey_swap_preview(
+            vec![0; 31],
+            old_hotkey.encode(),
+            new_hotkey.encode()
+        )
+        .is_none());
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test swap_hotkey -- test_swap_hotkey_on_subnet --exact --nocapture
+#[test]
+fn test_swap_hotkey_on_subnet() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let other_netuid: u16 = 2;
+        let old_hotkey = U256::from(1);
+        let new_hotkey = U256::from(2);
+        let coldkey = U256::from(3);
+        let child = U256::from(4);
+        let swap_cost = SubtensorModule::get_key_swap_cost();
+
+        add_network(netuid, 13, 0);
+        add_network(other_netuid, 13, 0);
+        register_ok_neuron(netuid, old_hotkey, coldkey, 0);
+        register_ok_neuron(other_netuid, old_hotkey, coldkey, 0);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, swap_cost * 2);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &old_hotkey, 1_000);
+        Axons::<Test>::insert(netuid, old_hotkey, AxonInfoOf::default());
+        Axons::<Test>::insert(other_netuid, old_hotkey, AxonInfoOf::default());
+        ChildKeys::<Test>::insert(old_hotkey, netuid, vec![(u64::MAX, child)]);
+        ParentKeys::<Test>::insert(child, netuid, vec![(u64::MAX, old_hotkey)]);
+        let uid = SubtensorModule::get_uid_for_net_and_hotkey(netuid, &old_hotkey).unwrap();
+
+        assert_noop!(
+            SubtensorModule::do_swap_hotkey_on_subnet(
+                RuntimeOrigin::signed(coldkey),
+                &old_hotkey,
+                &new_hotkey,
+                3,
+                false
+            ),
+            Error::<Test>::SubNetworkDoesNotExist
+        );
+        assert_noop!(
+            SubtensorModule::do_swap_hotkey_on_subnet(
+                RuntimeOrigin::signed(U256::from(5)),
+                &old_hotkey,
+                &new_hotkey,
+                netuid,
+                false
+            ),
+            Error::<Test>::NonAssociatedColdKey
+        );
+
+        // The new hotkey may not bring childkeys of its own on the subnet, nor stake to merge
+        let parented_hotkey = U256::from(6);
+        ParentKeys::<Test>::insert(parented_hotkey, netuid, vec![(u64::MAX, U256::from(7))]);
+        assert_noop!(
+            SubtensorModule::do_swap_hotkey_on_subnet(
+                RuntimeOrigin::signed(coldkey),
+                &old_hotkey,
+                &parented_hotkey,
+                netuid,
+                false
+            ),
+            Error::<Test>::NewHotKeyHasChildkeys
+        );
+        let staked_hotkey = U256::from(8);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(
+            &U256::from(9),
+            &staked_hotkey,
+            1,
+        );
+        assert_noop!(
+            SubtensorModule::do_swap_hotkey_on_subnet(
+                RuntimeOrigin::signed(coldkey),
+                &old_hotkey,
+                &staked_hotkey,
+                netuid,
+                true
+            ),
+            Error::<Test>::NewHotKeyHasStake
+        );
+
+        assert_ok!(SubtensorModule::do_swap_hotkey_on_subnet(
+            RuntimeOrigin::signed(coldkey),
+            &old_hotkey,
+            &new_hotkey,
+            netuid,
+            false
+        ));
+
+        // The registration on the subnet moved
+        assert_eq!(Uids::<Test>::get(netuid, new_hotkey), Some(uid));
+        assert!(!Uids::<Test>::contains_key(netuid, old_hotkey));
+        assert_eq!(Keys::<Test>::get(netuid, uid), new_hotkey);
+        assert!(Axons::<Test>::contains_key(netuid, new_hotkey));
+        assert!(!Axons::<Test>::contains_key(netuid, old_hotkey));
+        assert_eq!(
+            ChildKeys::<Test>::get(new_hotkey, netuid),
+            vec![(u64::MAX, child)]
+        );
+        assert_eq!(
+            ParentKeys::<Test>::get(child, netuid),
+            vec![(u64::MAX, new_hotkey)]
+        );
+
+        // Other subnets and the stake stay on the old hotkey, both are owned by the coldkey
+        assert!(IsNetworkMember::<Test>::get(old_hotkey, other_netuid));
+        assert!(Axons::<Test>::contains_key(other_netuid, old_hotkey));
+        assert_eq!(
+            SubtensorModule::get_total_stake_for_hotkey(&old_hotkey),
+            1_000
+        );
+        assert_eq!(SubtensorModule::get_total_stake_for_hotkey(&new_hotkey), 0);
+        assert!(SubtensorModule::coldkey_owns_hotkey(&coldkey, &old_hotkey));
+        assert!(SubtensorModule::coldkey_owns_hotkey(&coldkey, &new_hotkey));
+        System::assert_last_event(
+            Event::HotkeySwappedOnSubnet {
+                coldkey,
+                old_hotkey,
+                new_hotkey,
+                netuid,
+                move_stake: false,
+            }
+            .into(),
+        );
+
+        // The new hotkey can not take the place of the old one twice
+        assert_noop!(
+            SubtensorModule::do_swap_hotkey_on_subnet(
+                RuntimeOrigin::signed(coldkey),
+                &old_hotkey,
+                &new_hotkey,
+                netuid,
+                false
+            ),
+            Error::<Test>::HotKeyNotRegisteredInSubNet
+        );
+
+        // Swapping the other subnet with the stake moves the stake too
+        assert_ok!(SubtensorModule::do_swap_hotkey_on_subnet(
+            RuntimeOrigin::signed(coldkey),
+            &old_hotkey,
+            &new_hotkey,
+            other_netuid,
+            true
+        ));
+        assert!(IsNetworkMember::<Test>::get(new_hotkey, other_netuid));
+        assert_eq!(SubtensorModule::get_total_stake_for_hotkey(&old_hotkey), 0);
+        assert_eq!(
+            SubtensorModule::get_total_stake_for_hotkey(&new_hotkey),
+            1_000
+        );
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&coldkey, &new_hotkey),
+            1_000
+        );
+        assert_eq!(SubtensorModule::get_coldkey_balance(&coldkey), 0);
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test swap_hotkey -- test_swap_hotkey_continues_in_on_idle --exact --nocapture
+#[test]
+fn test_swap_hotkey_continues_in_on_idle() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let old_hotkey = U256::from(1);
+        let new_hotkey = U256::from(2);
+        let coldkey = U256::from(3);
+        let nominators: Vec<U256> = (100..300).map(U256::from).collect();
+
+        add_network(netuid, 13, 0);
+        // Every subnet is an entry of the swap, then every stake row
+        let entries = SubtensorModule::get_all_subnet_netuids().len() + nominators.len();
+        register_ok_neuron(netuid, old_hotkey, coldkey, 0);
+        SubtensorModule::add_balance_to_coldkey_account(
+            &coldkey,
+            SubtensorModule::get_key_swap_cost(),
+        );
+        for nominator in nominators.iter() {
+            SubtensorModule::increase_stake_on_coldkey_hotkey_account(
+                nominator,
+                &old_hotkey,
+                1_000,
+            );
+        }
+
+        assert_ok!(SubtensorModule::do_swap_hotkey(
+            RuntimeOrigin::signed(coldkey),
+            &old_hotkey,
+            &new_hotkey
+        ));
+
+        // The registration and total stake moved, the stake rows are left for on_idle
+        assert_eq!(
+            SubtensorModule::get_hotkey_for_net_and_uid(netuid, 0),
+            Ok(new_hotkey)
+        );
+        assert_eq!(
+            SubtensorModule::get_total_stake_for_hotkey(&new_hotkey),
+            200_000
+        );
+        let swap = PendingKeySwaps::<Test>::get(old_hotkey).unwrap();
+        assert_eq!(swap.phase, KeySwapPhase::HotkeyStake);
+        let rows_left = Stake::<Test>::iter_prefix(old_hotkey).count();
+        assert!(rows_left > 0);
+        assert_eq!(swap.entries_moved as usize, entries - rows_left);
+
+        // Both hotkeys are locked until the swap completes
+        assert!(SubtensorModule::is_key_swapping(&old_hotkey));
+        assert!(SubtensorModule::is_key_swapping(&new_hotkey));
+        assert_noop!(
+            SubtensorModule::add_stake(RuntimeOrigin::signed(nominators[0]), new_hotkey, 1),
+            Error::<Test>::KeySwapInProgress
+        );
+        assert_noop!(
+            SubtensorModule::do_swap_hotkey(
+                RuntimeOrigin::signed(coldkey),
+                &new_hotkey,
+                &U256::from(4)
+            ),
+            Error::<Test>::KeySwapInProgress
+        );
+
+        // A block with little weight left moves some of the rows
+        let row_weight = <Test as Config>::DbWeight::get().reads_writes(4, 5);
+        SubtensorModule::on_idle(System::block_number(), row_weight.saturating_mul(20));
+        let swap = PendingKeySwaps::<Test>::get(old_hotkey).unwrap();
+        assert!(Stake::<Test>::iter_prefix(old_hotkey).count() < rows_left);
+        System::assert_last_event(
+            Event::KeySwapProgressed {
+                old_key: old_hotkey,
+                new_key: new_hotkey,
+                entries_moved: swap.entries_moved,
+            }
+            .into(),
+        );
+
+        // Emission given to the old hotkey while its drains are skipped follows the swap
+        PendingdHotkeyEmission::<Test>::insert(old_hotkey, 300);
+
+        // The rest moves once there is enough weight
+        SubtensorModule::on_idle(System::block_number(), Weight::MAX);
+        assert!(!PendingdHotkeyEmission::<Test>::contains_key(old_hotkey));
+        assert_eq!(PendingdHotkeyEmission::<Test>::get(new_hotkey), 300);
+        assert!(!PendingKeySwaps::<Test>::contains_key(old_hotkey));
+        assert!(!SubtensorModule::is_key_swapping(&old_hotkey));
+        assert!(!SubtensorModule::is_key_swapping(&new_hotkey));
+        assert_eq!(Stake::<Test>::iter_prefix(old_hotkey).count(), 0);
+        for nominator in nominators.iter() {
+            assert_eq!(Stake::<Test>::get(new_hotkey, nominator), 1_000);
+            assert_eq!(StakingHotkeys::<Test>::get(nominator), vec![new_hotkey]);
+        }
+        System::assert_last_event(
+            Event::KeySwapCompleted {
+                old_key: old_hotkey,
+                new_key: new_hotkey,
+                entries_moved: entries as u32,
+            }
+            .into(),
+        );
+    });
+}
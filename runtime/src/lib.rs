@@ -142,7 +142,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
     //   `spec_version`, and `authoring_version` are the same between Wasm and native.
     // This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
     //   the compatible custom types.
-    spec_version: 195,
+    spec_version: 196,
     impl_version: 1,
     apis: RUNTIME_API_VERSIONS,
     transaction_version: 1,
@@ -431,7 +431,7 @@ parameter_types! {
     pub const SenateMaxMembers: u32 = 12;
 }
 
-use pallet_collective::{CanPropose, CanVote, GetVotingMembers};
+use pallet_collective::{CanPropose, CanVote, GetVotingMembers, GetVotingWeights};
 pub struct CanProposeToTriumvirate;
 impl CanPropose<AccountId> for CanProposeToTriumvirate {
     fn can_propose(account: &AccountId) -> bool {
@@ -493,6 +493,24 @@ impl Get<MemberCount> for GetSenateMemberCount {
     }
 }
 
+// Senate votes count in proportion to the total stake of the member's hotkey, once enabled.
+pub struct SenateStakeWeights;
+impl GetVotingWeights<AccountId> for SenateStakeWeights {
+    fn is_weighted() -> bool {
+        SubtensorModule::get_senate_stake_weighted_voting()
+    }
+
+    fn voting_weights() -> Vec<(AccountId, u64)> {
+        SenateMembers::members()
+            .into_iter()
+            .map(|hotkey| {
+                let stake = SubtensorModule::get_total_stake_for_hotkey(&hotkey);
+                (hotkey, stake)
+            })
+            .collect()
+    }
+}
+
 pub struct TriumvirateVotes;
 impl CollectiveInterface<AccountId, Hash, u32> for TriumvirateVotes {
     fn remove_votes(hotkey: &AccountId) -> Result<bool, sp_runtime::DispatchError> {
@@ -527,6 +545,8 @@ impl pallet_collective::Config<TriumvirateCollective> for Runtime {
     type CanPropose = CanProposeToTriumvirate;
     type CanVote = CanVoteToTriumvirate;
     type GetVotingMembers = GetSenateMemberCount;
+    type VotingWeights = SenateStakeWeights;
+    type WeightedThreshold = pallet_collective::MoreThanHalfWeight;
 }
 
 // We call council members Triumvirate
@@ -629,6 +649,7 @@ pub enum ProxyType {
     Registration,
     Transfer,
     SmallTransfer,
+    StakeTransfer, // Only transferring stake to another coldkey
 }
 // Transfers below SMALL_TRANSFER_LIMIT are considered small transfers
 pub const SMALL_TRANSFER_LIMIT: Balance = 500_000_000; // 0.5 TAO
@@ -641,12 +662,36 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
     fn filter(&self, c: &RuntimeCall) -> bool {
         match self {
             ProxyType::Any => true,
-            ProxyType::NonTransfer => !matches!(c, RuntimeCall::Balances(..)),
+            ProxyType::NonTransfer => !matches!(
+                c,
+                RuntimeCall::Balances(..)
+                    | RuntimeCall::SubtensorModule(pallet_subtensor::Call::transfer_stake { .. })
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::set_reward_payout { .. }
+                    )
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::set_recovery_guardians { .. }
+                    )
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::veto_coldkey_recovery { .. }
+                    )
+            ),
             ProxyType::NonFungibile => !matches!(
                 c,
                 RuntimeCall::Balances(..)
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::add_stake { .. })
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::remove_stake { .. })
+                    | RuntimeCall::SubtensorModule(pallet_subtensor::Call::move_stake { .. })
+                    | RuntimeCall::SubtensorModule(pallet_subtensor::Call::transfer_stake { .. })
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::set_reward_payout { .. }
+                    )
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::set_recovery_guardians { .. }
+                    )
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::veto_coldkey_recovery { .. }
+                    )
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::burned_register { .. })
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::root_register { .. })
             ),
@@ -670,6 +715,9 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
             ProxyType::NonCritical => !matches!(
                 c,
                 RuntimeCall::SubtensorModule(pallet_subtensor::Call::dissolve_network { .. })
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::propose_subnet_owner_transfer { .. }
+                    )
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::root_register { .. })
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::burned_register { .. })
                     | RuntimeCall::Triumvirate(..)
@@ -689,6 +737,11 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
                 c,
                 RuntimeCall::SubtensorModule(pallet_subtensor::Call::add_stake { .. })
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::remove_stake { .. })
+                    | RuntimeCall::SubtensorModule(pallet_subtensor::Call::move_stake { .. })
+            ),
+            ProxyType::StakeTransfer => matches!(
+                c,
+                RuntimeCall::SubtensorModule(pallet_subtensor::Call::transfer_stake { .. })
             ),
             ProxyType::Registration => matches!(
                 c,
@@ -703,8 +756,11 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
             (ProxyType::Any, _) => true,
             (_, ProxyType::Any) => false,
             (ProxyType::NonTransfer, _) => {
-                // NonTransfer is NOT a superset of Transfer or SmallTransfer
-                !matches!(o, ProxyType::Transfer | ProxyType::SmallTransfer)
+                // NonTransfer is NOT a superset of Transfer, SmallTransfer or StakeTransfer
+                !matches!(
+                    o,
+                    ProxyType::Transfer | ProxyType::SmallTransfer | ProxyType::StakeTransfer
+                )
             }
             (ProxyType::Governance, ProxyType::Triumvirate | ProxyType::Senate) => true,
             (ProxyType::Transfer, ProxyType::SmallTransfer) => true,
@@ -940,6 +996,9 @@ parameter_types! {
     pub const SubtensorInitialNetworkMaxStake: u64 = u64::MAX; // Maximum possible value for u64, this make the make stake infinity
     pub const  InitialColdkeySwapScheduleDuration: BlockNumber = 5 * 24 * 60 * 60 / 12; // 5 days
     pub const  InitialDissolveNetworkScheduleDuration: BlockNumber = 5 * 24 * 60 * 60 / 12; // 5 days
+    pub const  InitialSubnetOwnerTransferScheduleDuration: BlockNumber = 5 * 24 * 60 * 60 / 12; // 5 days
+    pub const SubtensorInitialSenateAdjustmentInterval: u64 = 0; // Disabled until set by root.
+    pub const SubtensorInitialDelegateTakeNoticePeriod: u64 = 7200; // One day of notice.
 
 }
 
@@ -1006,6 +1065,9 @@ impl pallet_subtensor::Config for Runtime {
     type Preimages = Preimage;
     type InitialColdkeySwapScheduleDuration = InitialColdkeySwapScheduleDuration;
     type InitialDissolveNetworkScheduleDuration = InitialDissolveNetworkScheduleDuration;
+    type InitialSubnetOwnerTransferScheduleDuration = InitialSubnetOwnerTransferScheduleDuration;
+    type InitialSenateAdjustmentInterval = SubtensorInitialSenateAdjustmentInterval;
+    type InitialDelegateTakeNoticePeriod = SubtensorInitialDelegateTakeNoticePeriod;
 }
 
 use sp_runtime::BoundedVec;
@@ -1075,10 +1137,12 @@ pub type SignedExtra = (
     frame_metadata_hash_extension::CheckMetadataHash<Runtime>,
 );
 
-type Migrations =
+type Migrations = (
     pallet_subtensor::migrations::migrate_init_total_issuance::initialise_total_issuance::Migration<
         Runtime,
-    >;
+    >,
+    pallet_collective::migrations::v5::MigrateToV5<Runtime, TriumvirateCollective>,
+);
 
 // Unchecked extrinsic type as expected by this runtime.
 pub type UncheckedExtrinsic =
@@ -1376,6 +1440,21 @@ impl_runtime_apis! {
             let result = SubtensorModule::get_delegated(delegatee_account_vec);
             result.encode()
         }
+
+        fn get_delegates_v2() -> Vec<u8> {
+            let result = SubtensorModule::get_delegates_v2();
+            result.encode()
+        }
+
+        fn get_delegate_v2(delegate_account_vec: Vec<u8>) -> Vec<u8> {
+            let _result = SubtensorModule::get_delegate_v2(delegate_account_vec);
+            if _result.is_some() {
+                let result = _result.expect("Could not get DelegateInfoV2");
+                result.encode()
+            } else {
+                vec![]
+            }
+        }
     }
 
     impl subtensor_custom_rpc_runtime_api::NeuronInfoRuntimeApi<Block> for Runtime {
@@ -1462,6 +1541,11 @@ impl_runtime_apis! {
             let result = SubtensorModule::get_stake_info_for_coldkeys( coldkey_account_vecs );
             result.encode()
         }
+
+        fn get_unbonding_queue( coldkey_account_vec: Vec<u8> ) -> Vec<u8> {
+            let result = SubtensorModule::get_unbonding_queue( coldkey_account_vec );
+            result.encode()
+        }
     }
 
     impl subtensor_custom_rpc_runtime_api::SubnetRegistrationRuntimeApi<Block> for Runtime {
@@ -1469,6 +1553,135 @@ impl_runtime_apis! {
             SubtensorModule::get_network_lock_cost()
         }
     }
+
+    impl subtensor_custom_rpc_runtime_api::ScheduleInfoRuntimeApi<Block> for Runtime {
+        fn get_scheduled_coldkey_swap( coldkey_account_vec: Vec<u8> ) -> Vec<u8> {
+            let _result = SubtensorModule::get_scheduled_coldkey_swap( coldkey_account_vec );
+            if _result.is_some() {
+                let result = _result.expect("Could not get ScheduledColdkeySwapInfo");
+                result.encode()
+            } else {
+                vec![]
+            }
+        }
+
+        fn get_scheduled_coldkey_swaps() -> Vec<u8> {
+            let result = SubtensorModule::get_scheduled_coldkey_swaps();
+            result.encode()
+        }
+
+        fn get_scheduled_network_dissolution( netuid: u16 ) -> Vec<u8> {
+            let _result = SubtensorModule::get_scheduled_network_dissolution( netuid );
+            if _result.is_some() {
+                let result = _result.expect("Could not get ScheduledNetworkDissolutionInfo");
+                result.encode()
+            } else {
+                vec![]
+            }
+        }
+
+        fn get_scheduled_network_dissolutions() -> Vec<u8> {
+            let result = SubtensorModule::get_scheduled_network_dissolutions();
+            result.encode()
+        }
+    }
+
+    impl subtensor_custom_rpc_runtime_api::HyperparameterInfoRuntimeApi<Block> for Runtime {
+        fn get_hyperparameter_bounds() -> Vec<u8> {
+            let result = SubtensorModule::get_all_hyperparameter_bounds();
+            result.encode()
+        }
+
+        fn get_hyperparameter_templates() -> Vec<u8> {
+            let result = SubtensorModule::get_hyperparameter_templates();
+            result.encode()
+        }
+    }
+
+    impl subtensor_custom_rpc_runtime_api::ChildkeyInfoRuntimeApi<Block> for Runtime {
+        fn get_childkey_info( hotkey_account_vec: Vec<u8>, netuid: u16 ) -> Vec<u8> {
+            let _result = SubtensorModule::get_childkey_info( hotkey_account_vec, netuid );
+            if _result.is_some() {
+                let result = _result.expect("Could not get ChildkeyInfo");
+                result.encode()
+            } else {
+                vec![]
+            }
+        }
+
+        fn get_pending_children( hotkey_account_vec: Vec<u8>, netuid: u16 ) -> Vec<u8> {
+            let _result = SubtensorModule::get_pending_children_info( hotkey_account_vec, netuid );
+            if _result.is_some() {
+                let result = _result.expect("Could not get PendingChildrenInfo");
+                result.encode()
+            } else {
+                vec![]
+            }
+        }
+
+        fn get_pending_parents( child_account_vec: Vec<u8> ) -> Vec<u8> {
+            let result = SubtensorModule::get_pending_parents_info( child_account_vec );
+            result.encode()
+        }
+
+        fn get_childkey_emission_history( netuid: u16 ) -> Vec<u8> {
+            let result = SubtensorModule::get_childkey_emission_history( netuid );
+            result.encode()
+        }
+    }
+
+    impl subtensor_custom_rpc_runtime_api::SwapInfoRuntimeApi<Block> for Runtime {
+        fn get_hotkey_swap_preview( coldkey_account_vec: Vec<u8>, old_hotkey_account_vec: Vec<u8>, new_hotkey_account_vec: Vec<u8> ) -> Vec<u8> {
+            let _result = SubtensorModule::get_hotkey_swap_preview( coldkey_account_vec, old_hotkey_account_vec, new_hotkey_account_vec );
+            if _result.is_some() {
+                let result = _result.expect("Could not get HotkeySwapPreview");
+                result.encode()
+            } else {
+                vec![]
+            }
+        }
+
+        fn get_coldkey_swap_preview( old_coldkey_account_vec: Vec<u8>, new_coldkey_account_vec: Vec<u8> ) -> Vec<u8> {
+            let _result = SubtensorModule::get_coldkey_swap_preview( old_coldkey_account_vec, new_coldkey_account_vec );
+            if _result.is_some() {
+                let result = _result.expect("Could not get ColdkeySwapPreview");
+                result.encode()
+            } else {
+                vec![]
+            }
+        }
+    }
+
+    impl subtensor_custom_rpc_runtime_api::SenateVotingRuntimeApi<Block> for Runtime {
+        fn get_weighted_tally( proposal_hash_vec: Vec<u8> ) -> Vec<u8> {
+            let _result = Hash::decode( &mut proposal_hash_vec.as_slice() )
+                .ok()
+                .and_then( Triumvirate::weighted_tally );
+            if _result.is_some() {
+                let result = _result.expect("Could not get WeightedTally");
+                result.encode()
+            } else {
+                vec![]
+            }
+        }
+
+        fn get_weighted_tallies() -> Vec<u8> {
+            let result = Triumvirate::weighted_tallies();
+            result.encode()
+        }
+
+        fn get_proposal_metadata( proposal_hash_vec: Vec<u8> ) -> Vec<u8> {
+            let _result = Hash::decode( &mut proposal_hash_vec.as_slice() )
+                .ok()
+                .and_then( Triumvirate::proposal_metadata_of );
+            if _result.is_some() {
+                let result = _result.expect("Could not get ProposalMetadata");
+                result.encode()
+            } else {
+                vec![]
+            }
+        }
+    }
 }
 
 // #[cfg(test)]
@@ -109,6 +109,16 @@ fn call_add_stake() -> RuntimeCall {
     })
 }
 
+// stake transfer call
+fn call_transfer_stake() -> RuntimeCall {
+    let amount = 100;
+    RuntimeCall::SubtensorModule(pallet_subtensor::Call::transfer_stake {
+        destination_coldkey: AccountId::from(OTHER_ACCOUNT),
+        hotkey: AccountId::from(DELEGATE),
+        amount,
+    })
+}
+
 // register call, account as hotkey, delegate as coldkey
 fn call_register() -> RuntimeCall {
     let block_number: u64 = 1;
@@ -171,6 +181,7 @@ fn test_proxy_pallet() {
         ProxyType::Governance,
         ProxyType::Staking,
         ProxyType::Registration,
+        ProxyType::StakeTransfer,
     ];
 
     let calls = [
@@ -182,6 +193,7 @@ fn test_proxy_pallet() {
         call_triumvirate,
         call_senate,
         call_add_stake,
+        call_transfer_stake,
         call_register,
     ];
 
@@ -227,3 +239,30 @@ fn test_non_transfer_cannot_transfer() {
         );
     });
 }
+
+#[test]
+fn test_non_transfer_cannot_transfer_stake() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Proxy::add_proxy(
+            RuntimeOrigin::signed(AccountId::from(ACCOUNT)),
+            AccountId::from(DELEGATE).into(),
+            ProxyType::NonTransfer,
+            0
+        ));
+
+        let call = call_transfer_stake();
+        assert_ok!(Proxy::proxy(
+            RuntimeOrigin::signed(AccountId::from(DELEGATE)),
+            AccountId::from(ACCOUNT).into(),
+            None,
+            Box::new(call.clone()),
+        ));
+
+        System::assert_last_event(
+            pallet_proxy::Event::ProxyExecuted {
+                result: Err(SystemError::CallFiltered.into()),
+            }
+            .into(),
+        );
+    });
+}